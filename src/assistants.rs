@@ -0,0 +1,210 @@
+//! See <https://platform.openai.com/docs/api-reference/assistants>.
+//! Use with [Client::create_assistant](crate::Client::create_assistant), [Client::list_assistants](crate::Client::list_assistants),
+//! [Client::retrieve_assistant](crate::Client::retrieve_assistant), [Client::modify_assistant](crate::Client::modify_assistant)
+//! or [Client::delete_assistant](crate::Client::delete_assistant).
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::chat::FunctionDefinition;
+
+pub mod messages;
+pub mod run_steps;
+pub mod runs;
+pub mod stream;
+pub mod threads;
+
+/// The value of the `OpenAI-Beta` header required by the Assistants API.
+pub(crate) const BETA_HEADER: &str = "assistants=v2";
+
+/// Request arguments for creating an assistant.
+///
+/// See <https://platform.openai.com/docs/api-reference/assistants/createAssistant>.
+///
+/// ```
+/// use openai_rust2::assistants::{AssistantArguments, AssistantTool};
+/// let mut args = AssistantArguments::new("gpt-4o");
+/// args.name = Some("Math Tutor".to_owned());
+/// args.instructions = Some("You are a personal math tutor.".to_owned());
+/// args.tools = vec![AssistantTool::CodeInterpreter];
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct AssistantArguments {
+    /// ID of the model to use. You can use the [List models](crate::Client::list_models) API to see all of your available models.
+    pub model: String,
+
+    /// The name of the assistant. The maximum length is 256 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The description of the assistant. The maximum length is 512 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The system instructions that the assistant uses. The maximum length is 256,000 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// A list of tools enabled on the assistant. There can be a maximum of 128 tools per assistant.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<AssistantTool>,
+
+    /// A set of resources that are used by the assistant's tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// What sampling temperature to use, between 0 and 2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+}
+
+impl AssistantArguments {
+    pub fn new(model: impl AsRef<str>) -> AssistantArguments {
+        AssistantArguments {
+            model: model.as_ref().to_owned(),
+            name: None,
+            description: None,
+            instructions: None,
+            tools: vec![],
+            tool_resources: None,
+            metadata: None,
+            temperature: None,
+            top_p: None,
+        }
+    }
+}
+
+/// Request arguments for modifying an assistant.
+///
+/// Only the fields that are set will be changed.
+///
+/// See <https://platform.openai.com/docs/api-reference/assistants/modifyAssistant>.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ModifyAssistantArguments {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<AssistantTool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+}
+
+/// A tool that can be enabled on an assistant.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AssistantTool {
+    /// Lets the assistant write and run Python code in a sandboxed environment.
+    CodeInterpreter,
+    /// Lets the assistant search the files of its vector stores.
+    FileSearch {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        file_search: Option<FileSearchSettings>,
+    },
+    /// A function the assistant can ask you to call.
+    Function { function: FunctionDefinition },
+}
+
+/// Overrides for the [AssistantTool::FileSearch] tool.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FileSearchSettings {
+    /// The maximum number of results the file search tool should output, between 1 and 50.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_num_results: Option<u32>,
+}
+
+/// Resources made available to the tools of an assistant.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ToolResources {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_interpreter: Option<CodeInterpreterResources>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_search: Option<FileSearchResources>,
+}
+
+/// Files made available to the [AssistantTool::CodeInterpreter] tool.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CodeInterpreterResources {
+    #[serde(default)]
+    pub file_ids: Vec<String>,
+}
+
+/// Vector stores made available to the [AssistantTool::FileSearch] tool.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FileSearchResources {
+    #[serde(default)]
+    pub vector_store_ids: Vec<String>,
+}
+
+/// Represents an assistant that can call the model and use tools.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"asst_abc123\",
+/// #  \"object\": \"assistant\",
+/// #  \"created_at\": 1698984975,
+/// #  \"name\": \"Math Tutor\",
+/// #  \"description\": null,
+/// #  \"model\": \"gpt-4o\",
+/// #  \"instructions\": \"You are a personal math tutor.\",
+/// #  \"tools\": [
+/// #    {
+/// #      \"type\": \"code_interpreter\"
+/// #    }
+/// #  ],
+/// #  \"metadata\": {},
+/// #  \"top_p\": 1.0,
+/// #  \"temperature\": 1.0,
+/// #  \"response_format\": \"auto\"
+/// # }";
+/// # let assistant = serde_json::from_str::<openai_rust2::assistants::Assistant>(json).unwrap();
+/// println!("{}", assistant.id);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Assistant {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the assistant was created.
+    pub created_at: u64,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub model: String,
+    pub instructions: Option<String>,
+    #[serde(default)]
+    pub tools: Vec<AssistantTool>,
+    #[serde(default)]
+    pub tool_resources: Option<ToolResources>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    #[serde(default)]
+    pub top_p: Option<f32>,
+}
+
+/// The response of an assistant deletion request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteAssistantResponse {
+    pub id: String,
+    pub deleted: bool,
+}
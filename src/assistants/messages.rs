@@ -0,0 +1,207 @@
+//! See <https://platform.openai.com/docs/api-reference/messages>.
+//! Use with [Client::create_message](crate::Client::create_message), [Client::list_messages](crate::Client::list_messages),
+//! [Client::retrieve_message](crate::Client::retrieve_message) or [Client::modify_message](crate::Client::modify_message).
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::AssistantTool;
+
+/// Request arguments for creating a message in a thread.
+///
+/// See <https://platform.openai.com/docs/api-reference/messages/createMessage>.
+///
+/// ```
+/// let args = openai_rust2::assistants::messages::MessageArguments::new(
+///     "user",
+///     "How does AI work? Explain it in simple terms.",
+/// );
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct MessageArguments {
+    /// The role of the entity that is creating the message, `user` or `assistant`.
+    pub role: String,
+
+    /// The text contents of the message.
+    pub content: String,
+
+    /// A list of files attached to the message, and the tools they should be added to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl MessageArguments {
+    pub fn new(role: impl AsRef<str>, content: impl AsRef<str>) -> MessageArguments {
+        MessageArguments {
+            role: role.as_ref().to_owned(),
+            content: content.as_ref().to_owned(),
+            attachments: vec![],
+            metadata: None,
+        }
+    }
+}
+
+/// A file attached to a message.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Attachment {
+    /// The ID of the file to attach to the message.
+    pub file_id: String,
+    /// The tools to add this file to.
+    #[serde(default)]
+    pub tools: Vec<AssistantTool>,
+}
+
+/// Request arguments for modifying a message.
+///
+/// See <https://platform.openai.com/docs/api-reference/messages/modifyMessage>.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ModifyMessageArguments {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Represents a message within a thread.
+///
+/// It implements [Display](std::fmt::Display) as a shortcut to easily extract the text content.
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"msg_abc123\",
+/// #  \"object\": \"thread.message\",
+/// #  \"created_at\": 1698983503,
+/// #  \"thread_id\": \"thread_abc123\",
+/// #  \"role\": \"assistant\",
+/// #  \"content\": [
+/// #    {
+/// #      \"type\": \"text\",
+/// #      \"text\": {
+/// #        \"value\": \"Hi! How can I help you today?\",
+/// #        \"annotations\": []
+/// #      }
+/// #    }
+/// #  ],
+/// #  \"assistant_id\": \"asst_abc123\",
+/// #  \"run_id\": \"run_abc123\",
+/// #  \"attachments\": [],
+/// #  \"metadata\": {}
+/// # }";
+/// # let msg = serde_json::from_str::<openai_rust2::assistants::messages::Message>(json).unwrap();
+/// use openai_rust2::assistants::messages::MessageContent;
+/// if let MessageContent::Text { text } = &msg.content[0] {
+///     println!("{}", text.value);
+/// }
+/// // or
+/// let text = msg.to_string();
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Message {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the message was created.
+    pub created_at: u64,
+    /// The ID of the thread that this message belongs to.
+    pub thread_id: String,
+    /// The status of the message, `in_progress`, `incomplete` or `completed`.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// The entity that produced the message, `user` or `assistant`.
+    pub role: String,
+    /// The content of the message.
+    pub content: Vec<MessageContent>,
+    /// If applicable, the ID of the assistant that authored this message.
+    pub assistant_id: Option<String>,
+    /// The ID of the run associated with the creation of this message.
+    pub run_id: Option<String>,
+    /// A list of files attached to the message, and the tools they were added to.
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+impl std::fmt::Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for content in &self.content {
+            if let MessageContent::Text { text } = content {
+                write!(f, "{}", text.value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A content part of a [Message].
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageContent {
+    /// The text content that is part of a message.
+    Text { text: Text },
+    /// References an image [File](crate::files::FileObject) in the content of a message.
+    ImageFile { image_file: ImageFile },
+    /// References an image URL in the content of a message.
+    ImageUrl { image_url: ImageUrl },
+    /// The refusal content generated by the assistant.
+    Refusal { refusal: String },
+}
+
+/// The text of a [MessageContent::Text].
+#[derive(Deserialize, Debug, Clone)]
+pub struct Text {
+    /// The data that makes up the text.
+    pub value: String,
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+}
+
+/// An annotation within a [Text].
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Annotation {
+    /// A citation within the message that points to a specific quote from a specific file.
+    /// Generated when the assistant uses the `file_search` tool.
+    FileCitation {
+        /// The text in the message content that needs to be replaced.
+        text: String,
+        file_citation: FileReference,
+        start_index: u32,
+        end_index: u32,
+    },
+    /// A URL for the file that's generated when the assistant used the `code_interpreter` tool to generate a file.
+    FilePath {
+        /// The text in the message content that needs to be replaced.
+        text: String,
+        file_path: FileReference,
+        start_index: u32,
+        end_index: u32,
+    },
+}
+
+/// A reference to a file from an [Annotation].
+#[derive(Deserialize, Debug, Clone)]
+pub struct FileReference {
+    pub file_id: String,
+}
+
+/// The image file of a [MessageContent::ImageFile].
+#[derive(Deserialize, Debug, Clone)]
+pub struct ImageFile {
+    /// The [File](crate::files::FileObject) ID of the image in the message content.
+    pub file_id: String,
+    /// Specifies the detail level of the image, `auto`, `low` or `high`.
+    #[serde(default)]
+    pub detail: Option<String>,
+}
+
+/// The image URL of a [MessageContent::ImageUrl].
+#[derive(Deserialize, Debug, Clone)]
+pub struct ImageUrl {
+    /// The external URL of the image.
+    pub url: String,
+    /// Specifies the detail level of the image, `auto`, `low` or `high`.
+    #[serde(default)]
+    pub detail: Option<String>,
+}
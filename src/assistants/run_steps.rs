@@ -0,0 +1,174 @@
+//! See <https://platform.openai.com/docs/api-reference/run-steps>.
+//! Use with [Client::list_run_steps](crate::Client::list_run_steps) or [Client::retrieve_run_step](crate::Client::retrieve_run_step).
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::runs::{RunError, Usage};
+
+/// The status of a [RunStep].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStepStatus {
+    InProgress,
+    Cancelled,
+    Failed,
+    Completed,
+    Expired,
+}
+
+/// Represents a step in execution of a [run](super::runs::Run).
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"step_abc123\",
+/// #  \"object\": \"thread.run.step\",
+/// #  \"created_at\": 1699063291,
+/// #  \"run_id\": \"run_abc123\",
+/// #  \"assistant_id\": \"asst_abc123\",
+/// #  \"thread_id\": \"thread_abc123\",
+/// #  \"type\": \"message_creation\",
+/// #  \"status\": \"completed\",
+/// #  \"cancelled_at\": null,
+/// #  \"completed_at\": 1699063291,
+/// #  \"expired_at\": null,
+/// #  \"failed_at\": null,
+/// #  \"last_error\": null,
+/// #  \"step_details\": {
+/// #    \"type\": \"message_creation\",
+/// #    \"message_creation\": {
+/// #      \"message_id\": \"msg_abc123\"
+/// #    }
+/// #  },
+/// #  \"usage\": {
+/// #    \"prompt_tokens\": 123,
+/// #    \"completion_tokens\": 456,
+/// #    \"total_tokens\": 579
+/// #  }
+/// # }";
+/// # let step = serde_json::from_str::<openai_rust2::assistants::run_steps::RunStep>(json).unwrap();
+/// use openai_rust2::assistants::run_steps::StepDetails;
+/// match &step.step_details {
+///     StepDetails::MessageCreation { message_creation } => println!("Created {}", message_creation.message_id),
+///     StepDetails::ToolCalls { tool_calls } => println!("Called {} tools", tool_calls.len()),
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct RunStep {
+    /// The identifier of the run step, which can be referenced in API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the run step was created.
+    pub created_at: u64,
+    /// The ID of the run that this run step is a part of.
+    pub run_id: String,
+    /// The ID of the assistant associated with the run step.
+    pub assistant_id: String,
+    /// The ID of the thread that was run.
+    pub thread_id: String,
+    /// The status of the run step.
+    pub status: RunStepStatus,
+    /// The details of the run step.
+    pub step_details: StepDetails,
+    /// The last error associated with this run step. Will be `None` if there are no errors.
+    #[serde(default)]
+    pub last_error: Option<RunError>,
+    pub expired_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+    pub failed_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Usage statistics related to the run step. This value will be `None` while the run step's status is `in_progress`.
+    #[serde(default)]
+    pub usage: Option<Usage>,
+}
+
+/// The details of a [RunStep].
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StepDetails {
+    /// The run step created a message.
+    MessageCreation { message_creation: MessageCreation },
+    /// The run step called tools.
+    ToolCalls { tool_calls: Vec<StepToolCall> },
+}
+
+/// The message created by a [StepDetails::MessageCreation].
+#[derive(Deserialize, Debug, Clone)]
+pub struct MessageCreation {
+    /// The ID of the message that was created by this run step.
+    pub message_id: String,
+}
+
+/// A tool call of a [StepDetails::ToolCalls].
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StepToolCall {
+    CodeInterpreter {
+        id: String,
+        code_interpreter: CodeInterpreterCall,
+    },
+    FileSearch {
+        id: String,
+        file_search: FileSearchCall,
+    },
+    Function {
+        id: String,
+        function: FunctionCall,
+    },
+}
+
+/// The details of a code interpreter tool call.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CodeInterpreterCall {
+    /// The input to the Code Interpreter tool call.
+    pub input: String,
+    /// The outputs from the Code Interpreter tool call.
+    #[serde(default)]
+    pub outputs: Vec<CodeInterpreterOutput>,
+}
+
+/// An output of a [CodeInterpreterCall].
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CodeInterpreterOutput {
+    /// Text output from the Code Interpreter tool call.
+    Logs { logs: String },
+    /// An image generated by the Code Interpreter tool call.
+    Image { image: CodeInterpreterImage },
+}
+
+/// The image of a [CodeInterpreterOutput::Image].
+#[derive(Deserialize, Debug, Clone)]
+pub struct CodeInterpreterImage {
+    /// The [file](crate::files::FileObject) ID of the image.
+    pub file_id: String,
+}
+
+/// The details of a file search tool call.
+#[derive(Deserialize, Debug, Clone)]
+pub struct FileSearchCall {
+    #[serde(default)]
+    pub results: Vec<FileSearchResult>,
+}
+
+/// A result of a [FileSearchCall].
+#[derive(Deserialize, Debug, Clone)]
+pub struct FileSearchResult {
+    pub file_id: String,
+    pub file_name: String,
+    /// The score of the result between 0 and 1.
+    pub score: f32,
+}
+
+/// The details of a function tool call.
+#[derive(Deserialize, Debug, Clone)]
+pub struct FunctionCall {
+    /// The name of the function.
+    pub name: String,
+    /// The arguments passed to the function.
+    pub arguments: String,
+    /// The output of the function. This will be `None` if the outputs have not been submitted yet.
+    pub output: Option<String>,
+}
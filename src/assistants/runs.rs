@@ -0,0 +1,372 @@
+//! See <https://platform.openai.com/docs/api-reference/runs>.
+//! Use with [Client::create_run](crate::Client::create_run), [Client::list_runs](crate::Client::list_runs),
+//! [Client::retrieve_run](crate::Client::retrieve_run), [Client::cancel_run](crate::Client::cancel_run)
+//! [Client::wait_for_run](crate::Client::wait_for_run), [Client::create_run_stream](crate::Client::create_run_stream),
+//! [Client::submit_tool_outputs](crate::Client::submit_tool_outputs), [Client::submit_tool_outputs_stream](crate::Client::submit_tool_outputs_stream)
+//! or [Client::create_thread_and_run](crate::Client::create_thread_and_run).
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::messages::MessageArguments;
+use super::threads::ThreadArguments;
+use super::{AssistantTool, ToolResources};
+
+/// Request arguments for creating a run.
+///
+/// See <https://platform.openai.com/docs/api-reference/runs/createRun>.
+///
+/// ```
+/// let args = openai_rust2::assistants::runs::RunArguments::new("asst_abc123");
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct RunArguments {
+    /// The ID of the [assistant](crate::assistants::Assistant) to use to execute this run.
+    pub assistant_id: String,
+
+    /// The ID of the model to be used to execute this run.
+    /// If a value is provided here, it will override the model associated with the assistant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Overrides the instructions of the assistant. This is useful for modifying the behavior on a per-run basis.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// Appends additional instructions at the end of the instructions for the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_instructions: Option<String>,
+
+    /// Adds additional messages to the thread before creating the run.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub additional_messages: Vec<MessageArguments>,
+
+    /// Override the tools the assistant can use for this run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<AssistantTool>>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// What sampling temperature to use, between 0 and 2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+
+    /// The maximum number of prompt tokens that may be used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_prompt_tokens: Option<u32>,
+
+    /// The maximum number of completion tokens that may be used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_completion_tokens: Option<u32>,
+
+    /// Whether to enable parallel function calling during tool use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+
+    /// Whether to stream back the run as server-sent events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stream: Option<bool>,
+}
+
+impl RunArguments {
+    pub fn new(assistant_id: impl AsRef<str>) -> RunArguments {
+        RunArguments {
+            assistant_id: assistant_id.as_ref().to_owned(),
+            model: None,
+            instructions: None,
+            additional_instructions: None,
+            additional_messages: vec![],
+            tools: None,
+            metadata: None,
+            temperature: None,
+            top_p: None,
+            max_prompt_tokens: None,
+            max_completion_tokens: None,
+            parallel_tool_calls: None,
+            stream: None,
+        }
+    }
+}
+
+/// Request arguments for creating a thread and running it in one request.
+///
+/// See <https://platform.openai.com/docs/api-reference/runs/createThreadAndRun>.
+///
+/// ```
+/// use openai_rust2::assistants::{messages::MessageArguments, runs::ThreadAndRunArguments};
+/// let mut args = ThreadAndRunArguments::new("asst_abc123");
+/// args.thread.messages = vec![MessageArguments::new("user", "Explain deep learning to a 5 year old.")];
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct ThreadAndRunArguments {
+    /// The ID of the [assistant](crate::assistants::Assistant) to use to execute this run.
+    pub assistant_id: String,
+
+    /// The thread to create.
+    pub thread: ThreadArguments,
+
+    /// The ID of the model to be used to execute this run.
+    /// If a value is provided here, it will override the model associated with the assistant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Override the default system message of the assistant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// Override the tools the assistant can use for this run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<AssistantTool>>,
+
+    /// A set of resources that are used by the assistant's tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// What sampling temperature to use, between 0 and 2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+
+    /// The maximum number of prompt tokens that may be used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_prompt_tokens: Option<u32>,
+
+    /// The maximum number of completion tokens that may be used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_completion_tokens: Option<u32>,
+
+    /// Whether to enable parallel function calling during tool use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+}
+
+impl ThreadAndRunArguments {
+    pub fn new(assistant_id: impl AsRef<str>) -> ThreadAndRunArguments {
+        ThreadAndRunArguments {
+            assistant_id: assistant_id.as_ref().to_owned(),
+            thread: ThreadArguments::default(),
+            model: None,
+            instructions: None,
+            tools: None,
+            tool_resources: None,
+            metadata: None,
+            temperature: None,
+            top_p: None,
+            max_prompt_tokens: None,
+            max_completion_tokens: None,
+            parallel_tool_calls: None,
+        }
+    }
+}
+
+/// Request arguments for submitting tool outputs to a run that requires action.
+///
+/// See <https://platform.openai.com/docs/api-reference/runs/submitToolOutputs>.
+///
+/// ```
+/// use openai_rust2::assistants::runs::{SubmitToolOutputsArguments, ToolOutput};
+/// let args = SubmitToolOutputsArguments::new(vec![ToolOutput {
+///     tool_call_id: "call_001".to_owned(),
+///     output: "70 degrees and sunny.".to_owned(),
+/// }]);
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct SubmitToolOutputsArguments {
+    /// A list of tools for which the outputs are being submitted.
+    pub tool_outputs: Vec<ToolOutput>,
+
+    /// Whether to stream back the run as server-sent events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stream: Option<bool>,
+}
+
+impl SubmitToolOutputsArguments {
+    pub fn new(tool_outputs: Vec<ToolOutput>) -> SubmitToolOutputsArguments {
+        SubmitToolOutputsArguments {
+            tool_outputs,
+            stream: None,
+        }
+    }
+}
+
+/// The output of a [ToolCall].
+#[derive(Serialize, Debug, Clone)]
+pub struct ToolOutput {
+    /// The ID of the tool call in the [RequiredAction] the output is being submitted for.
+    pub tool_call_id: String,
+    /// The output of the tool call to be submitted to continue the run.
+    pub output: String,
+}
+
+/// The status of a [Run].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    Queued,
+    InProgress,
+    RequiresAction,
+    Cancelling,
+    Cancelled,
+    Failed,
+    Completed,
+    Incomplete,
+    Expired,
+}
+
+impl RunStatus {
+    /// Whether the run has stopped and will not change status anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            RunStatus::Cancelled
+                | RunStatus::Failed
+                | RunStatus::Completed
+                | RunStatus::Incomplete
+                | RunStatus::Expired
+        )
+    }
+}
+
+/// Represents an execution run on a [thread](super::threads::Thread).
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"run_abc123\",
+/// #  \"object\": \"thread.run\",
+/// #  \"created_at\": 1699063290,
+/// #  \"assistant_id\": \"asst_abc123\",
+/// #  \"thread_id\": \"thread_abc123\",
+/// #  \"status\": \"queued\",
+/// #  \"started_at\": 1699063290,
+/// #  \"expires_at\": null,
+/// #  \"cancelled_at\": null,
+/// #  \"failed_at\": null,
+/// #  \"completed_at\": 1699063291,
+/// #  \"last_error\": null,
+/// #  \"model\": \"gpt-4o\",
+/// #  \"instructions\": null,
+/// #  \"incomplete_details\": null,
+/// #  \"tools\": [
+/// #    {
+/// #      \"type\": \"code_interpreter\"
+/// #    }
+/// #  ],
+/// #  \"metadata\": {},
+/// #  \"usage\": null,
+/// #  \"temperature\": 1.0,
+/// #  \"top_p\": 1.0,
+/// #  \"max_prompt_tokens\": 1000,
+/// #  \"max_completion_tokens\": 1000,
+/// #  \"truncation_strategy\": {
+/// #    \"type\": \"auto\",
+/// #    \"last_messages\": null
+/// #  },
+/// #  \"response_format\": \"auto\",
+/// #  \"tool_choice\": \"auto\",
+/// #  \"parallel_tool_calls\": true
+/// # }";
+/// # let run = serde_json::from_str::<openai_rust2::assistants::runs::Run>(json).unwrap();
+/// println!("{} is {:?}", run.id, run.status);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Run {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the run was created.
+    pub created_at: u64,
+    /// The ID of the thread that was executed on as a part of this run.
+    pub thread_id: String,
+    /// The ID of the assistant used for execution of this run.
+    pub assistant_id: String,
+    /// The status of the run.
+    pub status: RunStatus,
+    /// Details on the action required to continue the run. Will be `None` if no action is required.
+    #[serde(default)]
+    pub required_action: Option<RequiredAction>,
+    /// The last error associated with this run. Will be `None` if there are no errors.
+    #[serde(default)]
+    pub last_error: Option<RunError>,
+    pub expires_at: Option<u64>,
+    pub started_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+    pub failed_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    /// The model that the assistant used for this run.
+    pub model: String,
+    /// The instructions that the assistant used for this run.
+    pub instructions: Option<String>,
+    /// The list of tools that the assistant used for this run.
+    #[serde(default)]
+    pub tools: Vec<AssistantTool>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Usage statistics related to the run. This value will be `None` if the run is not in a terminal state.
+    #[serde(default)]
+    pub usage: Option<Usage>,
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    #[serde(default)]
+    pub top_p: Option<f32>,
+}
+
+/// Details on the action required to continue a [Run].
+#[derive(Deserialize, Debug, Clone)]
+pub struct RequiredAction {
+    /// Details on the tool outputs needed for this run to continue.
+    pub submit_tool_outputs: SubmitToolOutputsAction,
+}
+
+/// The tool calls a [Run] is waiting on.
+#[derive(Deserialize, Debug, Clone)]
+pub struct SubmitToolOutputsAction {
+    pub tool_calls: Vec<ToolCall>,
+}
+
+/// A function tool call requested by a [Run].
+#[derive(Deserialize, Debug, Clone)]
+pub struct ToolCall {
+    /// The ID of the tool call. This ID must be referenced when you submit the tool outputs.
+    pub id: String,
+    /// The function definition.
+    pub function: FunctionCall,
+}
+
+/// The function and arguments of a [ToolCall].
+#[derive(Deserialize, Debug, Clone)]
+pub struct FunctionCall {
+    /// The name of the function.
+    pub name: String,
+    /// The arguments that the model expects you to pass to the function, as a JSON string.
+    pub arguments: String,
+}
+
+/// The error of a failed [Run].
+#[derive(Deserialize, Debug, Clone)]
+pub struct RunError {
+    /// One of `server_error`, `rate_limit_exceeded`, or `invalid_prompt`.
+    pub code: String,
+    /// A human-readable description of the error.
+    pub message: String,
+}
+
+/// Information about the tokens used by a [Run].
+#[derive(Deserialize, Debug, Clone)]
+pub struct Usage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
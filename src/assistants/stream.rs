@@ -0,0 +1,299 @@
+//! Structs and deserialization method for the events
+//! received when streaming runs.
+//!
+//! See <https://platform.openai.com/docs/api-reference/assistants-streaming/events>.
+use futures_util::Stream;
+use serde::Deserialize;
+use std::pin::Pin;
+use std::task::Poll;
+
+use super::messages::{Annotation, ImageFile, ImageUrl, Message};
+use super::run_steps::{MessageCreation, RunStep};
+use super::runs::Run;
+use super::threads::Thread;
+
+/// An event received while streaming a [Run].
+///
+/// ```
+/// # use futures_util::StreamExt;
+/// # async fn example(mut stream: openai_rust2::assistants::stream::AssistantEventStream) {
+/// use openai_rust2::assistants::stream::AssistantStreamEvent;
+/// while let Some(event) = stream.next().await {
+///     match event.unwrap() {
+///         AssistantStreamEvent::MessageDelta(delta) => print!("{}", delta),
+///         AssistantStreamEvent::RunCompleted(run) => println!("\n{} completed", run.id),
+///         _ => {}
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub enum AssistantStreamEvent {
+    /// `thread.created`
+    ThreadCreated(Thread),
+    /// `thread.run.created`
+    RunCreated(Run),
+    /// `thread.run.queued`
+    RunQueued(Run),
+    /// `thread.run.in_progress`
+    RunInProgress(Run),
+    /// `thread.run.requires_action`
+    RunRequiresAction(Run),
+    /// `thread.run.completed`
+    RunCompleted(Run),
+    /// `thread.run.incomplete`
+    RunIncomplete(Run),
+    /// `thread.run.failed`
+    RunFailed(Run),
+    /// `thread.run.cancelling`
+    RunCancelling(Run),
+    /// `thread.run.cancelled`
+    RunCancelled(Run),
+    /// `thread.run.expired`
+    RunExpired(Run),
+    /// `thread.run.step.created`
+    RunStepCreated(RunStep),
+    /// `thread.run.step.in_progress`
+    RunStepInProgress(RunStep),
+    /// `thread.run.step.delta`
+    RunStepDelta(RunStepDelta),
+    /// `thread.run.step.completed`
+    RunStepCompleted(RunStep),
+    /// `thread.run.step.failed`
+    RunStepFailed(RunStep),
+    /// `thread.run.step.cancelled`
+    RunStepCancelled(RunStep),
+    /// `thread.run.step.expired`
+    RunStepExpired(RunStep),
+    /// `thread.message.created`
+    MessageCreated(Message),
+    /// `thread.message.in_progress`
+    MessageInProgress(Message),
+    /// `thread.message.delta`
+    MessageDelta(MessageDelta),
+    /// `thread.message.completed`
+    MessageCompleted(Message),
+    /// `thread.message.incomplete`
+    MessageIncomplete(Message),
+    /// `error`
+    Error(serde_json::Value),
+    /// `done`, the last event of the stream.
+    Done,
+    /// An event not known to this crate.
+    Unknown { event: String, data: String },
+}
+
+impl AssistantStreamEvent {
+    fn parse(event: &str, data: &str) -> serde_json::Result<AssistantStreamEvent> {
+        use AssistantStreamEvent::*;
+        Ok(match event {
+            "thread.created" => ThreadCreated(serde_json::from_str(data)?),
+            "thread.run.created" => RunCreated(serde_json::from_str(data)?),
+            "thread.run.queued" => RunQueued(serde_json::from_str(data)?),
+            "thread.run.in_progress" => RunInProgress(serde_json::from_str(data)?),
+            "thread.run.requires_action" => RunRequiresAction(serde_json::from_str(data)?),
+            "thread.run.completed" => RunCompleted(serde_json::from_str(data)?),
+            "thread.run.incomplete" => RunIncomplete(serde_json::from_str(data)?),
+            "thread.run.failed" => RunFailed(serde_json::from_str(data)?),
+            "thread.run.cancelling" => RunCancelling(serde_json::from_str(data)?),
+            "thread.run.cancelled" => RunCancelled(serde_json::from_str(data)?),
+            "thread.run.expired" => RunExpired(serde_json::from_str(data)?),
+            "thread.run.step.created" => RunStepCreated(serde_json::from_str(data)?),
+            "thread.run.step.in_progress" => RunStepInProgress(serde_json::from_str(data)?),
+            "thread.run.step.delta" => RunStepDelta(serde_json::from_str(data)?),
+            "thread.run.step.completed" => RunStepCompleted(serde_json::from_str(data)?),
+            "thread.run.step.failed" => RunStepFailed(serde_json::from_str(data)?),
+            "thread.run.step.cancelled" => RunStepCancelled(serde_json::from_str(data)?),
+            "thread.run.step.expired" => RunStepExpired(serde_json::from_str(data)?),
+            "thread.message.created" => MessageCreated(serde_json::from_str(data)?),
+            "thread.message.in_progress" => MessageInProgress(serde_json::from_str(data)?),
+            "thread.message.delta" => MessageDelta(serde_json::from_str(data)?),
+            "thread.message.completed" => MessageCompleted(serde_json::from_str(data)?),
+            "thread.message.incomplete" => MessageIncomplete(serde_json::from_str(data)?),
+            "error" => Error(serde_json::from_str(data)?),
+            "done" => Done,
+            _ => Unknown {
+                event: event.to_owned(),
+                data: data.to_owned(),
+            },
+        })
+    }
+}
+
+/// The changed fields of a [Message] during streaming.
+///
+/// It implements [Display](std::fmt::Display) as a shortcut to easily extract the text content.
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"msg_123\",
+/// #  \"object\": \"thread.message.delta\",
+/// #  \"delta\": {
+/// #    \"content\": [
+/// #      {
+/// #        \"index\": 0,
+/// #        \"type\": \"text\",
+/// #        \"text\": { \"value\": \"Hello\", \"annotations\": [] }
+/// #      }
+/// #    ]
+/// #  }
+/// # }";
+/// # let delta = serde_json::from_str::<openai_rust2::assistants::stream::MessageDelta>(json).unwrap();
+/// let text = delta.to_string();
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct MessageDelta {
+    /// The identifier of the message.
+    pub id: String,
+    pub delta: MessageDeltaContent,
+}
+
+impl std::fmt::Display for MessageDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for content in &self.delta.content {
+            if let MessageContentDelta::Text {
+                text: TextDelta {
+                    value: Some(value), ..
+                },
+                ..
+            } = content
+            {
+                write!(f, "{}", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The delta of a [MessageDelta].
+#[derive(Deserialize, Debug, Clone)]
+pub struct MessageDeltaContent {
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub content: Vec<MessageContentDelta>,
+}
+
+/// A partial content part of a [MessageDelta].
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageContentDelta {
+    Text {
+        /// The index of the content part in the message.
+        index: u32,
+        text: TextDelta,
+    },
+    ImageFile {
+        index: u32,
+        image_file: ImageFile,
+    },
+    ImageUrl {
+        index: u32,
+        image_url: ImageUrl,
+    },
+    Refusal {
+        index: u32,
+        refusal: String,
+    },
+}
+
+/// A text fragment of a [MessageContentDelta::Text].
+#[derive(Deserialize, Debug, Clone)]
+pub struct TextDelta {
+    #[serde(default)]
+    pub value: Option<String>,
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+}
+
+/// The changed fields of a [RunStep] during streaming.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RunStepDelta {
+    /// The identifier of the run step.
+    pub id: String,
+    pub delta: RunStepDeltaContent,
+}
+
+/// The delta of a [RunStepDelta].
+#[derive(Deserialize, Debug, Clone)]
+pub struct RunStepDeltaContent {
+    #[serde(default)]
+    pub step_details: Option<StepDetailsDelta>,
+}
+
+/// The partial details of a [RunStepDelta].
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StepDetailsDelta {
+    MessageCreation {
+        #[serde(default)]
+        message_creation: Option<MessageCreation>,
+    },
+    ToolCalls {
+        #[serde(default)]
+        tool_calls: Vec<ToolCallDelta>,
+    },
+}
+
+/// A fragment of a tool call in a [StepDetailsDelta::ToolCalls].
+#[derive(Deserialize, Debug, Clone)]
+pub struct ToolCallDelta {
+    /// The index of the tool call in the tool calls array.
+    pub index: u32,
+    #[serde(default)]
+    pub id: Option<String>,
+    /// The type of the tool call, `code_interpreter`, `file_search` or `function`.
+    #[serde(rename = "type")]
+    pub call_type: String,
+    #[serde(default)]
+    pub code_interpreter: Option<serde_json::Value>,
+    #[serde(default)]
+    pub file_search: Option<serde_json::Value>,
+    #[serde(default)]
+    pub function: Option<FunctionCallDelta>,
+}
+
+/// A fragment of a function tool call.
+#[derive(Deserialize, Debug, Clone)]
+pub struct FunctionCallDelta {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub arguments: Option<String>,
+    #[serde(default)]
+    pub output: Option<String>,
+}
+
+/// A stream of [AssistantStreamEvent]s returned by [crate::Client::create_run_stream].
+pub struct AssistantEventStream {
+    event_stream: crate::sse::EventStream,
+}
+
+impl AssistantEventStream {
+    pub(crate) fn new(stream: crate::sse::ByteStream) -> Self {
+        Self {
+            event_stream: crate::sse::EventStream::new(stream),
+        }
+    }
+}
+
+impl Stream for AssistantEventStream {
+    type Item = anyhow::Result<AssistantStreamEvent>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        match Pin::new(&mut self.event_stream).poll_next(cx) {
+            Poll::Ready(Some(Ok(event))) => {
+                let name = event.event.as_deref().unwrap_or("");
+                Poll::Ready(Some(
+                    AssistantStreamEvent::parse(name, &event.data).map_err(|e| anyhow::anyhow!(e)),
+                ))
+            }
+            Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
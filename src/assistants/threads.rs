@@ -0,0 +1,79 @@
+//! See <https://platform.openai.com/docs/api-reference/threads>.
+//! Use with [Client::create_thread](crate::Client::create_thread), [Client::retrieve_thread](crate::Client::retrieve_thread),
+//! [Client::modify_thread](crate::Client::modify_thread) or [Client::delete_thread](crate::Client::delete_thread).
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::messages::MessageArguments;
+use super::ToolResources;
+
+/// Request arguments for creating a thread.
+///
+/// See <https://platform.openai.com/docs/api-reference/threads/createThread>.
+///
+/// ```
+/// use openai_rust2::assistants::{messages::MessageArguments, threads::ThreadArguments};
+/// let args = ThreadArguments {
+///     messages: vec![MessageArguments::new("user", "Hello, what is AI?")],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ThreadArguments {
+    /// A list of messages to start the thread with.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<MessageArguments>,
+
+    /// A set of resources that are made available to the assistant's tools in this thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Request arguments for modifying a thread.
+///
+/// See <https://platform.openai.com/docs/api-reference/threads/modifyThread>.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ModifyThreadArguments {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Represents a thread that contains messages.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"thread_abc123\",
+/// #  \"object\": \"thread\",
+/// #  \"created_at\": 1699012949,
+/// #  \"metadata\": {},
+/// #  \"tool_resources\": {}
+/// # }";
+/// # let thread = serde_json::from_str::<openai_rust2::assistants::threads::Thread>(json).unwrap();
+/// println!("{}", thread.id);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Thread {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the thread was created.
+    pub created_at: u64,
+    #[serde(default)]
+    pub tool_resources: Option<ToolResources>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+/// The response of a thread deletion request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteThreadResponse {
+    pub id: String,
+    pub deleted: bool,
+}
@@ -0,0 +1,283 @@
+//! See <https://platform.openai.com/docs/api-reference/audio>.
+//! Use with [Client::create_transcription](crate::Client::create_transcription),
+//! [Client::create_translation](crate::Client::create_translation),
+//! [Client::create_speech](crate::Client::create_speech) or [Client::create_speech_stream](crate::Client::create_speech_stream).
+
+use bytes::Bytes;
+use futures_util::Stream;
+use reqwest::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::task::Poll;
+
+/// Request arguments for audio transcriptions.
+///
+/// See <https://platform.openai.com/docs/api-reference/audio/createTranscription>.
+///
+/// ```
+/// let args = openai_rust2::audio::TranscriptionArguments::new(
+///     "whisper-1",
+///     "speech.mp3",
+///     std::fs::read("speech.mp3").unwrap_or_default(),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TranscriptionArguments {
+    /// The contents of the audio file to transcribe, in one of these formats: flac, mp3, mp4, mpeg, mpga, m4a, ogg, wav, or webm.
+    pub file: Vec<u8>,
+
+    /// The name of the audio file. The API uses its extension to detect the format.
+    pub filename: String,
+
+    /// ID of the model to use. Only `whisper-1` is currently available.
+    pub model: String,
+
+    /// The language of the input audio. Supplying the input language in [ISO-639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes) format will improve accuracy and latency.
+    pub language: Option<String>,
+
+    /// An optional text to guide the model's style or continue a previous audio segment.
+    /// The prompt should match the audio language.
+    pub prompt: Option<String>,
+
+    /// The sampling temperature, between 0 and 1. Higher values like 0.8 will make the output more random,
+    /// while lower values like 0.2 will make it more focused and deterministic.
+    /// If set to 0, the model will use log probability to automatically increase the temperature until certain thresholds are hit.
+    pub temperature: Option<f32>,
+}
+
+impl TranscriptionArguments {
+    pub fn new(
+        model: impl AsRef<str>,
+        filename: impl AsRef<str>,
+        file: Vec<u8>,
+    ) -> TranscriptionArguments {
+        TranscriptionArguments {
+            file,
+            filename: filename.as_ref().to_owned(),
+            model: model.as_ref().to_owned(),
+            language: None,
+            prompt: None,
+            temperature: None,
+        }
+    }
+
+    /// Build the multipart form sent to the API.
+    pub(crate) fn into_form(self) -> Form {
+        let mut form = Form::new()
+            .part("file", Part::bytes(self.file).file_name(self.filename))
+            .text("model", self.model);
+        if let Some(language) = self.language {
+            form = form.text("language", language);
+        }
+        if let Some(prompt) = self.prompt {
+            form = form.text("prompt", prompt);
+        }
+        if let Some(temperature) = self.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+        form
+    }
+}
+
+/// The response of a transcription request.
+///
+/// It implements [Display](std::fmt::Display) as a shortcut to easily extract the text.
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"text\": \"Imagine the wildest idea that you've ever had, and you're curious about how it might scale to something that's a 100, a 1,000 times bigger.\"
+/// # }";
+/// # let res = serde_json::from_str::<openai_rust2::audio::TranscriptionResponse>(json).unwrap();
+/// let text = &res.text;
+/// // or
+/// let text = res.to_string();
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct TranscriptionResponse {
+    pub text: String,
+}
+
+impl std::fmt::Display for TranscriptionResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)?;
+        Ok(())
+    }
+}
+
+/// Request arguments for audio translations.
+///
+/// See <https://platform.openai.com/docs/api-reference/audio/createTranslation>.
+///
+/// ```
+/// let args = openai_rust2::audio::TranslationArguments::new(
+///     "whisper-1",
+///     "german.m4a",
+///     std::fs::read("german.m4a").unwrap_or_default(),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TranslationArguments {
+    /// The contents of the audio file to translate, in one of these formats: flac, mp3, mp4, mpeg, mpga, m4a, ogg, wav, or webm.
+    pub file: Vec<u8>,
+
+    /// The name of the audio file. The API uses its extension to detect the format.
+    pub filename: String,
+
+    /// ID of the model to use. Only `whisper-1` is currently available.
+    pub model: String,
+
+    /// An optional text to guide the model's style or continue a previous audio segment.
+    /// The prompt should be in English.
+    pub prompt: Option<String>,
+
+    /// The sampling temperature, between 0 and 1. Higher values like 0.8 will make the output more random,
+    /// while lower values like 0.2 will make it more focused and deterministic.
+    /// If set to 0, the model will use log probability to automatically increase the temperature until certain thresholds are hit.
+    pub temperature: Option<f32>,
+}
+
+impl TranslationArguments {
+    pub fn new(
+        model: impl AsRef<str>,
+        filename: impl AsRef<str>,
+        file: Vec<u8>,
+    ) -> TranslationArguments {
+        TranslationArguments {
+            file,
+            filename: filename.as_ref().to_owned(),
+            model: model.as_ref().to_owned(),
+            prompt: None,
+            temperature: None,
+        }
+    }
+
+    /// Build the multipart form sent to the API.
+    pub(crate) fn into_form(self) -> Form {
+        let mut form = Form::new()
+            .part("file", Part::bytes(self.file).file_name(self.filename))
+            .text("model", self.model);
+        if let Some(prompt) = self.prompt {
+            form = form.text("prompt", prompt);
+        }
+        if let Some(temperature) = self.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+        form
+    }
+}
+
+/// The response of a translation request, containing the English text.
+///
+/// It implements [Display](std::fmt::Display) as a shortcut to easily extract the text.
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"text\": \"Hello, my name is Wolfgang and I come from Germany. Where are you heading today?\"
+/// # }";
+/// # let res = serde_json::from_str::<openai_rust2::audio::TranslationResponse>(json).unwrap();
+/// let text = &res.text;
+/// // or
+/// let text = res.to_string();
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct TranslationResponse {
+    pub text: String,
+}
+
+impl std::fmt::Display for TranslationResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)?;
+        Ok(())
+    }
+}
+
+/// The format of the audio generated by [crate::Client::create_speech].
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechResponseFormat {
+    Mp3,
+    Opus,
+    Aac,
+    Flac,
+    Wav,
+    Pcm,
+}
+
+/// Request arguments for text-to-speech.
+///
+/// See <https://platform.openai.com/docs/api-reference/audio/createSpeech>.
+///
+/// ```
+/// let args = openai_rust2::audio::SpeechArguments::new(
+///     "tts-1",
+///     "The quick brown fox jumped over the lazy dog.",
+///     "alloy",
+/// );
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct SpeechArguments {
+    /// One of the available [TTS models](https://platform.openai.com/docs/models/tts): `tts-1`, `tts-1-hd` or `gpt-4o-mini-tts`.
+    pub model: String,
+
+    /// The text to generate audio for. The maximum length is 4096 characters.
+    pub input: String,
+
+    /// The voice to use when generating the audio, such as `alloy`, `echo`, `fable`, `onyx`, `nova` or `shimmer`.
+    pub voice: String,
+
+    /// Control the voice of your generated audio with additional instructions. Does not work with `tts-1` or `tts-1-hd`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// The format to return the audio in. Defaults to `mp3`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<SpeechResponseFormat>,
+
+    /// The speed of the generated audio. Select a value from 0.25 to 4.0. Defaults to 1.0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f32>,
+}
+
+impl SpeechArguments {
+    pub fn new(
+        model: impl AsRef<str>,
+        input: impl AsRef<str>,
+        voice: impl AsRef<str>,
+    ) -> SpeechArguments {
+        SpeechArguments {
+            model: model.as_ref().to_owned(),
+            input: input.as_ref().to_owned(),
+            voice: voice.as_ref().to_owned(),
+            instructions: None,
+            response_format: None,
+            speed: None,
+        }
+    }
+}
+
+/// A stream of raw audio bytes returned by [crate::Client::create_speech_stream].
+///
+/// The chunks are yielded as they arrive from the network,
+/// so playback can start before the whole file has been generated.
+pub struct SpeechStream {
+    byte_stream: crate::sse::ByteStream,
+}
+
+impl SpeechStream {
+    pub(crate) fn new(stream: crate::sse::ByteStream) -> Self {
+        Self {
+            byte_stream: stream,
+        }
+    }
+}
+
+impl Stream for SpeechStream {
+    type Item = anyhow::Result<Bytes>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.byte_stream.as_mut().poll_next(cx)
+    }
+}
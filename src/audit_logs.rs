@@ -0,0 +1,367 @@
+//! See <https://platform.openai.com/docs/api-reference/audit-logs>.
+//! Use with [Client::list_audit_logs](crate::Client::list_audit_logs).
+//!
+//! Audit logs must be activated in the organization settings,
+//! and require an admin API key, see [Client::with_admin_key](crate::Client::with_admin_key).
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Query arguments for listing audit logs.
+///
+/// See <https://platform.openai.com/docs/api-reference/audit-logs/list>.
+///
+/// ```
+/// let mut args = openai_rust2::audit_logs::AuditLogArguments::default();
+/// args.event_types = vec!["api_key.created".to_owned(), "api_key.deleted".to_owned()];
+/// args.effective_at_gte = Some(1730419200);
+/// args.limit = Some(100);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AuditLogArguments {
+    /// Return only events effective after or at this Unix timestamp.
+    pub effective_at_gte: Option<u64>,
+    /// Return only events effective after this Unix timestamp.
+    pub effective_at_gt: Option<u64>,
+    /// Return only events effective before or at this Unix timestamp.
+    pub effective_at_lte: Option<u64>,
+    /// Return only events effective before this Unix timestamp.
+    pub effective_at_lt: Option<u64>,
+    /// Return only events for these projects.
+    pub project_ids: Vec<String>,
+    /// Return only events with these types, such as `api_key.created` or `user.added`.
+    pub event_types: Vec<String>,
+    /// Return only events performed by these actors: user, service account or API key IDs.
+    pub actor_ids: Vec<String>,
+    /// Return only events performed by users with these emails.
+    pub actor_emails: Vec<String>,
+    /// Return only events performed on these targets, such as a project ID.
+    pub resource_ids: Vec<String>,
+    /// A limit on the number of objects to be returned, between 1 and 100.
+    pub limit: Option<u32>,
+    /// A cursor for use in pagination. `after` is an object ID that defines your place in the list.
+    pub after: Option<String>,
+    /// A cursor for use in pagination. `before` is an object ID that defines your place in the list.
+    pub before: Option<String>,
+}
+
+impl AuditLogArguments {
+    /// The query string pairs, with arrays sent as repeated `name[]` parameters.
+    pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![];
+        let times = [
+            ("effective_at[gte]", self.effective_at_gte),
+            ("effective_at[gt]", self.effective_at_gt),
+            ("effective_at[lte]", self.effective_at_lte),
+            ("effective_at[lt]", self.effective_at_lt),
+        ];
+        for (name, time) in times {
+            if let Some(time) = time {
+                query.push((name, time.to_string()));
+            }
+        }
+        let arrays = [
+            ("project_ids[]", &self.project_ids),
+            ("event_types[]", &self.event_types),
+            ("actor_ids[]", &self.actor_ids),
+            ("actor_emails[]", &self.actor_emails),
+            ("resource_ids[]", &self.resource_ids),
+        ];
+        for (name, values) in arrays {
+            query.extend(values.iter().map(|v| (name, v.clone())));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(after) = &self.after {
+            query.push(("after", after.clone()));
+        }
+        if let Some(before) = &self.before {
+            query.push(("before", before.clone()));
+        }
+        query
+    }
+}
+
+/// A log of a user action or configuration change within the organization.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"id\": \"audit_log-xxx_yyyymmdd\",
+/// #   \"type\": \"project.archived\",
+/// #   \"effective_at\": 1722461446,
+/// #   \"actor\": {
+/// #     \"type\": \"api_key\",
+/// #     \"api_key\": {
+/// #       \"type\": \"user\",
+/// #       \"user\": { \"id\": \"user-xxx\", \"email\": \"user@example.com\" }
+/// #     }
+/// #   },
+/// #   \"project.archived\": { \"id\": \"proj_abc\" }
+/// # }";
+/// # let log = serde_json::from_str::<openai_rust2::audit_logs::AuditLog>(json).unwrap();
+/// use openai_rust2::audit_logs::AuditLogEvent;
+/// if let AuditLogEvent::ProjectArchived(project) = &log.event {
+///     println!("{} archived at {}", project.id, log.effective_at);
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "RawAuditLog")]
+pub struct AuditLog {
+    pub id: String,
+    /// The event type, such as `api_key.created`.
+    pub event_type: String,
+    /// The Unix timestamp (in seconds) of the event.
+    pub effective_at: u64,
+    /// The project that the action was scoped to, absent for actions not scoped to projects.
+    pub project: Option<AuditLogProject>,
+    pub actor: Option<AuditLogActor>,
+    /// The details of the event.
+    pub event: AuditLogEvent,
+}
+
+/// The audit log as sent by the API, with the event details under a key named after its type.
+#[derive(Deserialize)]
+struct RawAuditLog {
+    id: String,
+    #[serde(rename = "type")]
+    event_type: String,
+    effective_at: u64,
+    project: Option<AuditLogProject>,
+    actor: Option<AuditLogActor>,
+    #[serde(flatten)]
+    details: HashMap<String, serde_json::Value>,
+}
+
+impl From<RawAuditLog> for AuditLog {
+    fn from(mut raw: RawAuditLog) -> Self {
+        let details = raw.details.remove(&raw.event_type).unwrap_or_default();
+        AuditLog {
+            event: AuditLogEvent::parse(&raw.event_type, details),
+            id: raw.id,
+            event_type: raw.event_type,
+            effective_at: raw.effective_at,
+            project: raw.project,
+            actor: raw.actor,
+        }
+    }
+}
+
+/// The details of an [AuditLog], depending on its type.
+///
+/// Details that don't match their type are kept in [AuditLogEvent::Unknown], rather than failing the whole page of logs.
+/// ```
+/// # use serde_json;
+/// let json = "{
+///   \"id\": \"audit_log-xxx_yyyymmdd\",
+///   \"type\": \"login.failed\",
+///   \"effective_at\": 1722461446
+/// }";
+/// let log = serde_json::from_str::<openai_rust2::audit_logs::AuditLog>(json).unwrap();
+/// assert!(matches!(log.event, openai_rust2::audit_logs::AuditLogEvent::Unknown(serde_json::Value::Null)));
+/// ```
+#[derive(Debug, Clone)]
+pub enum AuditLogEvent {
+    /// `api_key.created`
+    ApiKeyCreated(ApiKeyCreated),
+    /// `api_key.updated`
+    ApiKeyUpdated(ResourceUpdated),
+    /// `api_key.deleted`
+    ApiKeyDeleted(Resource),
+    /// `invite.sent`
+    InviteSent(InviteSent),
+    /// `invite.accepted`
+    InviteAccepted(Resource),
+    /// `invite.deleted`
+    InviteDeleted(Resource),
+    /// `login.succeeded`
+    LoginSucceeded,
+    /// `login.failed`
+    LoginFailed(AuthFailure),
+    /// `logout.succeeded`
+    LogoutSucceeded,
+    /// `logout.failed`
+    LogoutFailed(AuthFailure),
+    /// `organization.updated`
+    OrganizationUpdated(ResourceUpdated),
+    /// `project.created`
+    ProjectCreated(ProjectCreated),
+    /// `project.updated`
+    ProjectUpdated(ResourceUpdated),
+    /// `project.archived`
+    ProjectArchived(Resource),
+    /// `service_account.created`
+    ServiceAccountCreated(RoleAssigned),
+    /// `service_account.updated`
+    ServiceAccountUpdated(ResourceUpdated),
+    /// `service_account.deleted`
+    ServiceAccountDeleted(Resource),
+    /// `user.added`
+    UserAdded(RoleAssigned),
+    /// `user.updated`
+    UserUpdated(ResourceUpdated),
+    /// `user.deleted`
+    UserDeleted(Resource),
+    /// An event type not known to this crate, or details that don't match their type.
+    Unknown(serde_json::Value),
+}
+
+impl AuditLogEvent {
+    fn parse(event_type: &str, details: serde_json::Value) -> AuditLogEvent {
+        use AuditLogEvent::*;
+        let event = match event_type {
+            "api_key.created" => Deserialize::deserialize(&details).map(ApiKeyCreated),
+            "api_key.updated" => Deserialize::deserialize(&details).map(ApiKeyUpdated),
+            "api_key.deleted" => Deserialize::deserialize(&details).map(ApiKeyDeleted),
+            "invite.sent" => Deserialize::deserialize(&details).map(InviteSent),
+            "invite.accepted" => Deserialize::deserialize(&details).map(InviteAccepted),
+            "invite.deleted" => Deserialize::deserialize(&details).map(InviteDeleted),
+            "login.succeeded" => Ok(LoginSucceeded),
+            "login.failed" => Deserialize::deserialize(&details).map(LoginFailed),
+            "logout.succeeded" => Ok(LogoutSucceeded),
+            "logout.failed" => Deserialize::deserialize(&details).map(LogoutFailed),
+            "organization.updated" => Deserialize::deserialize(&details).map(OrganizationUpdated),
+            "project.created" => Deserialize::deserialize(&details).map(ProjectCreated),
+            "project.updated" => Deserialize::deserialize(&details).map(ProjectUpdated),
+            "project.archived" => Deserialize::deserialize(&details).map(ProjectArchived),
+            "service_account.created" => {
+                Deserialize::deserialize(&details).map(ServiceAccountCreated)
+            }
+            "service_account.updated" => {
+                Deserialize::deserialize(&details).map(ServiceAccountUpdated)
+            }
+            "service_account.deleted" => {
+                Deserialize::deserialize(&details).map(ServiceAccountDeleted)
+            }
+            "user.added" => Deserialize::deserialize(&details).map(UserAdded),
+            "user.updated" => Deserialize::deserialize(&details).map(UserUpdated),
+            "user.deleted" => Deserialize::deserialize(&details).map(UserDeleted),
+            _ => return Unknown(details),
+        };
+        event.unwrap_or(Unknown(details))
+    }
+}
+
+/// The project an [AuditLog] is scoped to.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AuditLogProject {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+/// The actor who performed the audit logged action.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AuditLogActor {
+    /// `session` or `api_key`.
+    #[serde(rename = "type")]
+    pub actor_type: String,
+    /// Set when the action was performed from the dashboard.
+    pub session: Option<AuditLogSession>,
+    /// Set when the action was performed through the API.
+    pub api_key: Option<AuditLogApiKey>,
+}
+
+/// The session in which an audit logged action was performed.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AuditLogSession {
+    pub user: Option<AuditLogUser>,
+    pub ip_address: Option<String>,
+}
+
+/// The API key with which an audit logged action was performed.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AuditLogApiKey {
+    pub id: Option<String>,
+    /// `user` or `service_account`.
+    #[serde(rename = "type")]
+    pub key_type: Option<String>,
+    pub user: Option<AuditLogUser>,
+    pub service_account: Option<Resource>,
+}
+
+/// The user who performed an audit logged action.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AuditLogUser {
+    pub id: String,
+    pub email: Option<String>,
+}
+
+/// The resource an event applies to.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Resource {
+    pub id: String,
+}
+
+/// A resource that was updated.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ResourceUpdated {
+    pub id: String,
+    /// The payload used to update the resource.
+    pub changes_requested: Option<serde_json::Value>,
+}
+
+/// The details of an `api_key.created` event.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiKeyCreated {
+    /// The tracking ID of the API key.
+    pub id: String,
+    pub data: Option<ApiKeyCreatedData>,
+}
+
+/// The payload used to create an API key.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiKeyCreatedData {
+    /// A list of scopes allowed for the API key, e.g. `["api.model.request"]`.
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// The details of an `invite.sent` event.
+#[derive(Deserialize, Debug, Clone)]
+pub struct InviteSent {
+    pub id: String,
+    pub data: Option<InviteSentData>,
+}
+
+/// The payload used to create an invite.
+#[derive(Deserialize, Debug, Clone)]
+pub struct InviteSentData {
+    pub email: String,
+    /// `owner` or `member`.
+    pub role: Option<String>,
+}
+
+/// The details of a `project.created` event.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProjectCreated {
+    pub id: String,
+    pub data: Option<ProjectCreatedData>,
+}
+
+/// The payload used to create a project.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProjectCreatedData {
+    pub name: Option<String>,
+    pub title: Option<String>,
+}
+
+/// The details of a `user.added` or `service_account.created` event.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RoleAssigned {
+    pub id: String,
+    pub data: Option<RoleAssignedData>,
+}
+
+/// The role given to a user or service account.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RoleAssignedData {
+    pub role: String,
+}
+
+/// The details of a failed login or logout.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AuthFailure {
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
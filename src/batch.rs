@@ -0,0 +1,445 @@
+//! See <https://platform.openai.com/docs/api-reference/batch>.
+//! Use with [Client::create_batch](crate::Client::create_batch), [Client::retrieve_batch](crate::Client::retrieve_batch),
+//! [Client::cancel_batch](crate::Client::cancel_batch), [Client::list_batches](crate::Client::list_batches)
+//! or [Client::wait_for_batch](crate::Client::wait_for_batch).
+//!
+//! The input file of a batch can be built with [BatchInputBuilder],
+//! and its output file parsed with [BatchOutputReader].
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::marker::PhantomData;
+
+use anyhow::anyhow;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::chat::{ChatArguments, ChatCompletion};
+use crate::embeddings::EmbeddingsArguments;
+use crate::files::{FilePurpose, UploadFileArguments};
+
+/// Request arguments for creating a batch.
+///
+/// See <https://platform.openai.com/docs/api-reference/batch/create>.
+///
+/// ```
+/// let args = openai_rust2::batch::BatchArguments::new("file-abc123", "/v1/chat/completions");
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct BatchArguments {
+    /// The ID of an uploaded file that contains requests for the new batch.
+    ///
+    /// See [upload file](crate::Client::upload_file) for how to upload a file.
+    /// Your input file must be formatted as a JSONL file, and must be uploaded with the purpose `batch`.
+    pub input_file_id: String,
+
+    /// The endpoint to be used for all requests in the batch.
+    /// Currently `/v1/responses`, `/v1/chat/completions`, `/v1/embeddings`, and `/v1/completions` are supported.
+    pub endpoint: String,
+
+    /// The time frame within which the batch should be processed. Currently only `24h` is supported.
+    pub completion_window: String,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl BatchArguments {
+    pub fn new(input_file_id: impl AsRef<str>, endpoint: impl AsRef<str>) -> BatchArguments {
+        BatchArguments {
+            input_file_id: input_file_id.as_ref().to_owned(),
+            endpoint: endpoint.as_ref().to_owned(),
+            completion_window: "24h".to_owned(),
+            metadata: None,
+        }
+    }
+}
+
+/// The status of a [Batch].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+    /// The input file is being validated before the batch can begin.
+    Validating,
+    /// The input file has failed the validation process.
+    Failed,
+    /// The input file was successfully validated and the batch is currently being run.
+    InProgress,
+    /// The batch has completed and the results are being prepared.
+    Finalizing,
+    /// The batch has been completed and the results are ready.
+    Completed,
+    /// The batch was not able to be completed within the 24-hour time window.
+    Expired,
+    /// The batch is being cancelled (may take up to 10 minutes).
+    Cancelling,
+    /// The batch was cancelled.
+    Cancelled,
+}
+
+impl BatchStatus {
+    /// Whether the batch has stopped and will not change status anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            BatchStatus::Failed
+                | BatchStatus::Completed
+                | BatchStatus::Expired
+                | BatchStatus::Cancelled
+        )
+    }
+}
+
+/// A batch of requests processed asynchronously.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"batch_abc123\",
+/// #  \"object\": \"batch\",
+/// #  \"endpoint\": \"/v1/completions\",
+/// #  \"errors\": null,
+/// #  \"input_file_id\": \"file-abc123\",
+/// #  \"completion_window\": \"24h\",
+/// #  \"status\": \"completed\",
+/// #  \"output_file_id\": \"file-cvaTdG\",
+/// #  \"error_file_id\": \"file-HOWS94\",
+/// #  \"created_at\": 1711471533,
+/// #  \"in_progress_at\": 1711471538,
+/// #  \"expires_at\": 1711557933,
+/// #  \"finalizing_at\": 1711493133,
+/// #  \"completed_at\": 1711493163,
+/// #  \"failed_at\": null,
+/// #  \"expired_at\": null,
+/// #  \"cancelling_at\": null,
+/// #  \"cancelled_at\": null,
+/// #  \"request_counts\": {
+/// #    \"total\": 100,
+/// #    \"completed\": 95,
+/// #    \"failed\": 5
+/// #  },
+/// #  \"metadata\": {
+/// #    \"customer_id\": \"user_123456789\",
+/// #    \"batch_description\": \"Nightly eval job\"
+/// #  }
+/// # }";
+/// # let batch = serde_json::from_str::<openai_rust2::batch::Batch>(json).unwrap();
+/// println!("{} is {:?}", batch.id, batch.status);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Batch {
+    pub id: String,
+    /// The OpenAI API endpoint used by the batch.
+    pub endpoint: String,
+    #[serde(default)]
+    pub errors: Option<BatchErrors>,
+    /// The ID of the input file for the batch.
+    pub input_file_id: String,
+    /// The time frame within which the batch should be processed.
+    pub completion_window: String,
+    /// The current status of the batch.
+    pub status: BatchStatus,
+    /// The ID of the file containing the outputs of successfully executed requests.
+    pub output_file_id: Option<String>,
+    /// The ID of the file containing the outputs of requests with errors.
+    pub error_file_id: Option<String>,
+    /// The Unix timestamp (in seconds) for when the batch was created.
+    pub created_at: u64,
+    pub in_progress_at: Option<u64>,
+    pub expires_at: Option<u64>,
+    pub finalizing_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    pub failed_at: Option<u64>,
+    pub expired_at: Option<u64>,
+    pub cancelling_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+    /// The request counts for different statuses within the batch.
+    #[serde(default)]
+    pub request_counts: Option<RequestCounts>,
+    #[serde(default)]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// The validation errors of a [Batch].
+#[derive(Deserialize, Debug, Clone)]
+pub struct BatchErrors {
+    pub data: Vec<BatchError>,
+}
+
+/// A validation error of a [Batch].
+#[derive(Deserialize, Debug, Clone)]
+pub struct BatchError {
+    /// An error code identifying the error type.
+    pub code: Option<String>,
+    /// A human-readable message providing more details about the error.
+    pub message: Option<String>,
+    /// The name of the parameter that caused the error, if applicable.
+    pub param: Option<String>,
+    /// The line number of the input file where the error occurred, if applicable.
+    pub line: Option<u64>,
+}
+
+/// The number of requests in a [Batch], per status.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RequestCounts {
+    pub total: u32,
+    pub completed: u32,
+    pub failed: u32,
+}
+
+/// A single line of a batch input file.
+///
+/// See <https://platform.openai.com/docs/api-reference/batch/request-input>.
+#[derive(Serialize, Debug, Clone)]
+pub struct BatchRequest {
+    /// A developer-provided per-request id that will be used to match outputs to inputs. Must be unique for each request in a batch.
+    pub custom_id: String,
+    /// The HTTP method to be used for the request. Currently only `POST` is supported.
+    pub method: String,
+    /// The OpenAI API relative URL to be used for the request.
+    pub url: String,
+    /// The body of the request.
+    pub body: BatchRequestBody,
+}
+
+/// The body of a [BatchRequest].
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum BatchRequestBody {
+    Chat(ChatArguments),
+    Embeddings(EmbeddingsArguments),
+}
+
+/// Builds the JSONL input file of a batch.
+///
+/// ```
+/// use openai_rust2::batch::BatchInputBuilder;
+/// use openai_rust2::chat::{ChatArguments, Message};
+/// let args = ChatArguments::new("gpt-4o-mini", vec![
+///     Message::user("Hello GPT!")
+/// ]);
+/// let jsonl = BatchInputBuilder::new()
+///     .chat("request-1", args.clone())
+///     .chat("request-2", args)
+///     .build()
+///     .unwrap();
+/// assert_eq!(jsonl.iter().filter(|b| **b == b'\n').count(), 2);
+/// ```
+///
+/// Use [BatchInputBuilder::into_upload_arguments] to upload it with [crate::Client::upload_file].
+#[derive(Debug, Clone, Default)]
+pub struct BatchInputBuilder {
+    requests: Vec<BatchRequest>,
+}
+
+impl BatchInputBuilder {
+    pub fn new() -> BatchInputBuilder {
+        BatchInputBuilder::default()
+    }
+
+    /// Add a request for the `/v1/chat/completions` endpoint.
+    ///
+    /// Like [crate::Client::create_chat], `max_tokens` is sent as `max_completion_tokens` to the reasoning models.
+    /// ```
+    /// # use openai_rust2::batch::BatchInputBuilder;
+    /// # use openai_rust2::chat::ChatArguments;
+    /// let mut args = ChatArguments::new("o3-mini", vec![]);
+    /// args.max_tokens = Some(100);
+    /// let jsonl = BatchInputBuilder::new().chat("request-1", args).build().unwrap();
+    /// let line = String::from_utf8(jsonl).unwrap();
+    /// assert!(line.contains("\"max_completion_tokens\":100"));
+    /// assert!(!line.contains("\"max_tokens\""));
+    /// ```
+    pub fn chat(self, custom_id: impl AsRef<str>, args: ChatArguments) -> BatchInputBuilder {
+        self.request(
+            custom_id,
+            "/v1/chat/completions",
+            BatchRequestBody::Chat(args.with_token_limit_for_model()),
+        )
+    }
+
+    /// Add a request for the `/v1/embeddings` endpoint.
+    pub fn embeddings(
+        self,
+        custom_id: impl AsRef<str>,
+        args: EmbeddingsArguments,
+    ) -> BatchInputBuilder {
+        self.request(
+            custom_id,
+            "/v1/embeddings",
+            BatchRequestBody::Embeddings(args),
+        )
+    }
+
+    /// Add a request for a custom endpoint url.
+    pub fn request(
+        mut self,
+        custom_id: impl AsRef<str>,
+        url: impl AsRef<str>,
+        body: BatchRequestBody,
+    ) -> BatchInputBuilder {
+        self.requests.push(BatchRequest {
+            custom_id: custom_id.as_ref().to_owned(),
+            method: "POST".to_owned(),
+            url: url.as_ref().to_owned(),
+            body,
+        });
+        self
+    }
+
+    /// The endpoint targeted by the requests, to be used as [BatchArguments::endpoint].
+    pub fn endpoint(&self) -> Option<&str> {
+        self.requests.first().map(|r| r.url.as_str())
+    }
+
+    /// Serialize the requests into JSONL.
+    ///
+    /// Fails if there are no requests, if a `custom_id` is used twice,
+    /// or if the requests don't all target the same endpoint.
+    pub fn build(self) -> anyhow::Result<Vec<u8>> {
+        let endpoint = self
+            .endpoint()
+            .ok_or_else(|| anyhow!("a batch needs at least one request"))?
+            .to_owned();
+
+        let mut custom_ids = HashSet::new();
+        let mut jsonl = vec![];
+        for request in &self.requests {
+            if !custom_ids.insert(request.custom_id.as_str()) {
+                return Err(anyhow!("duplicate custom_id: {}", request.custom_id));
+            }
+            if request.url != endpoint {
+                return Err(anyhow!(
+                    "all requests of a batch must use the same endpoint, found {} and {}",
+                    endpoint,
+                    request.url
+                ));
+            }
+            serde_json::to_writer(&mut jsonl, request)?;
+            jsonl.push(b'\n');
+        }
+        Ok(jsonl)
+    }
+
+    /// Build the input file and wrap it in [UploadFileArguments] with the `batch` purpose.
+    pub fn into_upload_arguments(
+        self,
+        filename: impl AsRef<str>,
+    ) -> anyhow::Result<UploadFileArguments> {
+        Ok(UploadFileArguments::new(
+            filename,
+            self.build()?,
+            FilePurpose::Batch,
+        ))
+    }
+}
+
+/// A single line of a batch output or error file.
+///
+/// See <https://platform.openai.com/docs/api-reference/batch/request-output>.
+#[derive(Deserialize, Debug, Clone)]
+pub struct BatchOutputLine {
+    pub id: String,
+    /// The `custom_id` of the matching [BatchRequest].
+    pub custom_id: String,
+    pub response: Option<BatchOutputResponse>,
+    /// For requests that failed with a non-HTTP error, this will contain more information on the cause of the failure.
+    pub error: Option<BatchOutputError>,
+}
+
+/// The HTTP response of a request in a batch.
+#[derive(Deserialize, Debug, Clone)]
+pub struct BatchOutputResponse {
+    pub status_code: u16,
+    pub request_id: Option<String>,
+    /// The JSON body of the response, as returned by the endpoint.
+    pub body: serde_json::Value,
+}
+
+/// The error of a request that failed with a non-HTTP error.
+#[derive(Deserialize, Debug, Clone)]
+pub struct BatchOutputError {
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+impl BatchOutputLine {
+    /// Deserialize the response body into `T`,
+    /// or return an error if the request failed.
+    pub fn into_result<T: DeserializeOwned>(self) -> anyhow::Result<T> {
+        if let Some(error) = self.error {
+            return Err(anyhow!(
+                "{}: {}",
+                error.code.unwrap_or_default(),
+                error.message.unwrap_or_default()
+            ));
+        }
+        let response = self
+            .response
+            .ok_or_else(|| anyhow!("no response for request {}", self.custom_id))?;
+        if response.status_code != 200 {
+            return Err(anyhow!(response.body.to_string()));
+        }
+        Ok(serde_json::from_value(response.body)?)
+    }
+}
+
+/// Reads a batch output file line by line, yielding each `custom_id` with its typed result.
+///
+/// The outer [Result](anyhow::Result) fails on I/O errors or malformed lines,
+/// the inner one when the request itself failed.
+/// The responses are parsed as [ChatCompletion] by default,
+/// use e.g. `BatchOutputReader<_, EmbeddingsResponse>` for other endpoints.
+///
+/// ```
+/// use openai_rust2::batch::BatchOutputReader;
+/// let output = r#"{"id": "batch_req_1", "custom_id": "request-1", "response": {"status_code": 400, "request_id": "req_1", "body": {"error": {"message": "Invalid model"}}}, "error": null}
+/// {"id": "batch_req_2", "custom_id": "request-2", "response": null, "error": {"code": "batch_expired", "message": "This request could not be executed before the completion window expired."}}
+/// "#;
+/// let reader: BatchOutputReader<_> = BatchOutputReader::new(output.as_bytes());
+/// for line in reader {
+///     let (custom_id, result) = line.unwrap();
+///     assert!(custom_id.starts_with("request-"));
+///     assert!(result.is_err());
+/// }
+/// ```
+///
+/// The output file can be downloaded with [crate::Client::retrieve_file_content_into].
+pub struct BatchOutputReader<R, T = ChatCompletion> {
+    reader: R,
+    line: String,
+    _response: PhantomData<T>,
+}
+
+impl<R: BufRead, T: DeserializeOwned> BatchOutputReader<R, T> {
+    pub fn new(reader: R) -> BatchOutputReader<R, T> {
+        BatchOutputReader {
+            reader,
+            line: String::new(),
+            _response: PhantomData,
+        }
+    }
+}
+
+impl<R: BufRead, T: DeserializeOwned> Iterator for BatchOutputReader<R, T> {
+    type Item = anyhow::Result<(String, anyhow::Result<T>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) if self.line.trim().is_empty() => continue,
+                Ok(_) => {
+                    return Some(
+                        serde_json::from_str::<BatchOutputLine>(&self.line)
+                            .map(|line| (line.custom_id.clone(), line.into_result()))
+                            .map_err(|e| e.into()),
+                    )
+                }
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
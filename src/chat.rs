@@ -1,24 +1,39 @@
 //! See <https://platform.openai.com/docs/api-reference/chat>.
-//! Use with [Client::create_chat](crate::Client::create_chat) or [Client::create_chat_stream](crate::Client::create_chat_stream).
+//! Use with [Client::create_chat](crate::Client::create_chat), [Client::create_chat_stream](crate::Client::create_chat_stream)
+//! or [Client::create_chat_with_tools](crate::Client::create_chat_with_tools).
+//! A [Conversation] keeps the message history between requests.
+
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+mod conversation;
+pub mod tools;
+
+pub use conversation::Conversation;
+
 /// Request arguments for chat completion.
 ///
 /// See <https://platform.openai.com/docs/api-reference/chat/create>.
 ///
 /// ```
 /// let args = openai_rust2::chat::ChatArguments::new("gpt-3.5-turbo", vec![
-///     openai_rust2::chat::Message {
-///         role: "user".to_owned(),
-///         content: "Hello GPT!".to_owned(),
-///     }
+///     openai_rust2::chat::Message::user("Hello GPT!")
 /// ]);
 /// ```
 ///
+/// Or with [ChatArguments::builder]:
+/// ```
+/// let args = openai_rust2::chat::ChatArguments::builder("gpt-4o")
+///     .temperature(0.2)
+///     .max_tokens(500)
+///     .message(openai_rust2::chat::Message::user("Hello GPT!"))
+///     .build();
+/// ```
+///
 /// To use streaming, use [crate::Client::create_chat_stream].
 ///
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ChatArguments {
     /// ID of the model to use. See the model [endpoint compatibility table](https://platform.openai.com/docs/models/model-endpoint-compatibility) for details on which models work with the Chat API.
     pub model: String,
@@ -38,6 +53,9 @@ pub struct ChatArguments {
     pub top_p: Option<f32>,
 
     /// How many chat completion choices to generate for each input message.
+    /// Each one is a separate [Choice] of the [ChatCompletion], in the order of its [Choice::index].
+    ///
+    /// You are charged for the generated tokens of all of the choices.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub n: Option<u32>,
 
@@ -46,15 +64,31 @@ pub struct ChatArguments {
     pub(crate) stream: Option<bool>,
 
     /// Up to 4 sequences where the API will stop generating further tokens.
+    ///
+    /// ```
+    /// # let mut args = openai_rust2::chat::ChatArguments::new("gpt-4o-mini", vec![]);
+    /// args.stop = Some("\n".into());
+    /// args.stop = Some(vec!["\n".to_owned(), "END".to_owned()].into());
+    /// ```
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop: Option<String>,
+    pub stop: Option<Stop>,
 
     /// The maximum number of [tokens](https://platform.openai.com/tokenizer) to generate in the chat completion.
     ///
     /// The total length of input tokens and generated tokens is limited by the model's context length.
+    ///
+    /// The reasoning models (`o1`, `o3`, `o4-mini`, `gpt-5`...) reject this field, so for them it is sent
+    /// as [ChatArguments::max_completion_tokens] instead.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_tokens: Option<u32>,
 
+    /// An upper bound for the number of tokens that can be generated for a completion,
+    /// including visible output tokens and reasoning tokens.
+    ///
+    /// Replaces `max_tokens` on OpenAI, but OpenAI-compatible servers may only support `max_tokens`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_completion_tokens: Option<u32>,
+
     /// Number between -2.0 and 2.0. Positive values penalize new tokens based on whether they appear in the text so far,
     /// increasing the model's likelihood to talk about new topics.
     ///
@@ -64,14 +98,108 @@ pub struct ChatArguments {
 
     /// Number between -2.0 and 2.0. Positive values penalize new tokens based on their existing frequency in the text so far,
     /// decreasing the model's likelihood to repeat the same line verbatim.
+    ///
+    /// [See more information about frequency and presence penalties.](https://platform.openai.com/docs/api-reference/parameter-details)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub frequency_penalty: Option<f32>,
 
-    // logit_bias
+    /// Modify the likelihood of specified tokens appearing in the completion.
+    ///
+    /// Maps token IDs (as strings) to a bias value from -100 to 100. Values between -1 and 1 decrease or increase
+    /// the likelihood of selection, while values like -100 or 100 result in a ban or exclusive selection of the token.
+    ///
+    /// ```
+    /// # let mut args = openai_rust2::chat::ChatArguments::new("gpt-4o-mini", vec![]);
+    /// // Ban the token `50256`, which is <|endoftext|>
+    /// args.logit_bias = Some([("50256".to_owned(), -100.0)].into());
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logit_bias: Option<HashMap<String, f32>>,
+
     /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
     /// [Learn more](https://platform.openai.com/docs/guides/safety-best-practices/end-user-ids).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
+
+    /// A list of tools the model may call. Currently, only functions are supported as a tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<ChatTool>>,
+
+    /// Controls which (if any) tool is called by the model.
+    /// Defaults to [ToolChoice::None] when no tools are present and [ToolChoice::Auto] otherwise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ToolChoice>,
+
+    /// Whether to enable parallel function calling during tool use.
+    /// When enabled, a single [Message] can hold several [Message::tool_calls], which should all be answered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+
+    /// Deprecated in favor of [ChatArguments::tools], for servers that only support the legacy function calling.
+    ///
+    /// A list of functions the model may generate JSON inputs for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub functions: Option<Vec<FunctionDefinition>>,
+
+    /// Deprecated in favor of [ChatArguments::tool_choice].
+    ///
+    /// Controls which (if any) of [ChatArguments::functions] is called by the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_call: Option<FunctionCallChoice>,
+
+    /// The format that the model must output.
+    ///
+    /// With [ResponseFormat::JsonObject] ("JSON mode"), you must also instruct the model to produce JSON
+    /// yourself via a system or user message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ResponseFormat>,
+
+    /// Whether to return log probabilities of the output tokens in [Choice::logprobs].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<bool>,
+
+    /// An integer between 0 and 20 specifying the number of most likely tokens to return at each token position,
+    /// each with an associated log probability. `logprobs` must be set to `true` if this parameter is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_logprobs: Option<u8>,
+
+    /// If specified, the system will make a best effort to sample deterministically,
+    /// such that repeated requests with the same `seed` and parameters should return the same result.
+    ///
+    /// Determinism is not guaranteed, compare the [ChatCompletion::system_fingerprint] of the responses to monitor changes in the backend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i64>,
+
+    /// Options for streaming responses, only used by [crate::Client::create_chat_stream].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_options: Option<StreamOptions>,
+
+    /// How much the [reasoning models](https://platform.openai.com/docs/guides/reasoning) think before answering,
+    /// less effort gives faster responses with fewer reasoning tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning_effort: Option<ReasoningEffort>,
+
+    /// Content that is expected in the response, such as a file being edited,
+    /// to generate it faster with [Predicted Outputs](https://platform.openai.com/docs/guides/predicted-outputs).
+    /// The matching tokens are counted by [CompletionTokensDetails::accepted_prediction_tokens].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prediction: Option<Prediction>,
+
+    /// Store the completion for 30 days, to show it in the dashboard and use it for [model distillation](https://platform.openai.com/docs/guides/distillation) or evals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<bool>,
+
+    /// Tags to filter the stored completions in the dashboard, up to 16 pairs, see [ChatArguments::store].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// The capacity pool serving the request, the one actually used is echoed in [ChatCompletion::service_tier].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_tier: Option<ServiceTier>,
+
+    /// Search the web before answering, only with the search models such as `gpt-4o-search-preview`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_search_options: Option<WebSearchOptions>,
 }
 
 impl ChatArguments {
@@ -85,260 +213,3048 @@ impl ChatArguments {
             stream: None,
             stop: None,
             max_tokens: None,
+            max_completion_tokens: None,
             presence_penalty: None,
             frequency_penalty: None,
+            logit_bias: None,
             user: None,
+            tools: None,
+            tool_choice: None,
+            parallel_tool_calls: None,
+            functions: None,
+            function_call: None,
+            response_format: None,
+            logprobs: None,
+            top_logprobs: None,
+            seed: None,
+            stream_options: None,
+            reasoning_effort: None,
+            prediction: None,
+            store: None,
+            metadata: None,
+            service_tier: None,
+            web_search_options: None,
         }
     }
-}
-
-/// This is the response of a chat.
-///
-/// It implements [Display](std::fmt::Display) as a shortcut to easily extract the content.
-/// ```
-/// # use serde_json;
-/// # let json = "{
-/// #  \"id\": \"chatcmpl-123\",
-/// #  \"object\": \"chat.completion\",
-/// #  \"created\": 1677652288,
-/// #  \"choices\": [{
-/// #    \"index\": 0,
-/// #    \"message\": {
-/// #     \"role\": \"assistant\",
-/// #     \"content\": \"\\n\\nHello there, how may I assist you today?\"
-/// #    },
-/// #    \"finish_reason\": \"stop\"
-/// #  }],
-/// #  \"usage\": {
-/// #   \"prompt_tokens\": 9,
-/// #   \"completion_tokens\": 12,
-/// #   \"total_tokens\": 21
-/// #  }
-/// # }";
-/// # let res = serde_json::from_str::<openai_rust2::chat::ChatCompletion>(json).unwrap();
-/// let msg = &res.choices[0].message.content;
-/// // or
-/// let msg = res.to_string();
-/// ```
-#[derive(Deserialize, Debug, Clone)]
-pub struct ChatCompletion {
-    // Make id optional
-    #[serde(default)]
-    pub id: Option<String>,
-    pub created: u32,
-    #[serde(default)]
-    pub model: Option<String>,
-    #[serde(default)]
-    pub object: Option<String>,
-    pub choices: Vec<Choice>,
-    pub usage: Usage,
-}
 
-impl std::fmt::Display for ChatCompletion {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", &self.choices[0].message.content)?;
-        Ok(())
+    /// A [ChatArgumentsBuilder] for `model`, without any messages yet.
+    pub fn builder(model: impl AsRef<str>) -> ChatArgumentsBuilder {
+        ChatArgumentsBuilder {
+            args: ChatArguments::new(model, vec![]),
+        }
     }
-}
 
-/// Structs and deserialization method for the responses
-/// when using streaming chat responses.
-pub mod stream {
-    use bytes::Bytes;
-    use futures_util::Stream;
-    use serde::Deserialize;
-    use std::pin::Pin;
-    use std::str;
-    use std::task::Poll;
+    /// Send `max_tokens` as `max_completion_tokens` to the reasoning models, which reject `max_tokens`.
+    pub(crate) fn with_token_limit_for_model(mut self) -> Self {
+        if uses_max_completion_tokens(&self.model) {
+            if let Some(max_tokens) = self.max_tokens.take() {
+                self.max_completion_tokens.get_or_insert(max_tokens);
+            }
+        }
+        self
+    }
 
-    /// This is the partial chat result received when streaming.
+    /// Turn the `system` messages into `developer` messages if `model` expects them, as the reasoning models do.
     ///
-    /// It implements [Display](std::fmt::Display) as a shortcut to easily extract the content.
     /// ```
-    /// # use serde_json;
-    /// # let json = "{
-    /// # \"id\": \"chatcmpl-6yX67cSCIAm4nrNLQUPOtJu9JUoLG\",
-    /// # \"object\": \"chat.completion.chunk\",
-    /// # \"created\": 1679884927,
-    /// # \"model\": \"gpt-3.5-turbo-0301\",
-    /// # \"choices\": [
-    /// #   {
-    /// #     \"delta\": {
-    /// #       \"content\": \" today\"
-    /// #     },
-    /// #     \"index\": 0,
-    /// #     \"finish_reason\": null
-    /// #   }
-    /// # ]
-    /// # }";
-    /// # let res = serde_json::from_str::<openai_rust2::chat::stream::ChatCompletionChunk>(json).unwrap();
-    /// let msg = &res.choices[0].delta.content;
-    /// // or
-    /// let msg = res.to_string();
+    /// use openai_rust2::chat::{ChatArguments, Message, Role};
+    /// let messages = vec![
+    ///     Message::system("You are a helpful assistant."),
+    ///     Message::user("Hello GPT!"),
+    /// ];
+    /// let args = ChatArguments::new("o3-mini", messages.clone()).with_developer_role_for_model();
+    /// assert_eq!(args.messages[0].role, Role::Developer);
+    /// let args = ChatArguments::new("gpt-4o-mini", messages).with_developer_role_for_model();
+    /// assert_eq!(args.messages[0].role, Role::System);
     /// ```
-    #[derive(Deserialize, Debug, Clone)]
-    pub struct ChatCompletionChunk {
-        pub id: String,
-        pub created: u32,
-        pub model: String,
-        pub choices: Vec<Choice>,
-        pub system_fingerprint: Option<String>,
+    pub fn with_developer_role_for_model(mut self) -> Self {
+        if uses_developer_role(&self.model) {
+            for message in &mut self.messages {
+                if message.role == Role::System {
+                    message.role = Role::Developer;
+                }
+            }
+        }
+        self
     }
+}
 
-    impl std::fmt::Display for ChatCompletionChunk {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(
-                f,
-                "{}",
-                self.choices[0].delta.content.as_ref().unwrap_or(&"".into())
-            )?;
-            Ok(())
-        }
+/// A fluent builder of [ChatArguments], created with [ChatArguments::builder].
+#[derive(Debug, Clone)]
+pub struct ChatArgumentsBuilder {
+    args: ChatArguments,
+}
+
+impl ChatArgumentsBuilder {
+    /// Appends a message.
+    pub fn message(mut self, message: Message) -> ChatArgumentsBuilder {
+        self.args.messages.push(message);
+        self
     }
 
-    /// Choices for [super::ChatCompletion].
-    #[derive(Deserialize, Debug, Clone)]
-    pub struct Choice {
-        pub delta: ChoiceDelta,
-        pub index: u32,
-        pub finish_reason: Option<String>,
+    /// Appends several messages.
+    pub fn messages(mut self, messages: impl IntoIterator<Item = Message>) -> ChatArgumentsBuilder {
+        self.args.messages.extend(messages);
+        self
     }
 
-    /// Additional data from [Choice].
-    #[derive(Deserialize, Debug, Clone)]
-    pub struct ChoiceDelta {
-        pub content: Option<String>,
+    /// Appends a tool to [ChatArguments::tools].
+    pub fn tool(mut self, tool: ChatTool) -> ChatArgumentsBuilder {
+        self.args.tools.get_or_insert_with(Vec::new).push(tool);
+        self
     }
 
-    pub struct ChatCompletionChunkStream {
-        byte_stream: Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>>>>,
-        // internal buffer of incomplete completionchunks
-        buf: String,
+    /// Sets [ChatArguments::temperature].
+    pub fn temperature(mut self, temperature: f32) -> ChatArgumentsBuilder {
+        self.args.temperature = Some(temperature);
+        self
     }
 
-    impl ChatCompletionChunkStream {
-        pub(crate) fn new(stream: Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>>>>) -> Self {
-            Self {
-                byte_stream: stream,
-                buf: String::new(),
-            }
-        }
+    /// Sets [ChatArguments::top_p].
+    pub fn top_p(mut self, top_p: f32) -> ChatArgumentsBuilder {
+        self.args.top_p = Some(top_p);
+        self
+    }
 
-        /// If possible, returns a the first deserialized chunk
-        /// from the buffer.
-        fn deserialize_buf(
-            self: Pin<&mut Self>,
-            cx: &mut std::task::Context<'_>,
-        ) -> Option<anyhow::Result<ChatCompletionChunk>> {
-            // let's take the first chunk
-            let bufclone = self.buf.clone();
-            let mut chunks = bufclone.split("\n\n").peekable();
-            let first = chunks.next();
-            let second = chunks.peek();
-
-            match first {
-                Some(first) => {
-                    match first.strip_prefix("data: ") {
-                        Some(chunk) => {
-                            if !chunk.ends_with("}") {
-                                // This guard happens on partial chunks or the
-                                // [DONE] marker
-                                None
-                            } else {
-                                // If there's a second chunk, wake
-                                if let Some(second) = second {
-                                    if second.ends_with("}") {
-                                        cx.waker().wake_by_ref();
-                                    }
-                                }
-
-                                // Save the remainder
-                                self.get_mut().buf = chunks.collect::<Vec<_>>().join("\n\n");
-                                //self.get_mut().buf = chunks.remainder().unwrap_or("").to_owned();
-
-                                Some(
-                                    serde_json::from_str::<ChatCompletionChunk>(&chunk)
-                                        .map_err(|e| anyhow::anyhow!(e)),
-                                )
-                            }
-                        }
-                        None => None,
-                    }
-                }
-                None => None,
-            }
-        }
+    /// Sets [ChatArguments::n].
+    pub fn n(mut self, n: u32) -> ChatArgumentsBuilder {
+        self.args.n = Some(n);
+        self
     }
 
-    impl Stream for ChatCompletionChunkStream {
-        type Item = anyhow::Result<ChatCompletionChunk>;
+    /// Sets [ChatArguments::stop].
+    pub fn stop(mut self, stop: impl Into<Stop>) -> ChatArgumentsBuilder {
+        self.args.stop = Some(stop.into());
+        self
+    }
 
-        fn poll_next(
-            mut self: Pin<&mut Self>,
-            cx: &mut std::task::Context<'_>,
-        ) -> Poll<Option<Self::Item>> {
-            // Possibly fetch a chunk from the buffer
-            match self.as_mut().deserialize_buf(cx) {
-                Some(chunk) => return Poll::Ready(Some(chunk)),
-                None => {}
-            };
+    /// Sets [ChatArguments::max_tokens].
+    pub fn max_tokens(mut self, max_tokens: u32) -> ChatArgumentsBuilder {
+        self.args.max_tokens = Some(max_tokens);
+        self
+    }
 
-            match self.byte_stream.as_mut().poll_next(cx) {
-                Poll::Ready(bytes_option) => match bytes_option {
-                    Some(bytes_result) => match bytes_result {
-                        Ok(bytes) => {
-                            // Finally actually get some bytes
-                            let data = str::from_utf8(&bytes)?.to_owned();
-                            self.buf = self.buf.clone() + &data;
-                            match self.deserialize_buf(cx) {
-                                Some(chunk) => Poll::Ready(Some(chunk)),
-                                // Partial
-                                None => {
-                                    // On a partial, I think the best we can do is just to wake the
-                                    // task again. If we don't this task will get stuck.
-                                    cx.waker().wake_by_ref();
-                                    Poll::Pending
-                                }
-                            }
-                        }
-                        Err(e) => Poll::Ready(Some(Err(e.into()))),
-                    },
-                    // Stream terminated
-                    None => Poll::Ready(None),
-                },
-                Poll::Pending => Poll::Pending,
-            }
-        }
+    /// Sets [ChatArguments::max_completion_tokens].
+    pub fn max_completion_tokens(mut self, max_completion_tokens: u32) -> ChatArgumentsBuilder {
+        self.args.max_completion_tokens = Some(max_completion_tokens);
+        self
     }
-}
 
-/// Information about the tokens used by [ChatCompletion].
-#[derive(Deserialize, Debug, Clone)]
-pub struct Usage {
-    pub prompt_tokens: u32,
-    pub completion_tokens: u32,
-    pub total_tokens: u32,
-}
+    /// Sets [ChatArguments::presence_penalty].
+    pub fn presence_penalty(mut self, presence_penalty: f32) -> ChatArgumentsBuilder {
+        self.args.presence_penalty = Some(presence_penalty);
+        self
+    }
 
-/// Completion choices from [ChatCompletion].
-#[derive(Deserialize, Debug, Clone)]
-pub struct Choice {
-    #[serde(default)]
-    pub index: Option<u32>,
-    pub message: Message,
-    pub finish_reason: String,
-}
+    /// Sets [ChatArguments::frequency_penalty].
+    pub fn frequency_penalty(mut self, frequency_penalty: f32) -> ChatArgumentsBuilder {
+        self.args.frequency_penalty = Some(frequency_penalty);
+        self
+    }
 
-/// A message.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Message {
-    pub role: String,
-    pub content: String,
+    /// Sets [ChatArguments::logit_bias].
+    pub fn logit_bias(mut self, logit_bias: HashMap<String, f32>) -> ChatArgumentsBuilder {
+        self.args.logit_bias = Some(logit_bias);
+        self
+    }
+
+    /// Sets [ChatArguments::user].
+    pub fn user(mut self, user: impl AsRef<str>) -> ChatArgumentsBuilder {
+        self.args.user = Some(user.as_ref().to_owned());
+        self
+    }
+
+    /// Sets [ChatArguments::tools].
+    pub fn tools(mut self, tools: Vec<ChatTool>) -> ChatArgumentsBuilder {
+        self.args.tools = Some(tools);
+        self
+    }
+
+    /// Sets [ChatArguments::tool_choice].
+    pub fn tool_choice(mut self, tool_choice: ToolChoice) -> ChatArgumentsBuilder {
+        self.args.tool_choice = Some(tool_choice);
+        self
+    }
+
+    /// Sets [ChatArguments::parallel_tool_calls].
+    pub fn parallel_tool_calls(mut self, parallel_tool_calls: bool) -> ChatArgumentsBuilder {
+        self.args.parallel_tool_calls = Some(parallel_tool_calls);
+        self
+    }
+
+    /// Sets [ChatArguments::functions].
+    pub fn functions(mut self, functions: Vec<FunctionDefinition>) -> ChatArgumentsBuilder {
+        self.args.functions = Some(functions);
+        self
+    }
+
+    /// Sets [ChatArguments::function_call].
+    pub fn function_call(mut self, function_call: FunctionCallChoice) -> ChatArgumentsBuilder {
+        self.args.function_call = Some(function_call);
+        self
+    }
+
+    /// Sets [ChatArguments::response_format].
+    pub fn response_format(mut self, response_format: ResponseFormat) -> ChatArgumentsBuilder {
+        self.args.response_format = Some(response_format);
+        self
+    }
+
+    /// Sets [ChatArguments::logprobs].
+    pub fn logprobs(mut self, logprobs: bool) -> ChatArgumentsBuilder {
+        self.args.logprobs = Some(logprobs);
+        self
+    }
+
+    /// Sets [ChatArguments::top_logprobs].
+    pub fn top_logprobs(mut self, top_logprobs: u8) -> ChatArgumentsBuilder {
+        self.args.top_logprobs = Some(top_logprobs);
+        self
+    }
+
+    /// Sets [ChatArguments::seed].
+    pub fn seed(mut self, seed: i64) -> ChatArgumentsBuilder {
+        self.args.seed = Some(seed);
+        self
+    }
+
+    /// Sets [ChatArguments::stream_options].
+    pub fn stream_options(mut self, stream_options: StreamOptions) -> ChatArgumentsBuilder {
+        self.args.stream_options = Some(stream_options);
+        self
+    }
+
+    /// Sets [ChatArguments::reasoning_effort].
+    pub fn reasoning_effort(mut self, reasoning_effort: ReasoningEffort) -> ChatArgumentsBuilder {
+        self.args.reasoning_effort = Some(reasoning_effort);
+        self
+    }
+
+    /// Sets [ChatArguments::prediction].
+    pub fn prediction(mut self, prediction: Prediction) -> ChatArgumentsBuilder {
+        self.args.prediction = Some(prediction);
+        self
+    }
+
+    /// Sets [ChatArguments::store].
+    pub fn store(mut self, store: bool) -> ChatArgumentsBuilder {
+        self.args.store = Some(store);
+        self
+    }
+
+    /// Adds a tag to [ChatArguments::metadata].
+    ///
+    /// ```
+    /// let args = openai_rust2::chat::ChatArguments::builder("gpt-4o-mini")
+    ///     .message(openai_rust2::chat::Message::user("Hello GPT!"))
+    ///     .store(true)
+    ///     .metadata_entry("project", "onboarding")
+    ///     .build();
+    /// assert_eq!(args.metadata.unwrap()["project"], "onboarding");
+    /// ```
+    pub fn metadata_entry(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> ChatArgumentsBuilder {
+        self.args
+            .metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets [ChatArguments::metadata].
+    pub fn metadata(mut self, metadata: HashMap<String, String>) -> ChatArgumentsBuilder {
+        self.args.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets [ChatArguments::service_tier].
+    pub fn service_tier(mut self, service_tier: ServiceTier) -> ChatArgumentsBuilder {
+        self.args.service_tier = Some(service_tier);
+        self
+    }
+
+    /// Sets [ChatArguments::web_search_options].
+    pub fn web_search_options(
+        mut self,
+        web_search_options: WebSearchOptions,
+    ) -> ChatArgumentsBuilder {
+        self.args.web_search_options = Some(web_search_options);
+        self
+    }
+
+    pub fn build(self) -> ChatArguments {
+        self.args
+    }
 }
 
-/// Role of a [Message].
+/// The [ChatArguments::stream_options] of a streaming request.
+///
+/// ```
+/// # let mut args = openai_rust2::chat::ChatArguments::new("gpt-4o-mini", vec![]);
+/// args.stream_options = Some(openai_rust2::chat::StreamOptions { include_usage: true });
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+pub struct StreamOptions {
+    /// Stream an additional chunk before `data: [DONE]`, with the [stream::ChatCompletionChunk::usage]
+    /// of the entire request and no choices.
+    pub include_usage: bool,
+}
+
+/// The [ChatArguments::reasoning_effort] of a reasoning model.
+///
+/// ```
+/// let args = openai_rust2::chat::ChatArguments::builder("o3-mini")
+///     .message(openai_rust2::chat::Message::user("Hello GPT!"))
+///     .reasoning_effort(openai_rust2::chat::ReasoningEffort::Low)
+///     .build();
+/// let json = serde_json::to_value(&args).unwrap();
+/// assert_eq!(json["reasoning_effort"], "low");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningEffort {
+    Low,
+    Medium,
+    High,
+}
+
+/// The processing tier of [ChatArguments::service_tier] and [ChatCompletion::service_tier].
+///
+/// Tiers unknown to this crate are kept in [ServiceTier::Other].
+/// ```
+/// use openai_rust2::chat::ServiceTier;
+/// assert_eq!(serde_json::to_string(&ServiceTier::Flex).unwrap(), "\"flex\"");
+/// assert_eq!(serde_json::from_str::<ServiceTier>("\"scale\"").unwrap(), ServiceTier::Scale);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceTier {
+    /// The Scale tier if the project has it, otherwise the default one.
+    Auto,
+    Default,
+    /// Lower prices for slower responses.
+    Flex,
+    /// The capacity reserved by Scale Tier customers.
+    Scale,
+    Priority,
+    Other(String),
+}
+
+impl ServiceTier {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ServiceTier::Auto => "auto",
+            ServiceTier::Default => "default",
+            ServiceTier::Flex => "flex",
+            ServiceTier::Scale => "scale",
+            ServiceTier::Priority => "priority",
+            ServiceTier::Other(tier) => tier,
+        }
+    }
+}
+
+impl From<&str> for ServiceTier {
+    fn from(tier: &str) -> Self {
+        match tier {
+            "auto" => ServiceTier::Auto,
+            "default" => ServiceTier::Default,
+            "flex" => ServiceTier::Flex,
+            "scale" => ServiceTier::Scale,
+            "priority" => ServiceTier::Priority,
+            other => ServiceTier::Other(other.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for ServiceTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for ServiceTier {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ServiceTier {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(ServiceTier::from(
+            String::deserialize(deserializer)?.as_str(),
+        ))
+    }
+}
+
+/// The [ChatArguments::web_search_options] of the search models.
+///
+/// See <https://platform.openai.com/docs/guides/tools-web-search>.
+///
+/// ```
+/// use openai_rust2::chat::{ApproximateLocation, WebSearchLocation, WebSearchOptions};
+/// let options = WebSearchOptions {
+///     search_context_size: Some("low".to_owned()),
+///     user_location: Some(WebSearchLocation::approximate(ApproximateLocation {
+///         country: Some("GB".to_owned()),
+///         city: Some("London".to_owned()),
+///         ..Default::default()
+///     })),
+/// };
+/// assert_eq!(
+///     serde_json::to_value(options).unwrap(),
+///     serde_json::json!({
+///         "search_context_size": "low",
+///         "user_location": {"type": "approximate", "approximate": {"city": "London", "country": "GB"}},
+///     })
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct WebSearchOptions {
+    /// How much context to retrieve from the web, `low`, `medium` or `high`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_context_size: Option<String>,
+    /// The location of the user, used to refine the search results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_location: Option<WebSearchLocation>,
+}
+
+/// The location of the user of [WebSearchOptions].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WebSearchLocation {
+    /// Always `approximate`.
+    #[serde(rename = "type")]
+    pub location_type: String,
+    pub approximate: ApproximateLocation,
+}
+
+impl WebSearchLocation {
+    pub fn approximate(location: ApproximateLocation) -> WebSearchLocation {
+        WebSearchLocation {
+            location_type: "approximate".to_owned(),
+            approximate: location,
+        }
+    }
+}
+
+/// An approximate [WebSearchLocation].
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ApproximateLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// The two-letter [ISO country code](https://en.wikipedia.org/wiki/ISO_3166-1) of the user, e.g. `US`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// The [IANA timezone](https://timeapi.io/documentation/iana-timezones) of the user, e.g. `America/Los_Angeles`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+/// The [ChatArguments::prediction] of the response.
+///
+/// ```
+/// use openai_rust2::chat::Prediction;
+/// assert_eq!(
+///     serde_json::to_value(Prediction::content("fn main() {}")).unwrap(),
+///     serde_json::json!({"type": "content", "content": "fn main() {}"})
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Prediction {
+    /// Static content, like the current version of the text to regenerate.
+    Content { content: Content },
+}
+
+impl Prediction {
+    pub fn content(content: impl Into<Content>) -> Prediction {
+        Prediction::Content {
+            content: content.into(),
+        }
+    }
+}
+
+/// The stop sequences of [ChatArguments::stop] and [CompletionArguments::stop](crate::completions::CompletionArguments::stop), serialized as a single string or an array of up to 4 strings.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Stop {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl From<&str> for Stop {
+    fn from(stop: &str) -> Self {
+        Stop::Single(stop.to_owned())
+    }
+}
+
+impl From<String> for Stop {
+    fn from(stop: String) -> Self {
+        Stop::Single(stop)
+    }
+}
+
+impl From<Vec<String>> for Stop {
+    fn from(stops: Vec<String>) -> Self {
+        Stop::Multiple(stops)
+    }
+}
+
+impl From<Vec<&str>> for Stop {
+    fn from(stops: Vec<&str>) -> Self {
+        Stop::Multiple(stops.into_iter().map(str::to_owned).collect())
+    }
+}
+
+/// The format that the model must output, see [ChatArguments::response_format].
+///
+/// ```
+/// use openai_rust2::chat::ResponseFormat;
+/// assert_eq!(
+///     serde_json::to_value(ResponseFormat::JsonObject).unwrap(),
+///     serde_json::json!({"type": "json_object"})
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseFormat {
+    /// Plain text, the default.
+    Text,
+    /// Guarantees the message the model generates is valid JSON.
+    JsonObject,
+    /// Enables Structured Outputs, which ensures the model will match your supplied JSON schema.
+    ///
+    /// See <https://platform.openai.com/docs/guides/structured-outputs>.
+    JsonSchema { json_schema: JsonSchemaFormat },
+}
+
+impl ResponseFormat {
+    /// Structured Outputs following `schema`, in `strict` mode.
+    pub fn json_schema(name: impl AsRef<str>, schema: serde_json::Value) -> ResponseFormat {
+        ResponseFormat::JsonSchema {
+            json_schema: JsonSchemaFormat::new(name, schema),
+        }
+    }
+}
+
+/// The JSON schema of a [ResponseFormat::JsonSchema].
+///
+/// ```
+/// use openai_rust2::chat::{JsonSchemaFormat, ResponseFormat};
+/// let format = ResponseFormat::JsonSchema {
+///     json_schema: JsonSchemaFormat::new("weather", serde_json::json!({
+///         "type": "object",
+///         "properties": {"temperature": {"type": "number"}},
+///         "required": ["temperature"],
+///         "additionalProperties": false
+///     })),
+/// };
+/// ```
+///
+/// With the `schemars` feature, the schema can be generated from a Rust type with [JsonSchemaFormat::from_type].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JsonSchemaFormat {
+    /// The name of the response format. Must be a-z, A-Z, 0-9, or contain underscores and dashes, with a maximum length of 64.
+    pub name: String,
+
+    /// A description of what the response format is for, used by the model to determine how to respond in the format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The schema for the response format, described as a JSON Schema object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<serde_json::Value>,
+
+    /// Whether to enable strict schema adherence when generating the output.
+    /// In strict mode only a [subset of JSON Schema](https://platform.openai.com/docs/guides/structured-outputs#supported-schemas) is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+impl JsonSchemaFormat {
+    /// A strict schema named `name`.
+    pub fn new(name: impl AsRef<str>, schema: serde_json::Value) -> JsonSchemaFormat {
+        JsonSchemaFormat {
+            name: name.as_ref().to_owned(),
+            description: None,
+            schema: Some(schema),
+            strict: Some(true),
+        }
+    }
+
+    /// A strict schema generated from `T`, named after it.
+    ///
+    /// Strict mode requires every object to list all of its properties as required and to forbid additional ones,
+    /// so the generated schema is adjusted accordingly. Optional fields stay nullable.
+    ///
+    /// ```
+    /// # #[cfg(feature = "schemars")]
+    /// # {
+    /// #[derive(serde::Deserialize, schemars::JsonSchema)]
+    /// struct Weather {
+    ///     temperature: f32,
+    ///     summary: Option<String>,
+    /// }
+    /// let format = openai_rust2::chat::JsonSchemaFormat::from_type::<Weather>();
+    /// let schema = format.schema.unwrap();
+    /// assert_eq!(format.name, "Weather");
+    /// assert_eq!(schema["required"], serde_json::json!(["summary", "temperature"]));
+    /// assert_eq!(schema["additionalProperties"], false);
+    /// # }
+    /// ```
+    #[cfg(feature = "schemars")]
+    pub fn from_type<T: schemars::JsonSchema>() -> JsonSchemaFormat {
+        let name: String = T::schema_name()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .take(64)
+            .collect();
+        let mut schema = schemars::schema_for!(T).to_value();
+        if let Some(root) = schema.as_object_mut() {
+            root.remove("$schema");
+            root.remove("title");
+        }
+        make_strict(&mut schema);
+        JsonSchemaFormat::new(name, schema)
+    }
+}
+
+/// Requires all properties and forbids additional ones on every object of `schema`.
+#[cfg(feature = "schemars")]
+fn make_strict(schema: &mut serde_json::Value) {
+    let serde_json::Value::Object(map) = schema else {
+        return;
+    };
+    if let Some(serde_json::Value::Object(properties)) = map.get("properties") {
+        let required = properties.keys().cloned().map(serde_json::Value::String);
+        let required = serde_json::Value::Array(required.collect());
+        map.insert("required".to_owned(), required);
+        map.insert("additionalProperties".to_owned(), false.into());
+    }
+
+    // Only recurse into subschemas, the names of the properties may be keywords too
+    for keyword in ["properties", "$defs", "definitions"] {
+        if let Some(serde_json::Value::Object(schemas)) = map.get_mut(keyword) {
+            schemas.values_mut().for_each(make_strict);
+        }
+    }
+    for keyword in ["anyOf", "allOf", "oneOf", "prefixItems"] {
+        if let Some(serde_json::Value::Array(schemas)) = map.get_mut(keyword) {
+            schemas.iter_mut().for_each(make_strict);
+        }
+    }
+    for keyword in ["items", "not"] {
+        if let Some(schema) = map.get_mut(keyword) {
+            make_strict(schema);
+        }
+    }
+}
+
+/// Whether `model` is a reasoning model, only accepting `max_completion_tokens`.
+fn uses_max_completion_tokens(model: &str) -> bool {
+    // Fine-tuned models are named `ft:<base model>:...`
+    let model = model.strip_prefix("ft:").unwrap_or(model);
+    ["o1", "o3", "o4", "gpt-5"]
+        .iter()
+        .any(|family| model.starts_with(family))
+}
+
+/// Whether `model` expects `developer` instead of `system` messages.
+fn uses_developer_role(model: &str) -> bool {
+    let model = model.strip_prefix("ft:").unwrap_or(model);
+    // The first reasoning models support neither
+    uses_max_completion_tokens(model)
+        && !model.starts_with("o1-mini")
+        && !model.starts_with("o1-preview")
+}
+
+/// A tool the model may call during a chat completion.
+///
+/// ```
+/// let tool = openai_rust2::chat::ChatTool::function(
+///     "get_weather",
+///     "Get the current weather in a given location",
+///     serde_json::json!({
+///         "type": "object",
+///         "properties": {"location": {"type": "string"}},
+///         "required": ["location"]
+///     }),
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatTool {
+    Function { function: FunctionDefinition },
+}
+
+impl ChatTool {
+    /// A function tool whose `parameters` are described as a JSON Schema object.
+    pub fn function(
+        name: impl AsRef<str>,
+        description: impl AsRef<str>,
+        parameters: serde_json::Value,
+    ) -> ChatTool {
+        ChatTool::Function {
+            function: FunctionDefinition {
+                name: name.as_ref().to_owned(),
+                description: Some(description.as_ref().to_owned()),
+                parameters: Some(parameters),
+                strict: None,
+            },
+        }
+    }
+
+    /// A function tool with [FunctionDefinition::strict] enabled,
+    /// failing if `parameters` doesn't meet the Structured Outputs restrictions.
+    ///
+    /// ```
+    /// use openai_rust2::chat::{ChatTool, StrictSchemaError};
+    /// let parameters = serde_json::json!({
+    ///     "type": "object",
+    ///     "properties": {
+    ///         "location": {"type": "string"},
+    ///         "unit": {"type": ["string", "null"], "enum": ["celsius", "fahrenheit", null]}
+    ///     },
+    ///     "required": ["location"],
+    ///     "additionalProperties": false
+    /// });
+    /// let error = ChatTool::strict_function("get_weather", "Get the weather", parameters).unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     StrictSchemaError::NotRequired { path: "#".to_owned(), property: "unit".to_owned() }
+    /// );
+    /// ```
+    pub fn strict_function(
+        name: impl AsRef<str>,
+        description: impl AsRef<str>,
+        parameters: serde_json::Value,
+    ) -> Result<ChatTool, StrictSchemaError> {
+        validate_strict_schema(&parameters)?;
+        Ok(ChatTool::Function {
+            function: FunctionDefinition {
+                name: name.as_ref().to_owned(),
+                description: Some(description.as_ref().to_owned()),
+                parameters: Some(parameters),
+                strict: Some(true),
+            },
+        })
+    }
+
+    /// The name of the tool.
+    pub fn name(&self) -> &str {
+        match self {
+            ChatTool::Function { function } => &function.name,
+        }
+    }
+}
+
+/// Checks that `schema` meets the [Structured Outputs restrictions](https://platform.openai.com/docs/guides/structured-outputs#supported-schemas)
+/// of strict functions and response formats: every object must forbid additional properties and require all of its properties.
+///
+/// ```
+/// let schema = serde_json::json!({
+///     "type": "object",
+///     "properties": {"tags": {"type": "array", "items": {"type": "object", "properties": {}}}},
+///     "required": ["tags"],
+///     "additionalProperties": false
+/// });
+/// let error = openai_rust2::chat::validate_strict_schema(&schema).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "#/properties/tags/items: `additionalProperties` must be `false` in strict mode"
+/// );
+/// ```
+pub fn validate_strict_schema(schema: &serde_json::Value) -> Result<(), StrictSchemaError> {
+    validate_strict_subschema(schema, "#")
+}
+
+fn validate_strict_subschema(
+    schema: &serde_json::Value,
+    path: &str,
+) -> Result<(), StrictSchemaError> {
+    let serde_json::Value::Object(map) = schema else {
+        // `true` and `false` are valid schemas, but have no properties to check
+        return match schema {
+            serde_json::Value::Bool(_) => Ok(()),
+            _ => Err(StrictSchemaError::InvalidSchema {
+                path: path.to_owned(),
+            }),
+        };
+    };
+
+    let is_object = match map.get("type") {
+        Some(serde_json::Value::String(t)) => t == "object",
+        Some(serde_json::Value::Array(types)) => types.iter().any(|t| t == "object"),
+        _ => map.contains_key("properties"),
+    };
+    if is_object {
+        if map.get("additionalProperties") != Some(&serde_json::Value::Bool(false)) {
+            return Err(StrictSchemaError::AdditionalProperties {
+                path: path.to_owned(),
+            });
+        }
+        let required = map.get("required").and_then(|r| r.as_array());
+        if let Some(serde_json::Value::Object(properties)) = map.get("properties") {
+            for property in properties.keys() {
+                let is_required = required.is_some_and(|r| r.iter().any(|p| p == property));
+                if !is_required {
+                    return Err(StrictSchemaError::NotRequired {
+                        path: path.to_owned(),
+                        property: property.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    // Keywords whose values are maps of schemas
+    for keyword in ["properties", "$defs", "definitions"] {
+        if let Some(serde_json::Value::Object(schemas)) = map.get(keyword) {
+            for (name, schema) in schemas {
+                validate_strict_subschema(schema, &format!("{}/{}/{}", path, keyword, name))?;
+            }
+        }
+    }
+    // Keywords whose values are lists of schemas
+    for keyword in ["anyOf", "allOf", "oneOf", "prefixItems"] {
+        if let Some(serde_json::Value::Array(schemas)) = map.get(keyword) {
+            for (i, schema) in schemas.iter().enumerate() {
+                validate_strict_subschema(schema, &format!("{}/{}/{}", path, keyword, i))?;
+            }
+        }
+    }
+    // Keywords whose values are schemas
+    for keyword in ["items", "not"] {
+        if let Some(schema) = map.get(keyword) {
+            validate_strict_subschema(schema, &format!("{}/{}", path, keyword))?;
+        }
+    }
+    Ok(())
+}
+
+/// Why a schema doesn't meet the Structured Outputs restrictions, returned by [validate_strict_schema].
+///
+/// The `path` is a [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) fragment to the offending (sub)schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StrictSchemaError {
+    /// An object schema doesn't set `additionalProperties` to `false`.
+    AdditionalProperties { path: String },
+    /// A property of an object schema is missing from its `required` list.
+    /// Optional properties must be required but accept `null` instead.
+    NotRequired { path: String, property: String },
+    /// The schema is neither an object nor a boolean.
+    InvalidSchema { path: String },
+}
+
+impl std::fmt::Display for StrictSchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StrictSchemaError::AdditionalProperties { path } => write!(
+                f,
+                "{}: `additionalProperties` must be `false` in strict mode",
+                path
+            ),
+            StrictSchemaError::NotRequired { path, property } => write!(
+                f,
+                "{}: property `{}` must be required in strict mode, make it nullable instead",
+                path, property
+            ),
+            StrictSchemaError::InvalidSchema { path } => {
+                write!(f, "{}: a schema must be an object or a boolean", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StrictSchemaError {}
+
+/// Controls which (if any) tool is called by the model, see [ChatArguments::tool_choice].
+///
+/// ```
+/// use openai_rust2::chat::ToolChoice;
+/// assert_eq!(serde_json::to_string(&ToolChoice::Required).unwrap(), "\"required\"");
+/// assert_eq!(
+///     serde_json::to_value(ToolChoice::function("get_weather")).unwrap(),
+///     serde_json::json!({"type": "function", "function": {"name": "get_weather"}})
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolChoice {
+    /// The model can pick between generating a message or calling one or more tools.
+    Auto,
+    /// The model will not call any tool and instead generates a message.
+    None,
+    /// The model must call one or more tools.
+    Required,
+    /// The model must call the function with this name.
+    Function(String),
+}
+
+impl ToolChoice {
+    /// Forces the model to call the function named `name`.
+    pub fn function(name: impl AsRef<str>) -> ToolChoice {
+        ToolChoice::Function(name.as_ref().to_owned())
+    }
+}
+
+impl Serialize for ToolChoice {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ToolChoice::Auto => serializer.serialize_str("auto"),
+            ToolChoice::None => serializer.serialize_str("none"),
+            ToolChoice::Required => serializer.serialize_str("required"),
+            ToolChoice::Function(name) => serde_json::json!({
+                "type": "function",
+                "function": {"name": name},
+            })
+            .serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ToolChoice {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.as_str() {
+            Some("auto") => Ok(ToolChoice::Auto),
+            Some("none") => Ok(ToolChoice::None),
+            Some("required") => Ok(ToolChoice::Required),
+            _ => value["function"]["name"]
+                .as_str()
+                .map(ToolChoice::function)
+                .ok_or_else(|| D::Error::custom(format!("invalid tool choice: {}", value))),
+        }
+    }
+}
+
+/// The legacy equivalent of [ToolChoice], see [ChatArguments::function_call].
+///
+/// ```
+/// use openai_rust2::chat::FunctionCallChoice;
+/// assert_eq!(
+///     serde_json::to_value(FunctionCallChoice::function("get_weather")).unwrap(),
+///     serde_json::json!({"name": "get_weather"})
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FunctionCallChoice {
+    /// The model can pick between generating a message or calling a function.
+    Auto,
+    /// The model will not call a function and instead generates a message.
+    None,
+    /// The model must call the function with this name.
+    Function(String),
+}
+
+impl FunctionCallChoice {
+    /// Forces the model to call the function named `name`.
+    pub fn function(name: impl AsRef<str>) -> FunctionCallChoice {
+        FunctionCallChoice::Function(name.as_ref().to_owned())
+    }
+}
+
+impl Serialize for FunctionCallChoice {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FunctionCallChoice::Auto => serializer.serialize_str("auto"),
+            FunctionCallChoice::None => serializer.serialize_str("none"),
+            FunctionCallChoice::Function(name) => {
+                serde_json::json!({ "name": name }).serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FunctionCallChoice {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.as_str() {
+            Some("auto") => Ok(FunctionCallChoice::Auto),
+            Some("none") => Ok(FunctionCallChoice::None),
+            _ => value["name"]
+                .as_str()
+                .map(|name| FunctionCallChoice::Function(name.to_owned()))
+                .ok_or_else(|| D::Error::custom(format!("invalid function call: {}", value))),
+        }
+    }
+}
+
+/// The definition of a function that can be called by the model.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunctionDefinition {
+    /// The name of the function to be called. Must be a-z, A-Z, 0-9, or contain underscores and dashes, with a maximum length of 64.
+    pub name: String,
+
+    /// A description of what the function does, used by the model to choose when and how to call the function.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The parameters the functions accepts, described as a [JSON Schema](https://json-schema.org/understanding-json-schema) object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+
+    /// Make the model follow the `parameters` schema exactly, which must then meet the
+    /// [Structured Outputs restrictions](https://platform.openai.com/docs/guides/structured-outputs#supported-schemas),
+    /// see [ChatTool::strict_function].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+/// This is the response of a chat.
+///
+/// It implements [Display](std::fmt::Display) as a shortcut to easily extract the content.
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"chatcmpl-123\",
+/// #  \"object\": \"chat.completion\",
+/// #  \"created\": 1677652288,
+/// #  \"choices\": [{
+/// #    \"index\": 0,
+/// #    \"message\": {
+/// #     \"role\": \"assistant\",
+/// #     \"content\": \"\\n\\nHello there, how may I assist you today?\"
+/// #    },
+/// #    \"finish_reason\": \"stop\"
+/// #  }],
+/// #  \"usage\": {
+/// #   \"prompt_tokens\": 9,
+/// #   \"completion_tokens\": 12,
+/// #   \"total_tokens\": 21
+/// #  }
+/// # }";
+/// # let res = serde_json::from_str::<openai_rust2::chat::ChatCompletion>(json).unwrap();
+/// let msg = &res.choices[0].message.content;
+/// // or
+/// let msg = res.to_string();
+/// // or, with several choices requested through `n`
+/// for choice in &res.choices {
+///     println!("{:?}: {}", choice.index, choice.message.content);
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChatCompletion {
+    // Make id optional
+    #[serde(default)]
+    pub id: Option<String>,
+    pub created: u32,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub object: Option<String>,
+    pub choices: Vec<Choice>,
+    pub usage: Usage,
+    /// The backend configuration that the model runs with. Can be used together with [ChatArguments::seed]
+    /// to understand when backend changes have been made that might impact determinism.
+    #[serde(default)]
+    pub system_fingerprint: Option<String>,
+    /// The processing tier that served the request, see [ChatArguments::service_tier].
+    #[serde(default)]
+    pub service_tier: Option<ServiceTier>,
+}
+
+impl std::fmt::Display for ChatCompletion {
+    /// Automatically grab the first choice, if any
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(choice) = self.choices.first() {
+            write!(f, "{}", choice.message.content)?;
+        }
+        Ok(())
+    }
+}
+
+impl ChatCompletion {
+    /// Deserialize the Structured Outputs JSON of the first choice into `T`.
+    ///
+    /// ```
+    /// # use serde_json;
+    /// # let json = "{
+    /// #  \"created\": 1677652288,
+    /// #  \"choices\": [{
+    /// #    \"index\": 0,
+    /// #    \"message\": {
+    /// #     \"role\": \"assistant\",
+    /// #     \"content\": \"{\\\"temperature\\\": 21.5}\"
+    /// #    },
+    /// #    \"finish_reason\": \"stop\"
+    /// #  }],
+    /// #  \"usage\": {\"prompt_tokens\": 9, \"completion_tokens\": 12, \"total_tokens\": 21}
+    /// # }";
+    /// # let res = serde_json::from_str::<openai_rust2::chat::ChatCompletion>(json).unwrap();
+    /// #[derive(serde::Deserialize)]
+    /// struct Weather {
+    ///     temperature: f32,
+    /// }
+    /// let weather: Weather = res.parse_structured().unwrap();
+    /// assert_eq!(weather.temperature, 21.5);
+    /// ```
+    pub fn parse_structured<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<T, StructuredOutputError> {
+        let message = &self
+            .choices
+            .first()
+            .ok_or(StructuredOutputError::NoChoices)?
+            .message;
+        if let Some(refusal) = &message.refusal {
+            return Err(StructuredOutputError::Refusal(refusal.clone()));
+        }
+        let content = message.content.to_string();
+        serde_json::from_str(&content)
+            .map_err(|source| StructuredOutputError::InvalidJson { content, source })
+    }
+}
+
+/// Why a Structured Outputs [ChatCompletion] could not be turned into the requested type.
+///
+/// Returned by [ChatCompletion::parse_structured], and wrapped in the [anyhow::Error] of
+/// [Client::create_chat_structured](crate::Client::create_chat_structured), from which it can be recovered with
+/// [anyhow::Error::downcast_ref].
+#[derive(Debug)]
+pub enum StructuredOutputError {
+    /// The model refused to answer, with its explanation.
+    Refusal(String),
+    /// The content of the message doesn't deserialize into the requested type,
+    /// for example because the output was cut short by `max_tokens`.
+    InvalidJson {
+        content: String,
+        source: serde_json::Error,
+    },
+    /// The response has no choices.
+    NoChoices,
+}
+
+impl std::fmt::Display for StructuredOutputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StructuredOutputError::Refusal(refusal) => write!(f, "the model refused: {}", refusal),
+            StructuredOutputError::InvalidJson { source, .. } => {
+                write!(f, "the model produced invalid JSON: {}", source)
+            }
+            StructuredOutputError::NoChoices => write!(f, "the response has no choices"),
+        }
+    }
+}
+
+impl std::error::Error for StructuredOutputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StructuredOutputError::InvalidJson { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Structs and deserialization method for the responses
+/// when using streaming chat responses.
+pub mod stream {
+    pub use futures_util::stream::AbortHandle;
+    use futures_util::stream::Abortable;
+    use futures_util::Stream;
+    use serde::Deserialize;
+    use std::collections::{HashMap, HashSet, VecDeque};
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::Poll;
+
+    /// This is the partial chat result received when streaming.
+    ///
+    /// It implements [Display](std::fmt::Display) as a shortcut to easily extract the content.
+    /// ```
+    /// # use serde_json;
+    /// # let json = "{
+    /// # \"id\": \"chatcmpl-6yX67cSCIAm4nrNLQUPOtJu9JUoLG\",
+    /// # \"object\": \"chat.completion.chunk\",
+    /// # \"created\": 1679884927,
+    /// # \"model\": \"gpt-3.5-turbo-0301\",
+    /// # \"choices\": [
+    /// #   {
+    /// #     \"delta\": {
+    /// #       \"content\": \" today\"
+    /// #     },
+    /// #     \"index\": 0,
+    /// #     \"finish_reason\": null
+    /// #   }
+    /// # ]
+    /// # }";
+    /// # let res = serde_json::from_str::<openai_rust2::chat::stream::ChatCompletionChunk>(json).unwrap();
+    /// let msg = &res.choices[0].delta.content;
+    /// // or
+    /// let msg = res.to_string();
+    /// // the last chunk of a choice tells why it ended
+    /// if let Some(reason) = &res.choices[0].finish_reason {
+    ///     println!("choice {} finished: {}", res.choices[0].index, reason);
+    /// }
+    /// ```
+    #[derive(Deserialize, Debug, Clone)]
+    pub struct ChatCompletionChunk {
+        pub id: String,
+        pub created: u32,
+        pub model: String,
+        pub choices: Vec<Choice>,
+        pub system_fingerprint: Option<String>,
+        /// See [ChatCompletion::service_tier](super::ChatCompletion::service_tier).
+        #[serde(default)]
+        pub service_tier: Option<super::ServiceTier>,
+        /// The token usage of the entire request, only set on the last chunk when
+        /// [StreamOptions::include_usage](super::StreamOptions::include_usage) is enabled.
+        /// That chunk has no choices.
+        #[serde(default)]
+        pub usage: Option<super::Usage>,
+    }
+
+    impl std::fmt::Display for ChatCompletionChunk {
+        /// Automatically grab the content of the first choice, if any
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if let Some(content) = self.choices.first().and_then(|c| c.delta.content.as_ref()) {
+                write!(f, "{}", content)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Choices for [ChatCompletionChunk].
+    #[derive(Deserialize, Debug, Clone)]
+    pub struct Choice {
+        pub delta: ChoiceDelta,
+        /// The index of the choice this chunk belongs to, when several are requested through `n`.
+        pub index: u32,
+        /// Set on the last chunk of the choice.
+        pub finish_reason: Option<super::FinishReason>,
+        /// The log probabilities of the tokens of this chunk, when `logprobs` is enabled.
+        #[serde(default)]
+        pub logprobs: Option<super::ChoiceLogProbs>,
+    }
+
+    /// Additional data from [Choice].
+    ///
+    /// ```
+    /// # use serde_json;
+    /// # let json = "{
+    /// #   \"id\": \"chatcmpl-123\",
+    /// #   \"created\": 1694268190,
+    /// #   \"model\": \"gpt-4o-mini\",
+    /// #   \"system_fingerprint\": null,
+    /// #   \"choices\": [{\"index\": 0, \"delta\": {\"content\": null, \"refusal\": \"I'm sorry\"}, \"finish_reason\": \"stop\"}]
+    /// # }";
+    /// use openai_rust2::chat::stream::{ChatCompletionAccumulator, ChatCompletionChunk};
+    /// let chunk = serde_json::from_str::<ChatCompletionChunk>(json).unwrap();
+    /// let mut accumulator = ChatCompletionAccumulator::new();
+    /// accumulator.push(&chunk);
+    /// let completion = accumulator.finish().unwrap();
+    /// assert_eq!(completion.choices[0].message.refusal.as_deref(), Some("I'm sorry"));
+    /// ```
+    #[derive(Deserialize, Debug, Clone)]
+    pub struct ChoiceDelta {
+        /// The role of the message, only set on the first chunk of the choice.
+        #[serde(default)]
+        pub role: Option<super::Role>,
+        pub content: Option<String>,
+        /// A piece of the refusal message, see [Message::refusal](super::Message::refusal).
+        #[serde(default)]
+        pub refusal: Option<String>,
+        /// Fragments of the tool calls of the message, use a [ToolCallAccumulator] to assemble them.
+        #[serde(default)]
+        pub tool_calls: Option<Vec<ToolCallDelta>>,
+        /// Citations of the message, see [Message::annotations](super::Message::annotations).
+        #[serde(default)]
+        pub annotations: Option<Vec<super::Annotation>>,
+    }
+
+    /// A fragment of a tool call in a [ChoiceDelta].
+    ///
+    /// The first fragment of a call has its `id` and function name, the following ones more of its arguments.
+    #[derive(Deserialize, Debug, Clone)]
+    pub struct ToolCallDelta {
+        /// The position of the tool call in the message.
+        pub index: u32,
+        #[serde(default)]
+        pub id: Option<String>,
+        #[serde(rename = "type", default)]
+        pub tool_type: Option<String>,
+        #[serde(default)]
+        pub function: Option<FunctionCallDelta>,
+    }
+
+    /// The function fragment of a [ToolCallDelta].
+    #[derive(Deserialize, Debug, Clone)]
+    pub struct FunctionCallDelta {
+        #[serde(default)]
+        pub name: Option<String>,
+        #[serde(default)]
+        pub arguments: Option<String>,
+    }
+
+    /// Assembles the [ToolCallDelta]s of a streamed choice into complete [ToolCall](super::ToolCall)s.
+    ///
+    /// A tool call is finished when the next one starts or when the choice finishes,
+    /// [ToolCallAccumulator::push] returns the calls finished by each chunk.
+    /// Use one accumulator per choice when several are requested through `n`.
+    ///
+    /// ```
+    /// # use serde_json;
+    /// use openai_rust2::chat::stream::{Choice, ToolCallAccumulator};
+    /// let chunks = [
+    ///     r#"{"index": 0, "delta": {"tool_calls": [{"index": 0, "id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": ""}}]}, "finish_reason": null}"#,
+    ///     r#"{"index": 0, "delta": {"tool_calls": [{"index": 0, "function": {"arguments": "{\"location\": "}}]}, "finish_reason": null}"#,
+    ///     r#"{"index": 0, "delta": {"tool_calls": [{"index": 0, "function": {"arguments": "\"Boston\"}"}}]}, "finish_reason": null}"#,
+    ///     r#"{"index": 0, "delta": {}, "finish_reason": "tool_calls"}"#,
+    /// ];
+    /// let mut accumulator = ToolCallAccumulator::new();
+    /// let mut finished = vec![];
+    /// for chunk in chunks {
+    ///     let choice: Choice = serde_json::from_str(chunk).unwrap();
+    ///     finished.extend(accumulator.push(&choice));
+    /// }
+    /// assert_eq!(finished.len(), 1);
+    /// assert_eq!(finished[0].id, "call_1");
+    /// assert_eq!(finished[0].function.name, "get_weather");
+    /// assert_eq!(finished[0].function.arguments, r#"{"location": "Boston"}"#);
+    /// ```
+    #[derive(Debug, Clone, Default)]
+    pub struct ToolCallAccumulator {
+        /// The tool calls with their index, in order
+        calls: Vec<(u32, super::ToolCall)>,
+        /// How many of `calls` were returned as finished
+        finished: usize,
+    }
+
+    impl ToolCallAccumulator {
+        pub fn new() -> ToolCallAccumulator {
+            ToolCallAccumulator::default()
+        }
+
+        /// Adds the tool call fragments of a chunk, returning the tool calls it finished.
+        pub fn push(&mut self, choice: &Choice) -> Vec<super::ToolCall> {
+            for delta in choice.delta.tool_calls.iter().flatten() {
+                self.push_delta(delta);
+            }
+            let done = if choice.finish_reason.is_some() {
+                self.calls.len()
+            } else {
+                // Every call but the last has been superseded by a later one
+                self.calls.len().saturating_sub(1)
+            };
+            self.take_finished(done)
+        }
+
+        /// Adds a single tool call fragment.
+        pub fn push_delta(&mut self, delta: &ToolCallDelta) {
+            let position = match self.calls.iter().position(|(i, _)| *i == delta.index) {
+                Some(position) => position,
+                None => {
+                    self.calls.push((
+                        delta.index,
+                        super::ToolCall {
+                            id: String::new(),
+                            tool_type: super::function_type(),
+                            function: super::FunctionCall {
+                                name: String::new(),
+                                arguments: String::new(),
+                            },
+                        },
+                    ));
+                    self.calls.len() - 1
+                }
+            };
+            let call = &mut self.calls[position].1;
+            if let Some(id) = &delta.id {
+                call.id.push_str(id);
+            }
+            if let Some(tool_type) = &delta.tool_type {
+                call.tool_type = tool_type.clone();
+            }
+            if let Some(function) = &delta.function {
+                if let Some(name) = &function.name {
+                    call.function.name.push_str(name);
+                }
+                if let Some(arguments) = &function.arguments {
+                    call.function.arguments.push_str(arguments);
+                }
+            }
+        }
+
+        /// The tool calls assembled so far, including the unfinished one.
+        pub fn tool_calls(&self) -> impl Iterator<Item = &super::ToolCall> {
+            self.calls.iter().map(|(_, call)| call)
+        }
+
+        /// All of the assembled tool calls, for streams that ended without a finish reason.
+        pub fn into_tool_calls(self) -> Vec<super::ToolCall> {
+            self.calls.into_iter().map(|(_, call)| call).collect()
+        }
+
+        fn take_finished(&mut self, done: usize) -> Vec<super::ToolCall> {
+            if done <= self.finished {
+                return vec![];
+            }
+            let finished = self.calls[self.finished..done]
+                .iter()
+                .map(|(_, call)| call.clone())
+                .collect();
+            self.finished = done;
+            finished
+        }
+    }
+
+    pub struct ChatCompletionChunkStream {
+        // dropped once the stream ends or is aborted, which closes the connection
+        source: Option<Abortable<ChunkSource>>,
+        abort_handle: AbortHandle,
+    }
+
+    impl ChatCompletionChunkStream {
+        /// With `reconnect`, the request is sent again if the connection drops,
+        /// see [Client::with_stream_retry](crate::Client::with_stream_retry).
+        pub(crate) fn new(stream: crate::sse::ByteStream, reconnect: Option<Reconnect>) -> Self {
+            let (abort_handle, registration) = AbortHandle::new_pair();
+            let source = ChunkSource {
+                event_stream: crate::sse::EventStream::new(stream),
+                reconnect,
+                retries: 0,
+                reconnecting: None,
+                replay: Replay::default(),
+            };
+            Self {
+                source: Some(Abortable::new(source, registration)),
+                abort_handle,
+            }
+        }
+
+        /// A handle to stop the generation, for example from another task.
+        ///
+        /// Once [aborted](AbortHandle::abort) the stream ends and its connection is closed,
+        /// dropping the stream does the same.
+        ///
+        /// ```no_run
+        /// # use tokio_test;
+        /// # tokio_test::block_on(async {
+        /// # use openai_rust2 as openai_rust;
+        /// # let client = openai_rust::Client::new("");
+        /// # let args = openai_rust::chat::ChatArguments::new("gpt-4o-mini", vec![
+        /// #     openai_rust::chat::Message::user("Hello GPT!"),
+        /// # ]);
+        /// use openai_rust::futures_util::StreamExt;
+        /// let mut stream = client.create_chat_stream(args, None).await.unwrap();
+        /// let abort_handle = stream.abort_handle();
+        /// tokio::spawn(async move {
+        ///     tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        ///     abort_handle.abort();
+        /// });
+        /// while let Some(chunk) = stream.next().await {
+        ///     print!("{}", chunk.unwrap());
+        /// }
+        /// # })
+        /// ```
+        pub fn abort_handle(&self) -> AbortHandle {
+            self.abort_handle.clone()
+        }
+
+        /// Consumes the stream and merges its chunks into a [ChatCompletion](super::ChatCompletion).
+        ///
+        /// Fails with an [IncompleteStreamError] if the stream ends before the finish reason of every choice.
+        /// To show the progress as well, feed the chunks to a [ChatCompletionAccumulator] instead.
+        ///
+        /// ```no_run
+        /// # use tokio_test;
+        /// # tokio_test::block_on(async {
+        /// # use openai_rust2 as openai_rust;
+        /// # let client = openai_rust::Client::new("");
+        /// # let args = openai_rust::chat::ChatArguments::new("gpt-4o-mini", vec![
+        /// #     openai_rust::chat::Message::user("Hello GPT!"),
+        /// # ]);
+        /// let stream = client.create_chat_stream(args, None).await.unwrap();
+        /// let completion = stream.collect_completion().await.unwrap();
+        /// println!("{}", completion);
+        /// # })
+        /// ```
+        pub async fn collect_completion(mut self) -> anyhow::Result<super::ChatCompletion> {
+            use futures_util::StreamExt;
+            let mut accumulator = ChatCompletionAccumulator::new();
+            while let Some(chunk) = self.next().await {
+                accumulator.push(&chunk?);
+            }
+            Ok(accumulator.finish()?)
+        }
+
+        /// Splits the chunks into their choices, which is easier to route when `n > 1`
+        /// and the choices of different indices are interleaved.
+        ///
+        /// ```no_run
+        /// # use tokio_test;
+        /// # tokio_test::block_on(async {
+        /// # use openai_rust2 as openai_rust;
+        /// # let client = openai_rust::Client::new("");
+        /// use openai_rust::futures_util::StreamExt;
+        /// let mut args = openai_rust::chat::ChatArguments::new("gpt-4o-mini", vec![
+        ///     openai_rust::chat::Message::user("Tell me a joke"),
+        /// ]);
+        /// args.n = Some(3);
+        /// let mut jokes = vec![String::new(); 3];
+        /// let mut choices = client.create_chat_stream(args, None).await.unwrap().choices();
+        /// while let Some(choice) = choices.next().await {
+        ///     let choice = choice.unwrap();
+        ///     if let Some(content) = choice.delta.content {
+        ///         jokes[choice.index as usize].push_str(&content);
+        ///     }
+        /// }
+        /// # })
+        /// ```
+        pub fn choices(self) -> ChoiceStream {
+            ChoiceStream {
+                chunk_stream: self,
+                pending: VecDeque::new(),
+            }
+        }
+
+        /// Turns the chunks into [ChatEvent]s, so the chunks need not be inspected.
+        ///
+        /// The events don't say which choice they belong to, use [choices](Self::choices) when `n > 1`.
+        ///
+        /// ```no_run
+        /// # use tokio_test;
+        /// # tokio_test::block_on(async {
+        /// # use openai_rust2 as openai_rust;
+        /// # let client = openai_rust::Client::new("");
+        /// # let args = openai_rust::chat::ChatArguments::new("gpt-4o-mini", vec![
+        /// #     openai_rust::chat::Message::user("Hello GPT!"),
+        /// # ]);
+        /// use openai_rust::chat::stream::ChatEvent;
+        /// use openai_rust::futures_util::StreamExt;
+        /// let mut events = client.create_chat_stream(args, None).await.unwrap().events();
+        /// while let Some(event) = events.next().await {
+        ///     match event.unwrap() {
+        ///         ChatEvent::ContentDelta(content) => print!("{}", content),
+        ///         ChatEvent::FinishReason(reason) => println!("\n({})", reason),
+        ///         ChatEvent::Done => break,
+        ///         _ => {}
+        ///     }
+        /// }
+        /// # })
+        /// ```
+        pub fn events(self) -> ChatEventStream {
+            ChatEventStream {
+                chunk_stream: self,
+                pending: VecDeque::new(),
+                done: false,
+            }
+        }
+    }
+
+    /// The choices of a [ChatCompletionChunkStream], one at a time.
+    ///
+    /// Chunks without choices, like the one carrying the [Usage](super::Usage), are skipped.
+    pub struct ChoiceStream {
+        chunk_stream: ChatCompletionChunkStream,
+        // choices of the last chunk that were not yielded yet
+        pending: VecDeque<Choice>,
+    }
+
+    /// An event of a [ChatEventStream].
+    #[derive(Debug, Clone)]
+    pub enum ChatEvent {
+        /// A piece of the message content.
+        ContentDelta(String),
+        /// A piece of the refusal message, sent instead of content when the model refuses to answer.
+        RefusalDelta(String),
+        /// A piece of a tool call, see [ToolCallAccumulator] to merge them.
+        ToolCallDelta(ToolCallDelta),
+        /// Why the model stopped generating.
+        FinishReason(super::FinishReason),
+        /// The tokens used, only sent when [StreamOptions::include_usage](super::StreamOptions::include_usage) is enabled.
+        Usage(super::Usage),
+        /// The stream ended, always the last event.
+        Done,
+    }
+
+    /// The [ChatEvent]s of a [ChatCompletionChunkStream].
+    pub struct ChatEventStream {
+        chunk_stream: ChatCompletionChunkStream,
+        // events of the last chunk that were not yielded yet
+        pending: VecDeque<ChatEvent>,
+        done: bool,
+    }
+
+    impl ChatEventStream {
+        /// See [ChatCompletionChunkStream::abort_handle].
+        pub fn abort_handle(&self) -> AbortHandle {
+            self.chunk_stream.abort_handle()
+        }
+
+        /// Drives the stream in a spawned task and forwards the events to a channel of the given capacity,
+        /// which is handy to feed a UI loop. Must be called from within a tokio runtime.
+        ///
+        /// The task ends after [ChatEvent::Done] or the first error, or when the receiver is dropped.
+        ///
+        /// ```no_run
+        /// # use tokio_test;
+        /// # tokio_test::block_on(async {
+        /// # use openai_rust2 as openai_rust;
+        /// # let client = openai_rust::Client::new("");
+        /// # let args = openai_rust::chat::ChatArguments::new("gpt-4o-mini", vec![
+        /// #     openai_rust::chat::Message::user("Hello GPT!"),
+        /// # ]);
+        /// use openai_rust::chat::stream::ChatEvent;
+        /// let stream = client.create_chat_stream(args, None).await.unwrap();
+        /// let mut receiver = stream.events().into_channel(32);
+        /// while let Some(event) = receiver.recv().await {
+        ///     if let Ok(ChatEvent::ContentDelta(content)) = event {
+        ///         print!("{}", content);
+        ///     }
+        /// }
+        /// # })
+        /// ```
+        pub fn into_channel(
+            mut self,
+            buffer: usize,
+        ) -> tokio::sync::mpsc::Receiver<anyhow::Result<ChatEvent>> {
+            use futures_util::StreamExt;
+            let (sender, receiver) = tokio::sync::mpsc::channel(buffer);
+            tokio::spawn(async move {
+                while let Some(event) = self.next().await {
+                    let last = event.is_err();
+                    if sender.send(event).await.is_err() || last {
+                        break;
+                    }
+                }
+            });
+            receiver
+        }
+
+        fn queue(&mut self, chunk: ChatCompletionChunk) {
+            for choice in chunk.choices {
+                if let Some(content) = choice.delta.content {
+                    if !content.is_empty() {
+                        self.pending.push_back(ChatEvent::ContentDelta(content));
+                    }
+                }
+                if let Some(refusal) = choice.delta.refusal {
+                    if !refusal.is_empty() {
+                        self.pending.push_back(ChatEvent::RefusalDelta(refusal));
+                    }
+                }
+                for tool_call in choice.delta.tool_calls.into_iter().flatten() {
+                    self.pending.push_back(ChatEvent::ToolCallDelta(tool_call));
+                }
+                if let Some(finish_reason) = choice.finish_reason {
+                    self.pending
+                        .push_back(ChatEvent::FinishReason(finish_reason));
+                }
+            }
+            if let Some(usage) = chunk.usage {
+                self.pending.push_back(ChatEvent::Usage(usage));
+            }
+        }
+    }
+
+    impl Stream for ChatEventStream {
+        type Item = anyhow::Result<ChatEvent>;
+
+        fn poll_next(
+            mut self: Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            loop {
+                if let Some(event) = self.pending.pop_front() {
+                    return Poll::Ready(Some(Ok(event)));
+                }
+                if self.done {
+                    return Poll::Ready(None);
+                }
+                match Pin::new(&mut self.chunk_stream).poll_next(cx) {
+                    Poll::Ready(Some(Ok(chunk))) => self.queue(chunk),
+                    Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                    Poll::Ready(None) => {
+                        self.done = true;
+                        return Poll::Ready(Some(Ok(ChatEvent::Done)));
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+
+    impl ChoiceStream {
+        /// See [ChatCompletionChunkStream::abort_handle].
+        pub fn abort_handle(&self) -> AbortHandle {
+            self.chunk_stream.abort_handle()
+        }
+    }
+
+    impl Stream for ChoiceStream {
+        type Item = anyhow::Result<Choice>;
+
+        fn poll_next(
+            mut self: Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            loop {
+                if let Some(choice) = self.pending.pop_front() {
+                    return Poll::Ready(Some(Ok(choice)));
+                }
+                match Pin::new(&mut self.chunk_stream).poll_next(cx) {
+                    Poll::Ready(Some(Ok(chunk))) => self.pending.extend(chunk.choices),
+                    Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                    Poll::Ready(None) => return Poll::Ready(None),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+
+    /// Merges the [ChatCompletionChunk]s of a stream into a [ChatCompletion](super::ChatCompletion),
+    /// with the content, tool calls, finish reason and log probabilities of each choice.
+    ///
+    /// The [Usage](super::Usage) is only known when [StreamOptions::include_usage](super::StreamOptions::include_usage)
+    /// is enabled, otherwise it is all zeros.
+    ///
+    /// ```no_run
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # use openai_rust2 as openai_rust;
+    /// # let client = openai_rust::Client::new("");
+    /// # let args = openai_rust::chat::ChatArguments::new("gpt-4o-mini", vec![
+    /// #     openai_rust::chat::Message::user("Hello GPT!"),
+    /// # ]);
+    /// use openai_rust::futures_util::StreamExt;
+    /// let mut stream = client.create_chat_stream(args, None).await.unwrap();
+    /// let mut accumulator = openai_rust::chat::stream::ChatCompletionAccumulator::new();
+    /// while let Some(chunk) = stream.next().await {
+    ///     let chunk = chunk.unwrap();
+    ///     print!("{}", chunk);
+    ///     accumulator.push(&chunk);
+    /// }
+    /// let completion = accumulator.finish().unwrap();
+    /// # })
+    /// ```
+    #[derive(Debug, Clone, Default)]
+    pub struct ChatCompletionAccumulator {
+        id: Option<String>,
+        created: u32,
+        model: Option<String>,
+        system_fingerprint: Option<String>,
+        service_tier: Option<super::ServiceTier>,
+        usage: Option<super::Usage>,
+        /// The choices, in order of their index
+        choices: Vec<ChoiceAccumulator>,
+    }
+
+    /// A choice being merged by a [ChatCompletionAccumulator].
+    #[derive(Debug, Clone, Default)]
+    struct ChoiceAccumulator {
+        index: u32,
+        role: Option<super::Role>,
+        content: String,
+        refusal: Option<String>,
+        tool_calls: ToolCallAccumulator,
+        annotations: Option<Vec<super::Annotation>>,
+        finish_reason: Option<super::FinishReason>,
+        logprobs: Option<super::ChoiceLogProbs>,
+    }
+
+    impl ChatCompletionAccumulator {
+        pub fn new() -> ChatCompletionAccumulator {
+            ChatCompletionAccumulator::default()
+        }
+
+        /// Merges a chunk.
+        pub fn push(&mut self, chunk: &ChatCompletionChunk) {
+            self.id.get_or_insert_with(|| chunk.id.clone());
+            self.model.get_or_insert_with(|| chunk.model.clone());
+            if self.created == 0 {
+                self.created = chunk.created;
+            }
+            if chunk.system_fingerprint.is_some() {
+                self.system_fingerprint = chunk.system_fingerprint.clone();
+            }
+            if chunk.service_tier.is_some() {
+                self.service_tier = chunk.service_tier.clone();
+            }
+            if chunk.usage.is_some() {
+                self.usage = chunk.usage.clone();
+            }
+            for choice in &chunk.choices {
+                let position = match self
+                    .choices
+                    .binary_search_by_key(&choice.index, |c| c.index)
+                {
+                    Ok(position) => position,
+                    Err(position) => {
+                        let accumulator = ChoiceAccumulator {
+                            index: choice.index,
+                            ..Default::default()
+                        };
+                        self.choices.insert(position, accumulator);
+                        position
+                    }
+                };
+                self.choices[position].push(choice);
+            }
+        }
+
+        /// The content received so far for the choice with the given index.
+        pub fn content(&self, index: u32) -> Option<&str> {
+            self.choices
+                .binary_search_by_key(&index, |c| c.index)
+                .ok()
+                .map(|position| self.choices[position].content.as_str())
+        }
+
+        /// The merged completion, once every choice has its finish reason.
+        pub fn finish(self) -> Result<super::ChatCompletion, IncompleteStreamError> {
+            let unfinished: Vec<u32> = self
+                .choices
+                .iter()
+                .filter(|choice| choice.finish_reason.is_none())
+                .map(|choice| choice.index)
+                .collect();
+            if self.choices.is_empty() || !unfinished.is_empty() {
+                return Err(IncompleteStreamError {
+                    unfinished,
+                    accumulator: Box::new(self),
+                });
+            }
+            Ok(super::ChatCompletion {
+                id: self.id,
+                created: self.created,
+                model: self.model,
+                object: Some("chat.completion".to_owned()),
+                choices: self
+                    .choices
+                    .into_iter()
+                    .map(ChoiceAccumulator::finish)
+                    .collect(),
+                usage: self.usage.unwrap_or(super::Usage {
+                    prompt_tokens: 0,
+                    completion_tokens: 0,
+                    total_tokens: 0,
+                    prompt_tokens_details: None,
+                    completion_tokens_details: None,
+                }),
+                system_fingerprint: self.system_fingerprint,
+                service_tier: self.service_tier,
+            })
+        }
+    }
+
+    /// A stream ended before the finish reason of all its choices, returned by [ChatCompletionAccumulator::finish].
+    ///
+    /// The content received so far can still be read from the [accumulator](IncompleteStreamError::accumulator).
+    #[derive(Debug, Clone)]
+    pub struct IncompleteStreamError {
+        /// The indices of the choices without a finish reason, empty if the stream had no choices at all.
+        pub unfinished: Vec<u32>,
+        pub accumulator: Box<ChatCompletionAccumulator>,
+    }
+
+    impl std::fmt::Display for IncompleteStreamError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "the stream ended before the model finished")
+        }
+    }
+
+    impl std::error::Error for IncompleteStreamError {}
+
+    impl ChoiceAccumulator {
+        fn push(&mut self, choice: &Choice) {
+            if choice.delta.role.is_some() {
+                self.role = choice.delta.role.clone();
+            }
+            if let Some(content) = &choice.delta.content {
+                self.content.push_str(content);
+            }
+            if let Some(refusal) = &choice.delta.refusal {
+                self.refusal
+                    .get_or_insert_with(String::new)
+                    .push_str(refusal);
+            }
+            self.tool_calls.push(choice);
+            if let Some(annotations) = &choice.delta.annotations {
+                self.annotations
+                    .get_or_insert_with(Vec::new)
+                    .extend(annotations.iter().cloned());
+            }
+            if choice.finish_reason.is_some() {
+                self.finish_reason = choice.finish_reason.clone();
+            }
+            if let Some(logprobs) = &choice.logprobs {
+                let merged = self.logprobs.get_or_insert(super::ChoiceLogProbs {
+                    content: None,
+                    refusal: None,
+                });
+                if let Some(content) = &logprobs.content {
+                    merged
+                        .content
+                        .get_or_insert_with(Vec::new)
+                        .extend(content.iter().cloned());
+                }
+                if let Some(refusal) = &logprobs.refusal {
+                    merged
+                        .refusal
+                        .get_or_insert_with(Vec::new)
+                        .extend(refusal.iter().cloned());
+                }
+            }
+        }
+
+        fn finish(self) -> super::Choice {
+            let tool_calls = self.tool_calls.into_tool_calls();
+            super::Choice {
+                index: Some(self.index),
+                message: super::Message {
+                    role: self.role.unwrap_or(super::Role::Assistant),
+                    content: self.content.into(),
+                    tool_calls: (!tool_calls.is_empty()).then_some(tool_calls),
+                    refusal: self.refusal,
+                    annotations: self.annotations,
+                    ..Default::default()
+                },
+                // Checked by ChatCompletionAccumulator::finish
+                finish_reason: self.finish_reason.unwrap(),
+                logprobs: self.logprobs,
+            }
+        }
+    }
+
+    impl Stream for ChatCompletionChunkStream {
+        type Item = anyhow::Result<ChatCompletionChunk>;
+
+        fn poll_next(
+            mut self: Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            let Some(source) = self.source.as_mut() else {
+                return Poll::Ready(None);
+            };
+            match Pin::new(source).poll_next(cx) {
+                Poll::Ready(None) => {
+                    self.source = None;
+                    Poll::Ready(None)
+                }
+                poll => poll,
+            }
+        }
+    }
+
+    /// How to send a chat request again when its stream drops.
+    pub(crate) struct Reconnect {
+        pub(crate) request: reqwest::RequestBuilder,
+        pub(crate) policy: crate::sse::StreamRetryPolicy,
+        pub(crate) idle_timeout: Option<std::time::Duration>,
+        /// The number of choices requested, see [ChatArguments::n](super::ChatArguments::n).
+        pub(crate) choices: u32,
+    }
+
+    type Reconnecting =
+        Pin<Box<dyn Future<Output = anyhow::Result<crate::sse::EventStream>> + Send>>;
+
+    /// The chunks of a chat request, over as many connections as needed.
+    struct ChunkSource {
+        event_stream: crate::sse::EventStream,
+        reconnect: Option<Reconnect>,
+        retries: u32,
+        reconnecting: Option<Reconnecting>,
+        replay: Replay,
+    }
+
+    impl ChunkSource {
+        /// Starts sending the request again, if the policy allows it.
+        fn try_reconnect(&mut self) -> bool {
+            let Some(reconnect) = &self.reconnect else {
+                return false;
+            };
+            if self.retries >= reconnect.policy.max_retries {
+                return false;
+            }
+            let Some(request) = reconnect.request.try_clone() else {
+                return false;
+            };
+            let delay = reconnect.policy.backoff * 2u32.saturating_pow(self.retries);
+            let idle_timeout = reconnect.idle_timeout;
+            self.retries += 1;
+            self.reconnecting = Some(Box::pin(async move {
+                tokio::time::sleep(delay).await;
+                let res = request.send().await?;
+                if res.status() == 200 {
+                    Ok(crate::sse::EventStream::new(crate::sse::byte_stream(
+                        res,
+                        idle_timeout,
+                    )))
+                } else {
+                    Err(anyhow::anyhow!(res.text().await?))
+                }
+            }));
+            true
+        }
+    }
+
+    /// Whether an error of the event stream comes from the connection.
+    fn is_transient(error: &anyhow::Error) -> bool {
+        error.is::<reqwest::Error>() || error.is::<crate::sse::IdleTimeoutError>()
+    }
+
+    impl Stream for ChunkSource {
+        type Item = anyhow::Result<ChatCompletionChunk>;
+
+        fn poll_next(
+            mut self: Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            loop {
+                if let Some(reconnecting) = self.reconnecting.as_mut() {
+                    match reconnecting.as_mut().poll(cx) {
+                        Poll::Ready(Ok(event_stream)) => {
+                            self.reconnecting = None;
+                            self.event_stream = event_stream;
+                            self.replay.restart();
+                        }
+                        Poll::Ready(Err(e)) => {
+                            self.reconnecting = None;
+                            if !self.try_reconnect() {
+                                return Poll::Ready(Some(Err(e)));
+                            }
+                            continue;
+                        }
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+
+                match Pin::new(&mut self.event_stream).poll_next(cx) {
+                    // The [DONE] marker ends the stream
+                    Poll::Ready(Some(Ok(event))) if event.data == "[DONE]" => {
+                        return Poll::Ready(None)
+                    }
+                    Poll::Ready(Some(Ok(event))) => {
+                        let chunk = serde_json::from_str::<ChatCompletionChunk>(&event.data)
+                            .map(|mut chunk| {
+                                self.replay.skip_received(&mut chunk);
+                                chunk
+                            })
+                            .map_err(|e| anyhow::anyhow!(e));
+                        return Poll::Ready(Some(chunk));
+                    }
+                    Poll::Ready(Some(Err(e))) => {
+                        if is_transient(&e) && self.try_reconnect() {
+                            continue;
+                        }
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                    // Without the [DONE] marker the connection was cut short,
+                    // unless every choice already finished
+                    Poll::Ready(None) => {
+                        let finished = self
+                            .reconnect
+                            .as_ref()
+                            .is_none_or(|reconnect| self.replay.finished(reconnect.choices));
+                        if !finished && self.try_reconnect() {
+                            continue;
+                        }
+                        return Poll::Ready(None);
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+
+    /// Removes from the chunks of a new connection what the previous ones already delivered.
+    ///
+    /// This is best-effort: the new generation only matches if the model repeats itself,
+    /// which is more likely with a [seed](super::ChatArguments::seed) and a low temperature.
+    #[derive(Default)]
+    struct Replay {
+        // bytes delivered so far of the content (None) or tool call arguments (Some) of each choice
+        delivered: HashMap<(u32, Option<u32>), usize>,
+        // bytes of the same fields received on the current connection
+        received: HashMap<(u32, Option<u32>), usize>,
+        // choices whose role was delivered
+        roles: HashSet<u32>,
+        // choices whose finish reason was delivered, nothing more comes for them
+        finish_reasons: HashSet<u32>,
+        // whether the usage was delivered
+        usage: bool,
+    }
+
+    impl Replay {
+        fn restart(&mut self) {
+            self.received.clear();
+        }
+
+        /// Whether the finish reasons of all the `choices` were delivered.
+        fn finished(&self, choices: u32) -> bool {
+            (0..choices).all(|index| self.finish_reasons.contains(&index))
+        }
+
+        fn skip_received(&mut self, chunk: &mut ChatCompletionChunk) {
+            if chunk.usage.is_some() && std::mem::replace(&mut self.usage, true) {
+                chunk.usage = None;
+            }
+            chunk
+                .choices
+                .retain(|choice| !self.finish_reasons.contains(&choice.index));
+            for choice in &mut chunk.choices {
+                if choice.finish_reason.is_some() {
+                    self.finish_reasons.insert(choice.index);
+                }
+                if choice.delta.role.is_some() && !self.roles.insert(choice.index) {
+                    choice.delta.role = None;
+                }
+                if let Some(content) = choice.delta.content.as_mut() {
+                    self.skip((choice.index, None), content);
+                }
+                for call in choice.delta.tool_calls.iter_mut().flatten() {
+                    let key = (choice.index, Some(call.index));
+                    // The id and name of a call come with its first delta
+                    if self.delivered.contains_key(&key) && !self.received.contains_key(&key) {
+                        call.id = None;
+                        call.tool_type = None;
+                        if let Some(function) = call.function.as_mut() {
+                            function.name = None;
+                        }
+                    }
+                    match call.function.as_mut().and_then(|f| f.arguments.as_mut()) {
+                        Some(arguments) => self.skip(key, arguments),
+                        None => self.skip(key, &mut String::new()),
+                    }
+                }
+            }
+        }
+
+        fn skip(&mut self, key: (u32, Option<u32>), text: &mut String) {
+            let delivered = self.delivered.entry(key).or_default();
+            let received = self.received.entry(key).or_default();
+            let start = *received;
+            *received += text.len();
+            if *received <= *delivered {
+                text.clear();
+                return;
+            }
+            if start < *delivered {
+                let mut cut = *delivered - start;
+                while !text.is_char_boundary(cut) {
+                    cut += 1;
+                }
+                text.drain(..cut);
+            }
+            *delivered = *received;
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn chunk(choices: serde_json::Value, usage: bool) -> ChatCompletionChunk {
+            let usage = usage.then(|| {
+                serde_json::json!({"prompt_tokens": 5, "completion_tokens": 4, "total_tokens": 9})
+            });
+            serde_json::from_value(serde_json::json!({
+                "id": "chatcmpl-123",
+                "created": 1694268190,
+                "model": "gpt-4o-mini",
+                "choices": choices,
+                "system_fingerprint": null,
+                "usage": usage,
+            }))
+            .unwrap()
+        }
+
+        fn delta(index: u32, content: &str, finish_reason: Option<&str>) -> serde_json::Value {
+            serde_json::json!({
+                "index": index,
+                "delta": {"role": "assistant", "content": content},
+                "finish_reason": finish_reason,
+            })
+        }
+
+        #[test]
+        fn replay_skips_what_was_delivered() {
+            let cut = [
+                chunk(serde_json::json!([delta(0, "Hello", None)]), false),
+                chunk(serde_json::json!([delta(1, "Wor", None)]), false),
+                chunk(serde_json::json!([delta(0, "", Some("stop"))]), false),
+            ];
+            let replayed = [
+                chunk(serde_json::json!([delta(0, "Hel", None)]), false),
+                chunk(serde_json::json!([delta(1, "World", None)]), false),
+                chunk(serde_json::json!([delta(0, "lo!", Some("stop"))]), false),
+                chunk(serde_json::json!([delta(1, "", Some("stop"))]), false),
+                chunk(serde_json::json!([]), true),
+            ];
+            let again = [
+                chunk(serde_json::json!([delta(1, "World", Some("stop"))]), false),
+                chunk(serde_json::json!([]), true),
+            ];
+
+            let mut replay = Replay::default();
+            let mut delivered = Vec::new();
+            for mut chunk in cut {
+                replay.skip_received(&mut chunk);
+                delivered.push(chunk);
+            }
+            assert!(!replay.finished(2));
+            for connection in [replayed.to_vec(), again.to_vec()] {
+                replay.restart();
+                for mut chunk in connection {
+                    replay.skip_received(&mut chunk);
+                    delivered.push(chunk);
+                }
+                assert!(replay.finished(2));
+            }
+
+            let mut accumulator = ChatCompletionAccumulator::new();
+            for chunk in &delivered {
+                accumulator.push(chunk);
+            }
+            assert_eq!(accumulator.content(0), Some("Hello"));
+            assert_eq!(accumulator.content(1), Some("World"));
+            let choices = delivered
+                .iter()
+                .flat_map(|chunk| &chunk.choices)
+                .collect::<Vec<_>>();
+            let finish_reasons = choices.iter().filter(|c| c.finish_reason.is_some());
+            let roles = choices.iter().filter(|c| c.delta.role.is_some());
+            let usages = delivered.iter().filter(|chunk| chunk.usage.is_some());
+            assert_eq!(finish_reasons.count(), 2);
+            assert_eq!(roles.count(), 2);
+            assert_eq!(usages.count(), 1);
+        }
+
+        #[test]
+        fn finish_fails_without_finish_reason() {
+            let mut accumulator = ChatCompletionAccumulator::new();
+            accumulator.push(&chunk(
+                serde_json::json!([delta(0, "Hello", Some("stop")), delta(1, "Wor", None)]),
+                false,
+            ));
+            let error = accumulator.finish().unwrap_err();
+            assert_eq!(error.unfinished, [1]);
+            assert_eq!(error.accumulator.content(1), Some("Wor"));
+
+            let error = ChatCompletionAccumulator::new().finish().unwrap_err();
+            assert!(error.unfinished.is_empty());
+        }
+
+        /// Serves a single streaming response with the given SSE body.
+        fn serve(body: String) -> String {
+            use std::io::{Read, Write};
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!(
+                "http://{}/v1/chat/completions",
+                listener.local_addr().unwrap()
+            );
+            std::thread::spawn(move || {
+                let (mut socket, _) = listener.accept().unwrap();
+                // Read the whole request, which ends with the 2 bytes of its body
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n{}") {
+                    let read = socket.read(&mut buf).unwrap();
+                    request.extend_from_slice(&buf[..read]);
+                }
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).unwrap();
+            });
+            url
+        }
+
+        fn event(choices: serde_json::Value) -> String {
+            let chunk = serde_json::json!({
+                "id": "chatcmpl-123",
+                "created": 1694268190,
+                "model": "gpt-4o-mini",
+                "choices": choices,
+            });
+            format!("data: {}\n\n", chunk)
+        }
+
+        #[tokio::test]
+        async fn reconnects_after_a_cut_mid_event() {
+            let hel = event(serde_json::json!([delta(0, "Hel", None)]));
+            let lo = event(serde_json::json!([delta(0, "lo", Some("stop"))]));
+            let url = serve(format!("{}{}data: [DONE]\n\n", hel, lo));
+
+            // The first connection drops in the middle of the second event
+            let cut = format!("{}{}", hel, &lo[..lo.len() / 2]);
+            let cut = futures_util::stream::iter([Ok(bytes::Bytes::from(cut))]);
+            let reconnect = Reconnect {
+                request: reqwest::Client::new().post(url).body("{}"),
+                policy: crate::sse::StreamRetryPolicy {
+                    max_retries: 1,
+                    backoff: std::time::Duration::ZERO,
+                },
+                idle_timeout: None,
+                choices: 1,
+            };
+            let completion = ChatCompletionChunkStream::new(Box::pin(cut), Some(reconnect))
+                .collect_completion()
+                .await
+                .unwrap();
+            assert_eq!(completion.choices[0].message.content.to_string(), "Hello");
+            assert_eq!(
+                completion.choices[0].finish_reason,
+                super::super::FinishReason::Stop
+            );
+        }
+    }
+}
+
+/// Information about the tokens used by [ChatCompletion].
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"prompt_tokens\": 2006,
+/// #   \"completion_tokens\": 300,
+/// #   \"total_tokens\": 2306,
+/// #   \"prompt_tokens_details\": {\"cached_tokens\": 1920, \"audio_tokens\": 0},
+/// #   \"completion_tokens_details\": {
+/// #     \"reasoning_tokens\": 192,
+/// #     \"audio_tokens\": 0,
+/// #     \"accepted_prediction_tokens\": 0,
+/// #     \"rejected_prediction_tokens\": 0
+/// #   }
+/// # }";
+/// # let usage = serde_json::from_str::<openai_rust2::chat::Usage>(json).unwrap();
+/// let cached = usage.prompt_tokens_details.map_or(0, |details| details.cached_tokens);
+/// let reasoning = usage.completion_tokens_details.map_or(0, |details| details.reasoning_tokens);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Usage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+    /// A breakdown of the prompt tokens, returned by the newer models.
+    #[serde(default)]
+    pub prompt_tokens_details: Option<PromptTokensDetails>,
+    /// A breakdown of the completion tokens, returned by the newer models.
+    #[serde(default)]
+    pub completion_tokens_details: Option<CompletionTokensDetails>,
+}
+
+/// The breakdown of [Usage::prompt_tokens].
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct PromptTokensDetails {
+    /// Tokens read from the prompt cache, billed at a discount.
+    #[serde(default)]
+    pub cached_tokens: u32,
+    /// Audio input tokens.
+    #[serde(default)]
+    pub audio_tokens: u32,
+}
+
+/// The breakdown of [Usage::completion_tokens].
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct CompletionTokensDetails {
+    /// Tokens generated by the model for reasoning, which are billed but not part of the visible output.
+    #[serde(default)]
+    pub reasoning_tokens: u32,
+    /// Audio output tokens.
+    #[serde(default)]
+    pub audio_tokens: u32,
+    /// Tokens of a Predicted Output that appeared in the completion.
+    #[serde(default)]
+    pub accepted_prediction_tokens: u32,
+    /// Tokens of a Predicted Output that did not appear in the completion, still billed as completion tokens.
+    #[serde(default)]
+    pub rejected_prediction_tokens: u32,
+}
+
+/// Completion choices from [ChatCompletion].
+#[derive(Deserialize, Debug, Clone)]
+pub struct Choice {
+    #[serde(default)]
+    pub index: Option<u32>,
+    pub message: Message,
+    pub finish_reason: FinishReason,
+    /// Log probability information for the choice, when [ChatArguments::logprobs] is enabled.
+    #[serde(default)]
+    pub logprobs: Option<ChoiceLogProbs>,
+}
+
+/// Why the model stopped generating tokens.
+///
+/// Reasons unknown to this crate, such as those of other OpenAI-compatible servers, are kept in [FinishReason::Other].
+/// ```
+/// use openai_rust2::chat::FinishReason;
+/// let reason: FinishReason = serde_json::from_str("\"tool_calls\"").unwrap();
+/// match reason {
+///     FinishReason::ToolCalls => println!("call the tools"),
+///     FinishReason::Length => println!("increase max_tokens"),
+///     _ => {}
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FinishReason {
+    /// The model hit a natural stop point or a provided stop sequence.
+    Stop,
+    /// The maximum number of tokens specified in the request was reached.
+    Length,
+    /// The model called a tool.
+    ToolCalls,
+    /// Content was omitted due to a flag from the content filters.
+    ContentFilter,
+    /// The model called a legacy function.
+    FunctionCall,
+    Other(String),
+}
+
+impl FinishReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FinishReason::Stop => "stop",
+            FinishReason::Length => "length",
+            FinishReason::ToolCalls => "tool_calls",
+            FinishReason::ContentFilter => "content_filter",
+            FinishReason::FunctionCall => "function_call",
+            FinishReason::Other(reason) => reason,
+        }
+    }
+}
+
+impl From<&str> for FinishReason {
+    fn from(reason: &str) -> Self {
+        match reason {
+            "stop" => FinishReason::Stop,
+            "length" => FinishReason::Length,
+            "tool_calls" => FinishReason::ToolCalls,
+            "content_filter" => FinishReason::ContentFilter,
+            "function_call" => FinishReason::FunctionCall,
+            other => FinishReason::Other(other.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for FinishReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for FinishReason {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FinishReason {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(FinishReason::from(
+            String::deserialize(deserializer)?.as_str(),
+        ))
+    }
+}
+
+/// The log probabilities of a [Choice].
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"content\": [{
+/// #     \"token\": \"Hello\",
+/// #     \"logprob\": -0.31725305,
+/// #     \"bytes\": [72, 101, 108, 108, 111],
+/// #     \"top_logprobs\": [
+/// #       {\"token\": \"Hello\", \"logprob\": -0.31725305, \"bytes\": [72, 101, 108, 108, 111]},
+/// #       {\"token\": \"Hi\", \"logprob\": -1.3190403, \"bytes\": [72, 105]}
+/// #     ]
+/// #   }],
+/// #   \"refusal\": null
+/// # }";
+/// # let logprobs = serde_json::from_str::<openai_rust2::chat::ChoiceLogProbs>(json).unwrap();
+/// let token = &logprobs.content.unwrap()[0];
+/// println!("{} ({:.0}%)", token.token, token.probability() * 100.0);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChoiceLogProbs {
+    /// The log probabilities of the message content tokens.
+    #[serde(default)]
+    pub content: Option<Vec<TokenLogProb>>,
+    /// The log probabilities of the message refusal tokens.
+    #[serde(default)]
+    pub refusal: Option<Vec<TokenLogProb>>,
+}
+
+/// The log probability of an output token, see [ChoiceLogProbs].
+#[derive(Deserialize, Debug, Clone)]
+pub struct TokenLogProb {
+    pub token: String,
+    /// The log probability of this token, or `-9999.0` if it is very unlikely.
+    pub logprob: f32,
+    /// The UTF-8 bytes of the token, useful when characters span several tokens.
+    #[serde(default)]
+    pub bytes: Option<Vec<u8>>,
+    /// The most likely tokens at this position, up to [ChatArguments::top_logprobs] of them.
+    #[serde(default)]
+    pub top_logprobs: Vec<TopLogProb>,
+}
+
+impl TokenLogProb {
+    /// The probability of this token, between 0 and 1.
+    pub fn probability(&self) -> f32 {
+        self.logprob.exp()
+    }
+}
+
+/// One of the most likely tokens of a [TokenLogProb].
+#[derive(Deserialize, Debug, Clone)]
+pub struct TopLogProb {
+    pub token: String,
+    pub logprob: f32,
+    #[serde(default)]
+    pub bytes: Option<Vec<u8>>,
+}
+
+/// A citation in the content of a [Message].
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"role\": \"assistant\",
+/// #   \"content\": \"Rust 1.0 was released in 2015 (blog.rust-lang.org).\",
+/// #   \"annotations\": [{
+/// #     \"type\": \"url_citation\",
+/// #     \"url_citation\": {
+/// #       \"url\": \"https://blog.rust-lang.org/2015/05/15/Rust-1.0.html\",
+/// #       \"title\": \"Announcing Rust 1.0\",
+/// #       \"start_index\": 33,
+/// #       \"end_index\": 51
+/// #     }
+/// #   }]
+/// # }";
+/// # let msg = serde_json::from_str::<openai_rust2::chat::Message>(json).unwrap();
+/// use openai_rust2::chat::Annotation;
+/// let content = msg.content.to_string();
+/// for annotation in msg.annotations.iter().flatten() {
+///     if let Annotation::UrlCitation { url_citation } = annotation {
+///         println!("[{}]({})", url_citation.cited_text(&content).unwrap(), url_citation.url);
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Annotation {
+    /// A web page found with [ChatArguments::web_search_options].
+    UrlCitation { url_citation: UrlCitation },
+    #[serde(other)]
+    Unknown,
+}
+
+/// A web page cited by an [Annotation::UrlCitation].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UrlCitation {
+    pub url: String,
+    pub title: String,
+    /// The index of the first character of the citation in the message content.
+    pub start_index: u32,
+    /// The index after the last character of the citation in the message content.
+    pub end_index: u32,
+}
+
+impl UrlCitation {
+    /// The part of `content` that cites the page, if the indices are within it.
+    pub fn cited_text<'a>(&self, content: &'a str) -> Option<&'a str> {
+        let start = content.char_indices().nth(self.start_index as usize)?.0;
+        let end = content
+            .char_indices()
+            .nth(self.end_index as usize)
+            .map_or(content.len(), |(i, _)| i);
+        content.get(start..end)
+    }
+}
+
+/// A message.
+///
+/// An assistant message asking for tools to be called has its [Message::tool_calls] set and usually an empty `content`.
+/// Answer each call with a `tool` message whose [Message::tool_call_id] is the [ToolCall::id].
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"role\": \"assistant\",
+/// #   \"content\": null,
+/// #   \"tool_calls\": [{
+/// #     \"id\": \"call_abc123\",
+/// #     \"type\": \"function\",
+/// #     \"function\": {
+/// #       \"name\": \"get_weather\",
+/// #       \"arguments\": \"{\\\"location\\\": \\\"Boston\\\"}\"
+/// #     }
+/// #   }]
+/// # }";
+/// # let msg = serde_json::from_str::<openai_rust2::chat::Message>(json).unwrap();
+/// #[derive(serde::Deserialize)]
+/// struct Location {
+///     location: String,
+/// }
+/// let call = &msg.tool_calls.as_ref().unwrap()[0];
+/// let args: Location = call.function.parse_arguments().unwrap();
+/// let answer = call.output(format!("It is sunny in {}", args.location));
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Message {
+    pub role: Role,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub content: Content,
+
+    /// An optional name for the participant, to differentiate between participants of the same role
+    /// (such as several agents), or the name of the function a legacy `function` message answers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The tool calls generated by the model, possibly several at once when
+    /// [ChatArguments::parallel_tool_calls] is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ToolCall>>,
+
+    /// Deprecated in favor of [Message::tool_calls].
+    ///
+    /// The function call generated by the model when using [ChatArguments::functions].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function_call: Option<FunctionCall>,
+
+    /// The refusal message generated by the model, instead of `content`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<String>,
+
+    /// The tool call a `tool` message is responding to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+
+    /// The sources cited by the `content` of an assistant message, when the model searched the web.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Vec<Annotation>>,
+}
+
+impl Message {
+    /// A message with the given role and content.
+    pub fn new(role: Role, content: impl Into<Content>) -> Message {
+        Message {
+            role,
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    /// A `system` message.
+    pub fn system(content: impl Into<Content>) -> Message {
+        Message::new(Role::System, content)
+    }
+
+    /// A `developer` message, the instructions of the reasoning models which replace `system` messages.
+    pub fn developer(content: impl Into<Content>) -> Message {
+        Message::new(Role::Developer, content)
+    }
+
+    /// A `user` message.
+    ///
+    /// ```
+    /// use openai_rust2::chat::Message;
+    /// let messages = vec![
+    ///     Message::system("You are a helpful assistant."),
+    ///     Message::user("Hello GPT!"),
+    /// ];
+    /// ```
+    pub fn user(content: impl Into<Content>) -> Message {
+        Message::new(Role::User, content)
+    }
+
+    /// An `assistant` message, such as a previous answer of the model.
+    pub fn assistant(content: impl Into<Content>) -> Message {
+        Message::new(Role::Assistant, content)
+    }
+
+    /// Sets [Message::name].
+    ///
+    /// ```
+    /// use openai_rust2::chat::Message;
+    /// let messages = vec![
+    ///     Message::assistant("I think we should refactor it.").with_name("reviewer"),
+    ///     Message::assistant("It is fine as it is.").with_name("author"),
+    /// ];
+    /// ```
+    pub fn with_name(mut self, name: impl AsRef<str>) -> Message {
+        self.name = Some(name.as_ref().to_owned());
+        self
+    }
+
+    /// A `tool` message answering the tool call with the ID `tool_call_id`.
+    pub fn tool(tool_call_id: impl AsRef<str>, content: impl Into<Content>) -> Message {
+        Message {
+            tool_call_id: Some(tool_call_id.as_ref().to_owned()),
+            ..Message::new(Role::Tool, content)
+        }
+    }
+}
+
+/// The content of assistant messages calling tools is `null`.
+fn null_as_empty<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Content, D::Error> {
+    Ok(Option::<Content>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// The content of a [Message], either plain text or an array of [ContentPart]s.
+///
+/// Text converts into [Content::Text] and a vector of parts into [Content::Parts],
+/// so text, images and other parts can be mixed in one message:
+/// ```
+/// use openai_rust2::chat::{ContentPart, ImageDetail, ImageUrl, Message};
+/// let msg = Message::user(vec![
+///     ContentPart::text("What is in this image?"),
+///     ContentPart::image_url(ImageUrl::new("https://example.com/cat.png").with_detail(ImageDetail::Low)),
+/// ]);
+/// let msg = Message::user("Hello GPT!");
+/// ```
+///
+/// It implements [Display](std::fmt::Display) as a shortcut to get the text, concatenating the text parts.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum Content {
+    Text(String),
+    Parts(Vec<ContentPart>),
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Content::Text(String::new())
+    }
+}
+
+impl Content {
+    /// The text of a [Content::Text], or `None` for [Content::Parts].
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Content::Text(text) => Some(text),
+            Content::Parts(_) => None,
+        }
+    }
+}
+
+impl From<String> for Content {
+    fn from(text: String) -> Self {
+        Content::Text(text)
+    }
+}
+
+impl From<&str> for Content {
+    fn from(text: &str) -> Self {
+        Content::Text(text.to_owned())
+    }
+}
+
+impl From<Vec<ContentPart>> for Content {
+    fn from(parts: Vec<ContentPart>) -> Self {
+        Content::Parts(parts)
+    }
+}
+
+impl std::fmt::Display for Content {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Content::Text(text) => write!(f, "{}", text)?,
+            Content::Parts(parts) => {
+                for part in parts {
+                    if let ContentPart::Text { text } = part {
+                        write!(f, "{}", text)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A part of a [Content::Parts].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    Text {
+        text: String,
+    },
+    /// An image, only supported by vision models such as `gpt-4o`.
+    ImageUrl {
+        image_url: ImageUrl,
+    },
+    /// Audio, only supported by audio models such as `gpt-4o-audio-preview`.
+    InputAudio {
+        input_audio: InputAudio,
+    },
+}
+
+impl ContentPart {
+    pub fn text(text: impl AsRef<str>) -> ContentPart {
+        ContentPart::Text {
+            text: text.as_ref().to_owned(),
+        }
+    }
+
+    pub fn image_url(image_url: ImageUrl) -> ContentPart {
+        ContentPart::ImageUrl { image_url }
+    }
+
+    pub fn input_audio(input_audio: InputAudio) -> ContentPart {
+        ContentPart::InputAudio { input_audio }
+    }
+}
+
+/// The image of a [ContentPart::ImageUrl].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImageUrl {
+    /// Either a URL of the image or the base64 encoded image data, as a data URL.
+    pub url: String,
+
+    /// Specifies the detail level of the image.
+    /// See the [Vision guide](https://platform.openai.com/docs/guides/vision#low-or-high-fidelity-image-understanding).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<ImageDetail>,
+}
+
+impl ImageUrl {
+    pub fn new(url: impl AsRef<str>) -> ImageUrl {
+        ImageUrl {
+            url: url.as_ref().to_owned(),
+            detail: None,
+        }
+    }
+
+    /// An image sent inline, as a data URL built from its mime type (such as `image/png`) and base64 encoded data.
+    ///
+    /// ```
+    /// let image = openai_rust2::chat::ImageUrl::from_base64("image/png", "iVBORw0KGgo=");
+    /// assert_eq!(image.url, "data:image/png;base64,iVBORw0KGgo=");
+    /// ```
+    pub fn from_base64(mime_type: impl AsRef<str>, data: impl AsRef<str>) -> ImageUrl {
+        ImageUrl::new(format!(
+            "data:{};base64,{}",
+            mime_type.as_ref(),
+            data.as_ref()
+        ))
+    }
+
+    pub fn with_detail(mut self, detail: ImageDetail) -> ImageUrl {
+        self.detail = Some(detail);
+        self
+    }
+}
+
+/// The audio of a [ContentPart::InputAudio].
+///
+/// ```no_run
+/// # use tokio_test;
+/// # tokio_test::block_on(async {
+/// use openai_rust2::chat::{ContentPart, InputAudio, Message};
+/// let msg = Message::user(vec![
+///     ContentPart::text("What is in this recording?"),
+///     ContentPart::input_audio(InputAudio::from_file("recording.wav").await.unwrap()),
+/// ]);
+/// # })
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputAudio {
+    /// The base64 encoded audio data.
+    pub data: String,
+    /// The format of the encoded audio data.
+    pub format: InputAudioFormat,
+}
+
+impl InputAudio {
+    /// Encodes raw audio data, such as the contents of a WAV file.
+    ///
+    /// ```
+    /// use openai_rust2::chat::{InputAudio, InputAudioFormat};
+    /// let audio = InputAudio::new(b"RIFF", InputAudioFormat::Wav);
+    /// assert_eq!(audio.data, "UklGRg==");
+    /// ```
+    pub fn new(audio: &[u8], format: InputAudioFormat) -> InputAudio {
+        use base64::Engine;
+        InputAudio {
+            data: base64::engine::general_purpose::STANDARD.encode(audio),
+            format,
+        }
+    }
+
+    /// Reads and encodes a `.wav` or `.mp3` file, with the format taken from its extension.
+    pub async fn from_file(path: impl AsRef<std::path::Path>) -> anyhow::Result<InputAudio> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase());
+        let format = match extension.as_deref() {
+            Some("wav") => InputAudioFormat::Wav,
+            Some("mp3") => InputAudioFormat::Mp3,
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "unsupported audio file {}, expected a .wav or .mp3 file",
+                    path.display()
+                ))
+            }
+        };
+        let audio = tokio::fs::read(path).await?;
+        Ok(InputAudio::new(&audio, format))
+    }
+}
+
+/// The format of an [InputAudio].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InputAudioFormat {
+    Wav,
+    Mp3,
+}
+
+/// The detail level of an [ImageUrl].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageDetail {
+    Auto,
+    Low,
+    High,
+}
+
+/// A tool call generated by the model in a [Message].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ToolCall {
+    /// The ID of the tool call, referenced by the [Message::tool_call_id] of its answer.
+    pub id: String,
+    /// The type of the tool. Currently, only `function` is supported.
+    #[serde(rename = "type", default = "function_type")]
+    pub tool_type: String,
+    /// The function that the model called.
+    pub function: FunctionCall,
+}
+
+impl ToolCall {
+    /// The `tool` message answering this call with `output`, such as the result of the function.
+    ///
+    /// The assistant [Message] holding the calls must be sent back before their answers:
+    /// ```
+    /// # use openai_rust2::chat::{ChatArguments, FunctionCall, Message, ToolCall};
+    /// # let assistant = Message {
+    /// #     role: openai_rust2::chat::Role::Assistant,
+    /// #     tool_calls: Some(vec![ToolCall {
+    /// #         id: "call_abc123".to_owned(),
+    /// #         tool_type: "function".to_owned(),
+    /// #         function: FunctionCall { name: "get_weather".to_owned(), arguments: "{}".to_owned() },
+    /// #     }]),
+    /// #     ..Default::default()
+    /// # };
+    /// # let mut args = ChatArguments::new("gpt-4o-mini", vec![]);
+    /// let outputs: Vec<Message> = assistant
+    ///     .tool_calls
+    ///     .iter()
+    ///     .flatten()
+    ///     .map(|call| call.output("It is sunny"))
+    ///     .collect();
+    /// args.messages.push(assistant);
+    /// args.messages.extend(outputs);
+    /// assert_eq!(args.messages[1].tool_call_id.as_deref(), Some("call_abc123"));
+    /// ```
+    pub fn output(&self, output: impl Into<Content>) -> Message {
+        Message::tool(&self.id, output)
+    }
+}
+
+fn function_type() -> String {
+    "function".to_owned()
+}
+
+/// The function and arguments of a [ToolCall], or of a legacy [Message::function_call].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunctionCall {
+    /// The name of the function to call.
+    pub name: String,
+    /// The arguments to call the function with, as generated by the model in JSON format.
+    /// The model does not always generate valid JSON, so validate them before calling your function.
+    pub arguments: String,
+}
+
+impl FunctionCall {
+    /// Deserialize the arguments.
+    pub fn parse_arguments<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_str(&self.arguments)
+    }
+}
+
+/// Role of a [Message].
+///
+/// Serialized as its lowercase name. Roles unknown to this crate are kept in [Role::Other].
+/// ```
+/// use openai_rust2::chat::Role;
+/// assert_eq!(serde_json::to_string(&Role::Developer).unwrap(), "\"developer\"");
+/// assert_eq!(serde_json::from_str::<Role>("\"critic\"").unwrap(), Role::Other("critic".to_owned()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum Role {
     System,
-    Assistant,
+    #[default]
     User,
+    Assistant,
+    /// The answer to a [ToolCall].
+    Tool,
+    /// Instructions that the model should follow, replacing `system` with the reasoning models.
+    Developer,
+    /// The legacy answer to a [Message::function_call].
+    Function,
+    Other(String),
+}
+
+impl Role {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Role::System => "system",
+            Role::User => "user",
+            Role::Assistant => "assistant",
+            Role::Tool => "tool",
+            Role::Developer => "developer",
+            Role::Function => "function",
+            Role::Other(role) => role,
+        }
+    }
+}
+
+impl From<&str> for Role {
+    fn from(role: &str) -> Self {
+        match role {
+            "system" => Role::System,
+            "user" => Role::User,
+            "assistant" => Role::Assistant,
+            "tool" => Role::Tool,
+            "developer" => Role::Developer,
+            "function" => Role::Function,
+            other => Role::Other(other.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for Role {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Role::from(String::deserialize(deserializer)?.as_str()))
+    }
 }
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+use super::tools::{MaxIterationsError, ToolRegistry};
+use super::{ChatArguments, Content, Message};
+
+/// A chat that keeps its message history, sending it with every new user message.
+///
+/// The assistant replies are recorded, along with the tool calls and outputs of [Conversation::send_with_tools].
+/// A failed request leaves the history unchanged, except for the tools that already ran when
+/// [Conversation::send_with_tools] runs out of iterations.
+///
+/// ```no_run
+/// # use tokio_test;
+/// # tokio_test::block_on(async {
+/// # use openai_rust2 as openai_rust;
+/// # let client = openai_rust::Client::new("");
+/// use openai_rust::chat::Conversation;
+/// let mut conversation = Conversation::new("gpt-4o-mini").with_system("You are a helpful assistant.");
+/// let reply = conversation.send(&client, "Tell me a joke.").await.unwrap();
+/// println!("{}", reply.content);
+/// let snapshot = conversation.snapshot();
+/// let reply = conversation.send(&client, "Explain why it is funny.").await.unwrap();
+/// println!("{}", reply.content);
+/// // Forget the explanation
+/// conversation.restore(snapshot);
+/// # })
+/// ```
+///
+/// It serializes as its [ChatArguments], to be saved and resumed later:
+/// ```
+/// use openai_rust2::chat::{ContentPart, Conversation, ImageUrl, Message, ToolChoice};
+/// let mut conversation = Conversation::new("gpt-4o-mini");
+/// conversation.arguments_mut().tool_choice = Some(ToolChoice::function("get_weather"));
+/// conversation.push(Message::user(vec![
+///     ContentPart::text("What is in this image?"),
+///     ContentPart::image_url(ImageUrl::new("https://example.com/cat.png")),
+/// ]));
+/// let json = serde_json::to_string(&conversation).unwrap();
+/// let resumed: Conversation = serde_json::from_str(&json).unwrap();
+/// assert_eq!(resumed.messages().len(), 1);
+/// assert_eq!(resumed.arguments().tool_choice, Some(ToolChoice::function("get_weather")));
+/// assert_eq!(resumed.arguments().model, "gpt-4o-mini");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct Conversation {
+    args: ChatArguments,
+}
+
+impl Conversation {
+    /// An empty conversation with `model`.
+    pub fn new(model: impl AsRef<str>) -> Conversation {
+        Conversation {
+            args: ChatArguments::new(model, vec![]),
+        }
+    }
+
+    /// A conversation sending `args` with every request, starting with their messages.
+    pub fn from_arguments(args: ChatArguments) -> Conversation {
+        Conversation { args }
+    }
+
+    /// Appends a `system` message.
+    pub fn with_system(mut self, content: impl Into<Content>) -> Conversation {
+        self.args.messages.push(Message::system(content));
+        self
+    }
+
+    /// The messages so far.
+    pub fn messages(&self) -> &[Message] {
+        &self.args.messages
+    }
+
+    /// The arguments sent with every request, including the messages.
+    pub fn arguments(&self) -> &ChatArguments {
+        &self.args
+    }
+
+    /// Allows to change the arguments, such as the model, between requests.
+    pub fn arguments_mut(&mut self) -> &mut ChatArguments {
+        &mut self.args
+    }
+
+    /// Appends a message without sending it.
+    pub fn push(&mut self, message: Message) {
+        self.args.messages.push(message);
+    }
+
+    /// Sends a user message and records the reply of the model.
+    pub async fn send(
+        &mut self,
+        client: &crate::Client,
+        content: impl Into<Content>,
+    ) -> anyhow::Result<&Message> {
+        let mut args = self.args.clone();
+        args.messages.push(Message::user(content));
+        let completion = client.create_chat(args.clone(), None).await?;
+        let Some(choice) = completion.choices.into_iter().next() else {
+            return Err(anyhow::anyhow!("the response has no choices"));
+        };
+        args.messages.push(choice.message);
+        self.args = args;
+        // Just pushed
+        Ok(self.args.messages.last().unwrap())
+    }
+
+    /// Like [Conversation::send] but runs the tools requested by the model,
+    /// see [Client::create_chat_with_tools](crate::Client::create_chat_with_tools).
+    ///
+    /// When the model is still calling tools after `max_iterations` completions, the tool calls and outputs so far
+    /// are recorded before returning the [MaxIterationsError], so that a next message continues from them.
+    pub async fn send_with_tools(
+        &mut self,
+        client: &crate::Client,
+        content: impl Into<Content>,
+        tools: &ToolRegistry,
+        max_iterations: usize,
+    ) -> anyhow::Result<&Message> {
+        let mut args = self.args.clone();
+        args.messages.push(Message::user(content));
+        let run = match client
+            .create_chat_with_tools(args, tools, max_iterations, None)
+            .await
+        {
+            Ok(run) => run,
+            Err(e) => {
+                if let Some(e) = e.downcast_ref::<MaxIterationsError>() {
+                    self.args.messages = e.messages.clone();
+                }
+                return Err(e);
+            }
+        };
+        self.args.messages = run.messages;
+        // Always ends with the answer
+        Ok(self.args.messages.last().unwrap())
+    }
+
+    /// A copy of the messages so far, to [Conversation::restore] later.
+    pub fn snapshot(&self) -> Vec<Message> {
+        self.args.messages.clone()
+    }
+
+    /// Replaces the messages, for example with a [Conversation::snapshot].
+    pub fn restore(&mut self, messages: Vec<Message>) {
+        self.args.messages = messages;
+    }
+}
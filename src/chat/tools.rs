@@ -0,0 +1,252 @@
+//! Run the tools requested by the model, see [Client::create_chat_with_tools](crate::Client::create_chat_with_tools).
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+
+use super::{ChatCompletion, ChatTool, FunctionDefinition, Message, ToolCall};
+
+/// A tool implemented in Rust, whose arguments are deserialized from those generated by the model.
+///
+/// Add it to a [ToolRegistry] with [ToolRegistry::register_tool].
+///
+/// ```
+/// # use tokio_test;
+/// # tokio_test::block_on(async {
+/// use openai_rust2::chat::tools::{Tool, ToolRegistry};
+///
+/// #[derive(serde::Deserialize)]
+/// struct WeatherArguments {
+///     location: String,
+/// }
+///
+/// struct GetWeather;
+///
+/// impl Tool for GetWeather {
+///     type Arguments = WeatherArguments;
+///
+///     fn name(&self) -> &str {
+///         "get_weather"
+///     }
+///
+///     fn description(&self) -> &str {
+///         "Get the current weather in a given location"
+///     }
+///
+///     fn parameters(&self) -> serde_json::Value {
+///         serde_json::json!({
+///             "type": "object",
+///             "properties": {"location": {"type": "string"}},
+///             "required": ["location"]
+///         })
+///     }
+///
+///     async fn call(&self, arguments: WeatherArguments) -> anyhow::Result<String> {
+///         Ok(format!("It is sunny in {}", arguments.location))
+///     }
+/// }
+///
+/// let tools = ToolRegistry::new().register_tool(GetWeather);
+/// # let call = serde_json::from_value(serde_json::json!({
+/// #     "id": "call_abc123",
+/// #     "type": "function",
+/// #     "function": {"name": "get_weather", "arguments": "{\"location\": \"Boston\"}"}
+/// # })).unwrap();
+/// let answer = tools.call(&call).await;
+/// assert_eq!(answer.content.to_string(), "It is sunny in Boston");
+/// # })
+/// ```
+pub trait Tool: Send + Sync + 'static {
+    /// The arguments of the tool, deserialized from the JSON generated by the model.
+    type Arguments: DeserializeOwned + Send;
+
+    /// The name of the function.
+    fn name(&self) -> &str;
+
+    /// What the function does, used by the model to choose when and how to call it.
+    fn description(&self) -> &str;
+
+    /// The JSON Schema of [Tool::Arguments], which can be generated with [parameters_for].
+    fn parameters(&self) -> serde_json::Value;
+
+    /// Whether the model must follow [Tool::parameters] exactly, see [FunctionDefinition::strict].
+    fn strict(&self) -> bool {
+        false
+    }
+
+    /// Runs the tool, returning the output sent to the model.
+    fn call(
+        &self,
+        arguments: Self::Arguments,
+    ) -> impl Future<Output = anyhow::Result<String>> + Send;
+
+    /// The definition sent to the model.
+    fn definition(&self) -> ChatTool {
+        ChatTool::Function {
+            function: FunctionDefinition {
+                name: self.name().to_owned(),
+                description: Some(self.description().to_owned()),
+                parameters: Some(self.parameters()),
+                strict: self.strict().then_some(true),
+            },
+        }
+    }
+}
+
+/// The strict JSON Schema of `T`, for [Tool::parameters].
+///
+/// Requires the `schemars` feature.
+///
+/// ```
+/// # #[cfg(feature = "schemars")]
+/// # {
+/// #[derive(serde::Deserialize, schemars::JsonSchema)]
+/// struct WeatherArguments {
+///     location: String,
+///     unit: Option<String>,
+/// }
+/// let parameters = openai_rust2::chat::tools::parameters_for::<WeatherArguments>();
+/// assert!(openai_rust2::chat::validate_strict_schema(&parameters).is_ok());
+/// # }
+/// ```
+#[cfg(feature = "schemars")]
+pub fn parameters_for<T: schemars::JsonSchema>() -> serde_json::Value {
+    // Always set by from_type
+    super::JsonSchemaFormat::from_type::<T>().schema.unwrap()
+}
+
+/// The future returned by a tool handler, resolving to the output sent back to the model.
+pub type ToolFuture = Pin<Box<dyn Future<Output = anyhow::Result<String>> + Send>>;
+
+type Handler = Box<dyn Fn(String) -> ToolFuture + Send + Sync>;
+
+/// The tools the model may call, with the handlers running them.
+///
+/// A handler receives the JSON arguments generated by the model and returns the output of the tool.
+/// Its errors are sent to the model as the output, so it can try again.
+///
+/// ```
+/// use openai_rust2::chat::tools::ToolRegistry;
+/// use openai_rust2::chat::ChatTool;
+/// let tools = ToolRegistry::new().register(
+///     ChatTool::function(
+///         "get_weather",
+///         "Get the current weather in a given location",
+///         serde_json::json!({
+///             "type": "object",
+///             "properties": {"location": {"type": "string"}},
+///             "required": ["location"]
+///         }),
+///     ),
+///     |arguments| async move {
+///         let arguments: serde_json::Value = serde_json::from_str(&arguments)?;
+///         Ok(format!("It is sunny in {}", arguments["location"]))
+///     },
+/// );
+/// ```
+#[derive(Default)]
+pub struct ToolRegistry {
+    definitions: Vec<ChatTool>,
+    handlers: HashMap<String, Handler>,
+}
+
+impl ToolRegistry {
+    pub fn new() -> ToolRegistry {
+        ToolRegistry::default()
+    }
+
+    /// Adds a tool, replacing any previous tool with the same name.
+    pub fn register<F, Fut>(mut self, tool: ChatTool, handler: F) -> ToolRegistry
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<String>> + Send + 'static,
+    {
+        let name = tool.name().to_owned();
+        self.definitions
+            .retain(|definition| definition.name() != name);
+        self.definitions.push(tool);
+        self.handlers.insert(
+            name,
+            Box::new(move |arguments| Box::pin(handler(arguments))),
+        );
+        self
+    }
+
+    /// Adds a [Tool], replacing any previous tool with the same name.
+    ///
+    /// If the arguments generated by the model don't deserialize, the error is sent back to the model.
+    pub fn register_tool<T: Tool>(self, tool: T) -> ToolRegistry {
+        let definition = tool.definition();
+        let tool = Arc::new(tool);
+        self.register(definition, move |arguments| {
+            let tool = tool.clone();
+            async move {
+                let arguments = serde_json::from_str::<T::Arguments>(&arguments)?;
+                tool.call(arguments).await
+            }
+        })
+    }
+
+    /// The definitions of the tools, to send as [ChatArguments::tools](super::ChatArguments::tools).
+    pub fn definitions(&self) -> &[ChatTool] {
+        &self.definitions
+    }
+
+    /// Runs the tool requested by `call`, answering with a `tool` message.
+    pub async fn call(&self, call: &ToolCall) -> Message {
+        let output = match self.handlers.get(&call.function.name) {
+            Some(handler) => match handler(call.function.arguments.clone()).await {
+                Ok(output) => output,
+                Err(e) => format!("Error: {}", e),
+            },
+            None => format!("Error: there is no tool named {}", call.function.name),
+        };
+        call.output(output)
+    }
+}
+
+/// The result of [Client::create_chat_with_tools](crate::Client::create_chat_with_tools).
+#[derive(Debug, Clone)]
+pub struct ToolRun {
+    /// The messages of the arguments, followed by the tool calls of the model,
+    /// the outputs of the tools and the final answer.
+    pub messages: Vec<Message>,
+    /// The completion with the final answer.
+    pub completion: ChatCompletion,
+}
+
+impl ToolRun {
+    /// The final answer of the model.
+    pub fn message(&self) -> &Message {
+        // Always ends with the answer
+        self.messages.last().unwrap()
+    }
+}
+
+/// The model was still calling tools after the `max_iterations` completions of
+/// [Client::create_chat_with_tools](crate::Client::create_chat_with_tools).
+///
+/// It is wrapped in the returned [anyhow::Error], from which it can be recovered with [anyhow::Error::downcast],
+/// to inspect the tools that already ran or to continue the run.
+#[derive(Debug, Clone)]
+pub struct MaxIterationsError {
+    pub max_iterations: usize,
+    /// The messages of the arguments, followed by the tool calls of the model and the outputs of the tools.
+    pub messages: Vec<Message>,
+    /// The last completion, still calling tools. `None` when `max_iterations` is 0.
+    pub completion: Option<ChatCompletion>,
+}
+
+impl std::fmt::Display for MaxIterationsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the model was still calling tools after {} completions",
+            self.max_iterations
+        )
+    }
+}
+
+impl std::error::Error for MaxIterationsError {}
@@ -1,5 +1,5 @@
 //! See <https://platform.openai.com/docs/api-reference/completions>.
-//! Use with [Client::create_completion](crate::Client::create_completion).
+//! Use with [Client::create_completion](crate::Client::create_completion) or [Client::create_completion_stream](crate::Client::create_completion_stream).
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
@@ -14,6 +14,16 @@ use serde::{Deserialize, Serialize};
 ///     "The quick brown fox".to_owned()
 /// );
 /// ```
+///
+/// Or with [CompletionArguments::builder]:
+/// ```
+/// let args = openai_rust2::completions::CompletionArguments::builder("gpt-3.5-turbo-instruct")
+///     .prompt("The quick brown fox")
+///     .max_tokens(16)
+///     .temperature(0.2)
+///     .build();
+/// assert_eq!(args.prompt.as_deref(), Some("The quick brown fox"));
+/// ```
 #[derive(Serialize, Debug, Clone)]
 pub struct CompletionArguments {
     /// ID of the model to use.
@@ -62,7 +72,7 @@ pub struct CompletionArguments {
 
     /// How many completions to generate for each prompt.
     ///
-    /// *Note:* Because this parameter generates many completions,it can quickly consume your token quota.
+    /// *Note:* Because this parameter generates many completions, it can quickly consume your token quota.
     /// Use carefully and ensure that you have reasonable settings for `max_tokens` and `stop`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub n: Option<u32>,
@@ -80,13 +90,13 @@ pub struct CompletionArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub logprobs: Option<u8>,
 
-    /// Echo back the prompt in addition to the completion
+    /// Echo back the prompt in addition to the completion.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub echo: Option<bool>,
 
     /// Up to 4 sequences where the API will stop generating further tokens. The returned text will not contain the stop sequence.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop: Option<String>,
+    pub stop: Option<crate::chat::Stop>,
 
     /// Number between -2.0 and 2.0. Positive values penalize new tokens based on whether they appear in the text so far,
     /// increasing the model's likelihood to talk about new topics.
@@ -107,12 +117,24 @@ pub struct CompletionArguments {
     ///
     /// When used with `n`, `best_of` controls the number of candidate completions and `n` specifies how many to return – `best_of` must be greater than `n`.
     ///
-    /// *Note:* Because this parameter generates many completions,it can quickly consume your token quota.
-    /// Use carefully and ensure that you have reasonable settings for max_tokens` and `stop`.
+    /// *Note:* Because this parameter generates many completions, it can quickly consume your token quota.
+    /// Use carefully and ensure that you have reasonable settings for `max_tokens` and `stop`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub best_of: Option<u32>,
 
-    //logit_bias
+    /// Modify the likelihood of specified tokens appearing in the completion.
+    ///
+    /// Maps token IDs (as strings) to a bias value from -100 to 100. Values between -1 and 1 decrease or increase
+    /// the likelihood of selection, while values like -100 or 100 result in a ban or exclusive selection of the token.
+    ///
+    /// ```
+    /// # let mut args = openai_rust2::completions::CompletionArguments::new("gpt-3.5-turbo-instruct", "".to_owned());
+    /// // Ban the token `50256`, which is <|endoftext|>
+    /// args.logit_bias = Some([("50256".to_owned(), -100.0)].into());
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logit_bias: Option<HashMap<String, f32>>,
+
     /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
     /// [Learn more](https://platform.openai.com/docs/guides/safety-best-practices/end-user-ids).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -136,9 +158,113 @@ impl CompletionArguments {
             presence_penalty: None,
             frequency_penalty: None,
             best_of: None,
+            logit_bias: None,
             user: None,
         }
     }
+
+    /// A [CompletionArgumentsBuilder] for `model`, without a prompt yet.
+    pub fn builder(model: impl AsRef<str>) -> CompletionArgumentsBuilder {
+        let mut args = CompletionArguments::new(model, String::new());
+        args.prompt = None;
+        CompletionArgumentsBuilder { args }
+    }
+}
+
+/// A fluent builder of [CompletionArguments], created with [CompletionArguments::builder].
+#[derive(Debug, Clone)]
+pub struct CompletionArgumentsBuilder {
+    args: CompletionArguments,
+}
+
+impl CompletionArgumentsBuilder {
+    /// Sets [CompletionArguments::prompt].
+    pub fn prompt(mut self, prompt: impl AsRef<str>) -> CompletionArgumentsBuilder {
+        self.args.prompt = Some(prompt.as_ref().to_owned());
+        self
+    }
+
+    /// Sets [CompletionArguments::suffix].
+    pub fn suffix(mut self, suffix: impl AsRef<str>) -> CompletionArgumentsBuilder {
+        self.args.suffix = Some(suffix.as_ref().to_owned());
+        self
+    }
+
+    /// Sets [CompletionArguments::max_tokens].
+    pub fn max_tokens(mut self, max_tokens: u32) -> CompletionArgumentsBuilder {
+        self.args.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Sets [CompletionArguments::temperature].
+    pub fn temperature(mut self, temperature: f32) -> CompletionArgumentsBuilder {
+        self.args.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets [CompletionArguments::top_p].
+    pub fn top_p(mut self, top_p: f32) -> CompletionArgumentsBuilder {
+        self.args.top_p = Some(top_p);
+        self
+    }
+
+    /// Sets [CompletionArguments::n].
+    pub fn n(mut self, n: u32) -> CompletionArgumentsBuilder {
+        self.args.n = Some(n);
+        self
+    }
+
+    /// Sets [CompletionArguments::logprobs].
+    pub fn logprobs(mut self, logprobs: u8) -> CompletionArgumentsBuilder {
+        self.args.logprobs = Some(logprobs);
+        self
+    }
+
+    /// Sets [CompletionArguments::echo].
+    pub fn echo(mut self, echo: bool) -> CompletionArgumentsBuilder {
+        self.args.echo = Some(echo);
+        self
+    }
+
+    /// Sets [CompletionArguments::stop].
+    pub fn stop(mut self, stop: impl Into<crate::chat::Stop>) -> CompletionArgumentsBuilder {
+        self.args.stop = Some(stop.into());
+        self
+    }
+
+    /// Sets [CompletionArguments::presence_penalty].
+    pub fn presence_penalty(mut self, presence_penalty: f32) -> CompletionArgumentsBuilder {
+        self.args.presence_penalty = Some(presence_penalty);
+        self
+    }
+
+    /// Sets [CompletionArguments::frequency_penalty].
+    pub fn frequency_penalty(mut self, frequency_penalty: f32) -> CompletionArgumentsBuilder {
+        self.args.frequency_penalty = Some(frequency_penalty);
+        self
+    }
+
+    /// Sets [CompletionArguments::best_of].
+    pub fn best_of(mut self, best_of: u32) -> CompletionArgumentsBuilder {
+        self.args.best_of = Some(best_of);
+        self
+    }
+
+    /// Sets [CompletionArguments::logit_bias].
+    pub fn logit_bias(mut self, logit_bias: HashMap<String, f32>) -> CompletionArgumentsBuilder {
+        self.args.logit_bias = Some(logit_bias);
+        self
+    }
+
+    /// Sets [CompletionArguments::user].
+    pub fn user(mut self, user: impl AsRef<str>) -> CompletionArgumentsBuilder {
+        self.args.user = Some(user.as_ref().to_owned());
+        self
+    }
+
+    pub fn build(self) -> CompletionArguments {
+        self.args
+    }
 }
 
 /// The repsonse of a completion request.
@@ -193,7 +319,7 @@ pub struct Choice {
     pub text: String,
     pub index: u32,
     pub logprobs: Option<LogProbs>,
-    pub finish_reason: String,
+    pub finish_reason: crate::chat::FinishReason,
 }
 
 /// The log probabilities of a completion response.
@@ -213,6 +339,99 @@ pub struct Usage {
     pub total_tokens: u32,
 }
 
+/// Structs and deserialization method for the responses
+/// when using streaming completions.
+pub mod stream {
+    use futures_util::Stream;
+    use serde::Deserialize;
+    use std::pin::Pin;
+    use std::task::Poll;
+
+    /// This is the partial completion result received when streaming.
+    ///
+    /// It implements [Display](std::fmt::Display) as a shortcut to easily extract the content.
+    /// ```
+    /// # use serde_json;
+    /// # let json = "{
+    /// # \"id\": \"cmpl-7C9Wxi9Du4j1lQjdjhxBlO22M61LD\",
+    /// # \"object\": \"text_completion\",
+    /// # \"created\": 1683132412,
+    /// # \"model\": \"gpt-3.5-turbo-instruct\",
+    /// # \"choices\": [
+    /// #   {
+    /// #     \"text\": \" jumps\",
+    /// #     \"index\": 0,
+    /// #     \"logprobs\": null,
+    /// #     \"finish_reason\": null
+    /// #   }
+    /// # ]
+    /// # }";
+    /// # let res = serde_json::from_str::<openai_rust2::completions::stream::CompletionChunk>(json).unwrap();
+    /// let text = &res.choices[0].text;
+    /// // or
+    /// let text = res.to_string();
+    /// ```
+    #[derive(Deserialize, Debug, Clone)]
+    pub struct CompletionChunk {
+        pub id: String,
+        pub created: u32,
+        pub model: String,
+        pub choices: Vec<Choice>,
+    }
+
+    impl std::fmt::Display for CompletionChunk {
+        /// Automatically grab the first choice, if any
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if let Some(choice) = self.choices.first() {
+                write!(f, "{}", choice.text)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Choices for [CompletionChunk].
+    #[derive(Deserialize, Debug, Clone)]
+    pub struct Choice {
+        pub text: String,
+        pub index: u32,
+        pub logprobs: Option<super::LogProbs>,
+        pub finish_reason: Option<crate::chat::FinishReason>,
+    }
+
+    pub struct CompletionChunkStream {
+        event_stream: crate::sse::EventStream,
+    }
+
+    impl CompletionChunkStream {
+        pub(crate) fn new(stream: crate::sse::ByteStream) -> Self {
+            Self {
+                event_stream: crate::sse::EventStream::new(stream),
+            }
+        }
+    }
+
+    impl Stream for CompletionChunkStream {
+        type Item = anyhow::Result<CompletionChunk>;
+
+        fn poll_next(
+            mut self: Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            match Pin::new(&mut self.event_stream).poll_next(cx) {
+                // The [DONE] marker ends the stream
+                Poll::Ready(Some(Ok(event))) if event.data == "[DONE]" => Poll::Ready(None),
+                Poll::Ready(Some(Ok(event))) => Poll::Ready(Some(
+                    serde_json::from_str::<CompletionChunk>(&event.data)
+                        .map_err(|e| anyhow::anyhow!(e)),
+                )),
+                Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+}
+
 /*
 {
   "logprobs": {
@@ -1,5 +1,8 @@
 //! See <https://platform.openai.com/docs/api-reference/edits>.
 //! Use with [Client::create_edit](crate::Client::create_edit).
+//!
+//! The `/v1/edits` endpoint is deprecated, so by default edits are executed
+//! through chat completions instead. See [EditBackend].
 #![deprecated = "Use the chat api instead"]
 
 use serde::{Deserialize, Serialize};
@@ -15,6 +18,8 @@ use serde::{Deserialize, Serialize};
 ///     "Complete this sentence.".to_owned()
 /// );
 /// ```
+///
+/// To call the deprecated endpoint directly, set [EditArguments::backend] to [EditBackend::Edits].
 #[derive(Serialize, Debug, Clone)]
 pub struct EditArguments {
     /// ID of the model to use. You can use the `text-davinci-edit-001` or `code-davinci-edit-001` model with this endpoint.
@@ -45,6 +50,39 @@ pub struct EditArguments {
     /// We generally recommend altering this or `temperature` but not both.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub top_p: Option<f32>,
+
+    /// Where the edit is executed. Defaults to chat completions.
+    #[serde(skip)]
+    pub backend: EditBackend,
+}
+
+/// The chat model used by [EditBackend::default].
+pub const DEFAULT_CHAT_MODEL: &str = "gpt-4o-mini";
+
+/// How [Client::create_edit](crate::Client::create_edit) executes an [EditArguments].
+///
+/// ```
+/// use openai_rust2::edits::{EditArguments, EditBackend};
+/// let mut args = EditArguments::new(
+///     "text-davinci-edit-001",
+///     "The quick brown fox".to_owned(),
+///     "Complete this sentence.".to_owned()
+/// );
+/// args.backend = EditBackend::Chat("gpt-4o".to_owned());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditBackend {
+    /// Run the edit as a chat completion with the given chat model.
+    /// [EditArguments::model] is ignored, since the edit models are not chat models.
+    Chat(String),
+    /// Call the deprecated `/v1/edits` endpoint.
+    Edits,
+}
+
+impl Default for EditBackend {
+    fn default() -> Self {
+        EditBackend::Chat(DEFAULT_CHAT_MODEL.to_owned())
+    }
 }
 
 impl EditArguments {
@@ -52,12 +90,34 @@ impl EditArguments {
         EditArguments {
             model: model.as_ref().to_owned(),
             input: Some(input),
-            instruction: instruction,
+            instruction,
             n: None,
             temperature: None,
             top_p: None,
+            backend: EditBackend::default(),
         }
     }
+
+    /// Builds the equivalent chat request, with the instruction as the system message
+    /// and the input as the user message.
+    pub(crate) fn into_chat_arguments(self, model: &str) -> crate::chat::ChatArguments {
+        let system = format!(
+            "Apply the following instruction to the text provided by the user. \
+            Reply with only the edited text.\n\nInstruction: {}",
+            self.instruction
+        );
+        let mut args = crate::chat::ChatArguments::new(
+            model,
+            vec![
+                crate::chat::Message::system(system),
+                crate::chat::Message::user(self.input.unwrap_or_default()),
+            ],
+        );
+        args.n = self.n;
+        args.temperature = self.temperature;
+        args.top_p = self.top_p;
+        args
+    }
 }
 
 /// The response of an edit request.
@@ -114,3 +174,25 @@ pub struct Usage {
     pub completion_tokens: u32,
     pub total_tokens: u32,
 }
+
+impl From<crate::chat::ChatCompletion> for EditResponse {
+    fn from(chat: crate::chat::ChatCompletion) -> Self {
+        EditResponse {
+            created: chat.created,
+            choices: chat
+                .choices
+                .into_iter()
+                .enumerate()
+                .map(|(i, choice)| Choice {
+                    text: choice.message.content.to_string(),
+                    index: choice.index.unwrap_or(i as u32),
+                })
+                .collect(),
+            usage: Usage {
+                prompt_tokens: chat.usage.prompt_tokens,
+                completion_tokens: chat.usage.completion_tokens,
+                total_tokens: chat.usage.total_tokens,
+            },
+        }
+    }
+}
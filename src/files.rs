@@ -0,0 +1,183 @@
+//! See <https://platform.openai.com/docs/api-reference/files>.
+//! Use with [Client::upload_file](crate::Client::upload_file), [Client::list_files](crate::Client::list_files),
+//! [Client::retrieve_file](crate::Client::retrieve_file), [Client::delete_file](crate::Client::delete_file)
+//! or [Client::retrieve_file_content](crate::Client::retrieve_file_content).
+
+use reqwest::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+
+/// The intended purpose of an uploaded file.
+///
+/// Purposes unknown to this crate are kept in [FilePurpose::Other].
+/// ```
+/// use openai_rust2::files::FilePurpose;
+/// assert_eq!(serde_json::to_string(&FilePurpose::FineTune).unwrap(), "\"fine-tune\"");
+/// assert_eq!(
+///     serde_json::from_str::<FilePurpose>("\"responses\"").unwrap(),
+///     FilePurpose::Other("responses".to_owned())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilePurpose {
+    /// Used in the Assistants API.
+    Assistants,
+    /// Files generated by the Assistants API.
+    AssistantsOutput,
+    /// Used in the Batch API.
+    Batch,
+    /// Output files of the Batch API.
+    BatchOutput,
+    /// Used for fine-tuning.
+    FineTune,
+    /// Result files of a fine-tuning job.
+    FineTuneResults,
+    /// Images used for vision fine-tuning.
+    Vision,
+    /// Flexible file type for any purpose.
+    UserData,
+    /// Used for eval data sets.
+    Evals,
+    Other(String),
+}
+
+impl FilePurpose {
+    /// The name of the purpose as used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilePurpose::Assistants => "assistants",
+            FilePurpose::AssistantsOutput => "assistants_output",
+            FilePurpose::Batch => "batch",
+            FilePurpose::BatchOutput => "batch_output",
+            FilePurpose::FineTune => "fine-tune",
+            FilePurpose::FineTuneResults => "fine-tune-results",
+            FilePurpose::Vision => "vision",
+            FilePurpose::UserData => "user_data",
+            FilePurpose::Evals => "evals",
+            FilePurpose::Other(purpose) => purpose,
+        }
+    }
+}
+
+impl From<&str> for FilePurpose {
+    fn from(purpose: &str) -> Self {
+        match purpose {
+            "assistants" => FilePurpose::Assistants,
+            "assistants_output" => FilePurpose::AssistantsOutput,
+            "batch" => FilePurpose::Batch,
+            "batch_output" => FilePurpose::BatchOutput,
+            "fine-tune" => FilePurpose::FineTune,
+            "fine-tune-results" => FilePurpose::FineTuneResults,
+            "vision" => FilePurpose::Vision,
+            "user_data" => FilePurpose::UserData,
+            "evals" => FilePurpose::Evals,
+            other => FilePurpose::Other(other.to_owned()),
+        }
+    }
+}
+
+impl Serialize for FilePurpose {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilePurpose {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(FilePurpose::from(
+            String::deserialize(deserializer)?.as_str(),
+        ))
+    }
+}
+
+/// Request arguments for uploading a file.
+///
+/// See <https://platform.openai.com/docs/api-reference/files/create>.
+///
+/// ```
+/// use openai_rust2::files::{FilePurpose, UploadFileArguments};
+/// let args = UploadFileArguments::new(
+///     "mydata.jsonl",
+///     std::fs::read("mydata.jsonl").unwrap_or_default(),
+///     FilePurpose::FineTune,
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct UploadFileArguments {
+    /// The contents of the file to be uploaded.
+    pub file: Vec<u8>,
+
+    /// The name of the file.
+    pub filename: String,
+
+    /// The intended purpose of the uploaded file.
+    pub purpose: FilePurpose,
+}
+
+impl UploadFileArguments {
+    pub fn new(
+        filename: impl AsRef<str>,
+        file: Vec<u8>,
+        purpose: FilePurpose,
+    ) -> UploadFileArguments {
+        UploadFileArguments {
+            file,
+            filename: filename.as_ref().to_owned(),
+            purpose,
+        }
+    }
+
+    /// Build the multipart form sent to the API.
+    pub(crate) fn into_form(self) -> Form {
+        Form::new()
+            .part("file", Part::bytes(self.file).file_name(self.filename))
+            .text("purpose", self.purpose.as_str().to_owned())
+    }
+}
+
+/// A document that has been uploaded to OpenAI.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"file-abc123\",
+/// #  \"object\": \"file\",
+/// #  \"bytes\": 120000,
+/// #  \"created_at\": 1677610602,
+/// #  \"filename\": \"mydata.jsonl\",
+/// #  \"purpose\": \"fine-tune\",
+/// #  \"status\": \"processed\"
+/// # }";
+/// # let file = serde_json::from_str::<openai_rust2::files::FileObject>(json).unwrap();
+/// println!("{} ({} bytes)", file.filename, file.bytes);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct FileObject {
+    /// The file identifier, which can be referenced in the API endpoints.
+    pub id: String,
+    /// The size of the file, in bytes.
+    pub bytes: u64,
+    /// The Unix timestamp (in seconds) for when the file was created.
+    pub created_at: u64,
+    /// The name of the file.
+    pub filename: String,
+    /// The intended purpose of the file.
+    pub purpose: FilePurpose,
+    /// The current status of the file, which can be either `uploaded`, `processed`, or `error`.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// For details on why a fine-tuning training file failed validation.
+    #[serde(default)]
+    pub status_details: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ListFilesResponse {
+    pub data: Vec<FileObject>,
+}
+
+/// The response of a file deletion request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteFileResponse {
+    pub id: String,
+    pub deleted: bool,
+}
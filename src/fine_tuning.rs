@@ -0,0 +1,285 @@
+//! See <https://platform.openai.com/docs/api-reference/fine-tuning>.
+//! Use with [Client::create_fine_tuning_job](crate::Client::create_fine_tuning_job), [Client::list_fine_tuning_jobs](crate::Client::list_fine_tuning_jobs),
+//! [Client::retrieve_fine_tuning_job](crate::Client::retrieve_fine_tuning_job), [Client::list_fine_tuning_events](crate::Client::list_fine_tuning_events)
+//! [Client::cancel_fine_tuning_job](crate::Client::cancel_fine_tuning_job) or [Client::list_fine_tuning_checkpoints](crate::Client::list_fine_tuning_checkpoints).
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Request arguments for creating a fine-tuning job.
+///
+/// See <https://platform.openai.com/docs/api-reference/fine-tuning/create>.
+///
+/// ```
+/// use openai_rust2::fine_tuning::{FineTuningJobArguments, Hyperparameter, Hyperparameters};
+/// let mut args = FineTuningJobArguments::new("gpt-4o-mini-2024-07-18", "file-abc123");
+/// args.suffix = Some("custom-model-name".to_owned());
+/// args.hyperparameters = Some(Hyperparameters {
+///     n_epochs: Some(Hyperparameter::Value(2)),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct FineTuningJobArguments {
+    /// The name of the model to fine-tune. You can select one of the [supported models](https://platform.openai.com/docs/guides/fine-tuning#which-models-can-be-fine-tuned).
+    pub model: String,
+
+    /// The ID of an uploaded file that contains training data.
+    ///
+    /// See [upload file](crate::Client::upload_file) for how to upload a file.
+    /// Your dataset must be formatted as a JSONL file and uploaded with the purpose `fine-tune`.
+    pub training_file: String,
+
+    /// The ID of an uploaded file that contains validation data.
+    ///
+    /// If you provide this file, the data is used to generate validation metrics periodically during fine-tuning.
+    /// The same data should not be present in both train and validation files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_file: Option<String>,
+
+    /// A string of up to 64 characters that will be added to your fine-tuned model name.
+    ///
+    /// For example, a `suffix` of "custom-model-name" would produce a model name like `ft:gpt-4o-mini:openai:custom-model-name:7p4lURel`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+
+    /// The hyperparameters used for the fine-tuning job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hyperparameters: Option<Hyperparameters>,
+
+    /// The seed controls the reproducibility of the job. If a seed is not specified, one will be generated for you.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+impl FineTuningJobArguments {
+    pub fn new(model: impl AsRef<str>, training_file: impl AsRef<str>) -> FineTuningJobArguments {
+        FineTuningJobArguments {
+            model: model.as_ref().to_owned(),
+            training_file: training_file.as_ref().to_owned(),
+            validation_file: None,
+            suffix: None,
+            hyperparameters: None,
+            seed: None,
+        }
+    }
+}
+
+/// The hyperparameters used for a fine-tuning job.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Hyperparameters {
+    /// Number of examples in each batch. A larger batch size means that model parameters are updated less frequently, but with lower variance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_size: Option<Hyperparameter<u32>>,
+
+    /// Scaling factor for the learning rate. A smaller learning rate may be useful to avoid overfitting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub learning_rate_multiplier: Option<Hyperparameter<f64>>,
+
+    /// The number of epochs to train the model for. An epoch refers to one full cycle through the training dataset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n_epochs: Option<Hyperparameter<u32>>,
+}
+
+/// A hyperparameter value, either chosen automatically by OpenAI or set explicitly.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Hyperparameter<T> {
+    /// Serialized as `"auto"`.
+    Auto,
+    Value(T),
+}
+
+impl<T: Serialize> Serialize for Hyperparameter<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Hyperparameter::Auto => serializer.serialize_str("auto"),
+            Hyperparameter::Value(v) => v.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Hyperparameter<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr<T> {
+            Auto(String),
+            Value(T),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Auto(s) if s == "auto" => Ok(Hyperparameter::Auto),
+            Repr::Auto(s) => Err(serde::de::Error::custom(format!(
+                "invalid hyperparameter value: {}",
+                s
+            ))),
+            Repr::Value(v) => Ok(Hyperparameter::Value(v)),
+        }
+    }
+}
+
+/// The status of a [FineTuningJob].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FineTuningJobStatus {
+    ValidatingFiles,
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+/// The `fine_tuning.job` object represents a fine-tuning job that has been created through the API.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"object\": \"fine_tuning.job\",
+/// #  \"id\": \"ftjob-abc123\",
+/// #  \"model\": \"gpt-4o-mini-2024-07-18\",
+/// #  \"created_at\": 1721764800,
+/// #  \"fine_tuned_model\": null,
+/// #  \"organization_id\": \"org-123\",
+/// #  \"result_files\": [],
+/// #  \"status\": \"queued\",
+/// #  \"validation_file\": null,
+/// #  \"training_file\": \"file-abc123\",
+/// #  \"hyperparameters\": {
+/// #    \"batch_size\": \"auto\",
+/// #    \"learning_rate_multiplier\": \"auto\",
+/// #    \"n_epochs\": 2
+/// #  },
+/// #  \"trained_tokens\": null,
+/// #  \"error\": null,
+/// #  \"seed\": 683058546
+/// # }";
+/// # let job = serde_json::from_str::<openai_rust2::fine_tuning::FineTuningJob>(json).unwrap();
+/// println!("{} is {:?}", job.id, job.status);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct FineTuningJob {
+    /// The object identifier, which can be referenced in the API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the fine-tuning job was created.
+    pub created_at: u64,
+    /// For fine-tuning jobs that have `failed`, this will contain more information on the cause of the failure.
+    pub error: Option<FineTuningJobError>,
+    /// The name of the fine-tuned model that is being created. The value will be `None` if the fine-tuning job is still running.
+    pub fine_tuned_model: Option<String>,
+    /// The Unix timestamp (in seconds) for when the fine-tuning job was finished. The value will be `None` if the fine-tuning job is still running.
+    pub finished_at: Option<u64>,
+    /// The hyperparameters used for the fine-tuning job.
+    pub hyperparameters: Hyperparameters,
+    /// The base model that is being fine-tuned.
+    pub model: String,
+    /// The organization that owns the fine-tuning job.
+    pub organization_id: String,
+    /// The compiled results file ID(s) for the fine-tuning job. You can retrieve the results with the [Files API](crate::Client::retrieve_file_content).
+    pub result_files: Vec<String>,
+    /// The current status of the fine-tuning job.
+    pub status: FineTuningJobStatus,
+    /// The total number of billable tokens processed by this fine-tuning job. The value will be `None` if the fine-tuning job is still running.
+    pub trained_tokens: Option<u64>,
+    /// The file ID used for training.
+    pub training_file: String,
+    /// The file ID used for validation.
+    pub validation_file: Option<String>,
+    /// The seed used for the fine-tuning job.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the fine-tuning job is estimated to finish.
+    #[serde(default)]
+    pub estimated_finish: Option<u64>,
+}
+
+/// The error of a failed [FineTuningJob].
+#[derive(Deserialize, Debug, Clone)]
+pub struct FineTuningJobError {
+    /// A machine-readable error code.
+    pub code: String,
+    /// A human-readable error message.
+    pub message: String,
+    /// The parameter that was invalid, usually `training_file` or `validation_file`.
+    pub param: Option<String>,
+}
+
+/// Fine-tuning job event object.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"object\": \"fine_tuning.job.event\",
+/// #  \"id\": \"ft-event-TjX0lMfOniCZX64t9PUQT5hn\",
+/// #  \"created_at\": 1689813489,
+/// #  \"level\": \"warn\",
+/// #  \"message\": \"Fine tuning process stopping due to job cancellation\",
+/// #  \"data\": null,
+/// #  \"type\": \"message\"
+/// # }";
+/// # let event = serde_json::from_str::<openai_rust2::fine_tuning::FineTuningJobEvent>(json).unwrap();
+/// println!("[{}] {}", event.level, event.message);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct FineTuningJobEvent {
+    /// The object identifier.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the fine-tuning job was created.
+    pub created_at: u64,
+    /// The log level of the event, `info`, `warn` or `error`.
+    pub level: String,
+    /// The message of the event.
+    pub message: String,
+    /// The data associated with the event.
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+    /// The type of event, `message` or `metrics`.
+    #[serde(rename = "type", default)]
+    pub event_type: Option<String>,
+}
+
+/// A model checkpoint created during a fine-tuning job, ready to be used for inference.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"object\": \"fine_tuning.job.checkpoint\",
+/// #  \"id\": \"ftckpt_zc4Q7MP6XxulcVzj4MZdwsAB\",
+/// #  \"created_at\": 1721764867,
+/// #  \"fine_tuned_model_checkpoint\": \"ft:gpt-4o-mini-2024-07-18:my-org:custom-suffix:96olL566:ckpt-step-2000\",
+/// #  \"metrics\": {
+/// #    \"full_valid_loss\": 0.134,
+/// #    \"full_valid_mean_token_accuracy\": 0.874
+/// #  },
+/// #  \"fine_tuning_job_id\": \"ftjob-abc123\",
+/// #  \"step_number\": 2000
+/// # }";
+/// # let checkpoint = serde_json::from_str::<openai_rust2::fine_tuning::FineTuningCheckpoint>(json).unwrap();
+/// println!("{} at step {}", checkpoint.fine_tuned_model_checkpoint, checkpoint.step_number);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct FineTuningCheckpoint {
+    /// The checkpoint identifier, which can be referenced in the API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the checkpoint was created.
+    pub created_at: u64,
+    /// The name of the fine-tuned checkpoint model that is created. Use it as the model name for inference.
+    pub fine_tuned_model_checkpoint: String,
+    /// The step number that the checkpoint was created at.
+    pub step_number: u64,
+    /// Metrics at the step number during the fine-tuning job.
+    pub metrics: CheckpointMetrics,
+    /// The name of the fine-tuning job that this checkpoint was created from.
+    pub fine_tuning_job_id: String,
+}
+
+/// Metrics of a [FineTuningCheckpoint].
+#[derive(Deserialize, Debug, Clone)]
+pub struct CheckpointMetrics {
+    pub step: Option<f64>,
+    pub train_loss: Option<f64>,
+    pub train_mean_token_accuracy: Option<f64>,
+    pub valid_loss: Option<f64>,
+    pub valid_mean_token_accuracy: Option<f64>,
+    pub full_valid_loss: Option<f64>,
+    pub full_valid_mean_token_accuracy: Option<f64>,
+}
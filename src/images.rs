@@ -1,7 +1,14 @@
 //! See <https://platform.openai.com/docs/api-reference/images>.
-//! Use with [Client::create_image](crate::Client::create_image).
+//! Use with [Client::create_image](crate::Client::create_image),
+//! [Client::create_image_stream](crate::Client::create_image_stream),
+//! [Client::create_image_edit](crate::Client::create_image_edit)
+//! or [Client::create_image_variation](crate::Client::create_image_variation).
 
+use futures_util::Stream;
+use reqwest::multipart::{Form, Part};
 use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::task::Poll;
 
 /// The format in which the generated images are returned.
 #[derive(Serialize, Debug, Clone)]
@@ -10,10 +17,37 @@ pub enum ResponseFormat {
     Base64JSON,
 }
 
+impl ResponseFormat {
+    /// The value of the format sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResponseFormat::Url => "url",
+            ResponseFormat::Base64JSON => "b64_json",
+        }
+    }
+}
+
+/// Request arguments for image generations.
+///
+/// See <https://platform.openai.com/docs/api-reference/images/create>.
+///
+/// ```
+/// let mut args = openai_rust2::images::ImageArguments::new("A cute baby sea otter");
+/// args.model = Some("gpt-image-1".to_owned());
+/// args.quality = Some("high".to_owned());
+/// args.background = Some("transparent".to_owned());
+/// args.output_format = Some("webp".to_owned());
+/// ```
+///
+/// The `quality`, `background`, `output_format`, `output_compression` and `moderation` fields
+/// are only supported by `gpt-image-1` and are not sent for `dall-e-*` models.
 #[derive(Serialize, Debug, Clone)]
 pub struct ImageArguments {
     /// A text description of the desired image(s). The maximum length is 1000 characters.
     pub prompt: String,
+    /// The model to use for image generation, `dall-e-2`, `dall-e-3` or `gpt-image-1`. Defaults to `dall-e-2`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
     /// The number of images to generate. Must be between 1 and 10. Defaults to 1.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub n: Option<u32>,
@@ -26,18 +60,217 @@ pub struct ImageArguments {
     /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse. [Learn more](https://platform.openai.com/docs/guides/safety-best-practices/end-user-ids).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
+    /// The quality of the image, `auto`, `high`, `medium` or `low` for `gpt-image-1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    /// The transparency of the background, `transparent`, `opaque` or `auto`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    /// The format of the generated images, `png`, `jpeg` or `webp`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<String>,
+    /// The compression level (0-100%) of the generated images, for the `webp` or `jpeg` output formats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_compression: Option<u8>,
+    /// The content-moderation level, `low` or `auto`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<String>,
+    /// The number of partial images, from 0 to 3, to send while streaming with [crate::Client::create_image_stream].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial_images: Option<u8>,
+
+    /// Whether to stream back the generation as server-sent events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stream: Option<bool>,
 }
 
 impl ImageArguments {
     pub fn new(prompt: impl AsRef<str>) -> Self {
         Self {
             prompt: prompt.as_ref().to_owned(),
+            model: None,
             n: None,
             response_format: None,
             size: None,
             user: None,
+            quality: None,
+            background: None,
+            output_format: None,
+            output_compression: None,
+            moderation: None,
+            partial_images: None,
+            stream: None,
+        }
+    }
+
+    /// Remove the `gpt-image-1` fields if a `dall-e-*` model is used.
+    pub(crate) fn without_unsupported_fields(mut self) -> Self {
+        let dall_e = self
+            .model
+            .as_deref()
+            .is_none_or(|model| model.starts_with("dall-e"));
+        if dall_e {
+            self.background = None;
+            self.output_format = None;
+            self.output_compression = None;
+            self.moderation = None;
+            self.partial_images = None;
+            // dall-e-3 has its own `standard` and `hd` qualities
+            if self
+                .quality
+                .as_deref()
+                .is_some_and(|q| q != "standard" && q != "hd")
+            {
+                self.quality = None;
+            }
+        }
+        self
+    }
+}
+
+/// Request arguments for image edits.
+///
+/// See <https://platform.openai.com/docs/api-reference/images/createEdit>.
+///
+/// ```
+/// let mut args = openai_rust2::images::ImageEditArguments::new(
+///     "otter.png",
+///     std::fs::read("otter.png").unwrap_or_default(),
+///     "A cute baby sea otter wearing a beret",
+/// );
+/// args.mask = Some(std::fs::read("mask.png").unwrap_or_default());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ImageEditArguments {
+    /// The image to edit. Must be a valid PNG file, less than 4MB, and square.
+    pub image: Vec<u8>,
+    /// The name of the image file.
+    pub filename: String,
+    /// A text description of the desired image(s). The maximum length is 1000 characters.
+    pub prompt: String,
+    /// An additional PNG image whose fully transparent areas indicate where `image` should be edited.
+    /// Must have the same dimensions as `image`.
+    pub mask: Option<Vec<u8>>,
+    /// The model to use for image generation.
+    pub model: Option<String>,
+    /// The number of images to generate. Must be between 1 and 10. Defaults to 1.
+    pub n: Option<u32>,
+    /// The size of the generated images. Must be one of `256x256`, `512x512`, or `1024x1024`. Defaults to `1024x1024`.
+    pub size: Option<String>,
+    /// The format in which the generated images are returned Defaults to `url`.
+    pub response_format: Option<ResponseFormat>,
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    pub user: Option<String>,
+}
+
+impl ImageEditArguments {
+    pub fn new(
+        filename: impl AsRef<str>,
+        image: Vec<u8>,
+        prompt: impl AsRef<str>,
+    ) -> ImageEditArguments {
+        ImageEditArguments {
+            image,
+            filename: filename.as_ref().to_owned(),
+            prompt: prompt.as_ref().to_owned(),
+            mask: None,
+            model: None,
+            n: None,
+            size: None,
+            response_format: None,
+            user: None,
+        }
+    }
+
+    /// Build the multipart form sent to the API.
+    pub(crate) fn into_form(self) -> Form {
+        let mut form = Form::new()
+            .part("image", Part::bytes(self.image).file_name(self.filename))
+            .text("prompt", self.prompt);
+        if let Some(mask) = self.mask {
+            form = form.part("mask", Part::bytes(mask).file_name("mask.png"));
+        }
+        if let Some(model) = self.model {
+            form = form.text("model", model);
+        }
+        if let Some(n) = self.n {
+            form = form.text("n", n.to_string());
+        }
+        if let Some(size) = self.size {
+            form = form.text("size", size);
+        }
+        if let Some(response_format) = self.response_format {
+            form = form.text("response_format", response_format.as_str());
+        }
+        if let Some(user) = self.user {
+            form = form.text("user", user);
+        }
+        form
+    }
+}
+
+/// Request arguments for image variations.
+///
+/// See <https://platform.openai.com/docs/api-reference/images/createVariation>.
+///
+/// ```
+/// let mut args = openai_rust2::images::ImageVariationArguments::new(
+///     "otter.png",
+///     std::fs::read("otter.png").unwrap_or_default(),
+/// );
+/// args.n = Some(2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ImageVariationArguments {
+    /// The image to use as the basis for the variation(s). Must be a valid PNG file, less than 4MB, and square.
+    pub image: Vec<u8>,
+    /// The name of the image file.
+    pub filename: String,
+    /// The model to use for image generation. Only `dall-e-2` is supported at this time.
+    pub model: Option<String>,
+    /// The number of images to generate. Must be between 1 and 10. Defaults to 1.
+    pub n: Option<u32>,
+    /// The size of the generated images. Must be one of `256x256`, `512x512`, or `1024x1024`. Defaults to `1024x1024`.
+    pub size: Option<String>,
+    /// The format in which the generated images are returned Defaults to `url`.
+    pub response_format: Option<ResponseFormat>,
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    pub user: Option<String>,
+}
+
+impl ImageVariationArguments {
+    pub fn new(filename: impl AsRef<str>, image: Vec<u8>) -> ImageVariationArguments {
+        ImageVariationArguments {
+            image,
+            filename: filename.as_ref().to_owned(),
+            model: None,
+            n: None,
+            size: None,
+            response_format: None,
+            user: None,
         }
     }
+
+    /// Build the multipart form sent to the API.
+    pub(crate) fn into_form(self) -> Form {
+        let mut form = Form::new().part("image", Part::bytes(self.image).file_name(self.filename));
+        if let Some(model) = self.model {
+            form = form.text("model", model);
+        }
+        if let Some(n) = self.n {
+            form = form.text("n", n.to_string());
+        }
+        if let Some(size) = self.size {
+            form = form.text("size", size);
+        }
+        if let Some(response_format) = self.response_format {
+            form = form.text("response_format", response_format.as_str());
+        }
+        if let Some(user) = self.user {
+            form = form.text("user", user);
+        }
+        form
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -45,7 +278,7 @@ pub(crate) enum ImageObject {
     #[serde(alias = "url")]
     Url(String),
     #[serde(alias = "b64_json")]
-    Base64JSON(String), 
+    Base64JSON(String),
 }
 
 #[derive(Deserialize, Debug)]
@@ -54,3 +287,119 @@ pub(crate) struct ImageResponse {
     created: u32,
     pub data: Vec<ImageObject>,
 }
+
+impl ImageResponse {
+    /// The URLs or base64 encoded images.
+    pub(crate) fn into_data(self) -> Vec<String> {
+        self.data
+            .into_iter()
+            .map(|o| match o {
+                ImageObject::Url(s) => s,
+                ImageObject::Base64JSON(s) => s,
+            })
+            .collect()
+    }
+}
+
+/// An event received while streaming an image generation.
+///
+/// ```
+/// # use futures_util::StreamExt;
+/// # async fn example(mut stream: openai_rust2::images::ImageEventStream) {
+/// use openai_rust2::images::ImageStreamEvent;
+/// while let Some(event) = stream.next().await {
+///     match event.unwrap() {
+///         ImageStreamEvent::PartialImage(image) => println!("frame {:?}", image.partial_image_index),
+///         ImageStreamEvent::Completed(image) => println!("{} bytes of base64", image.b64_json.len()),
+///         _ => {}
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub enum ImageStreamEvent {
+    /// `image_generation.partial_image`
+    PartialImage(StreamedImage),
+    /// `image_generation.completed`
+    Completed(StreamedImage),
+    /// An event not known to this crate.
+    Unknown { event: String, data: String },
+}
+
+impl ImageStreamEvent {
+    fn parse(event: &str, data: &str) -> serde_json::Result<ImageStreamEvent> {
+        use ImageStreamEvent::*;
+        Ok(match event {
+            "image_generation.partial_image" | "image_edit.partial_image" => {
+                PartialImage(serde_json::from_str(data)?)
+            }
+            "image_generation.completed" | "image_edit.completed" => {
+                Completed(serde_json::from_str(data)?)
+            }
+            _ => Unknown {
+                event: event.to_owned(),
+                data: data.to_owned(),
+            },
+        })
+    }
+}
+
+/// A partial or final image received while streaming.
+#[derive(Deserialize, Debug, Clone)]
+pub struct StreamedImage {
+    /// The base64 encoded image.
+    pub b64_json: String,
+    pub created_at: u64,
+    pub size: Option<String>,
+    pub quality: Option<String>,
+    pub background: Option<String>,
+    pub output_format: Option<String>,
+    /// The index of a partial image, starting at 0. `None` for the final image.
+    pub partial_image_index: Option<u32>,
+}
+
+/// The type of an event, for servers that don't send the `event:` field.
+#[derive(Deserialize)]
+struct EventType {
+    #[serde(rename = "type")]
+    event_type: String,
+}
+
+/// A stream of [ImageStreamEvent]s returned by [crate::Client::create_image_stream].
+pub struct ImageEventStream {
+    event_stream: crate::sse::EventStream,
+}
+
+impl ImageEventStream {
+    pub(crate) fn new(stream: crate::sse::ByteStream) -> Self {
+        Self {
+            event_stream: crate::sse::EventStream::new(stream),
+        }
+    }
+}
+
+impl Stream for ImageEventStream {
+    type Item = anyhow::Result<ImageStreamEvent>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        match Pin::new(&mut self.event_stream).poll_next(cx) {
+            Poll::Ready(Some(Ok(event))) => {
+                let name = match event.event {
+                    Some(name) => name,
+                    None => serde_json::from_str::<EventType>(&event.data)
+                        .map(|t| t.event_type)
+                        .unwrap_or_default(),
+                };
+                Poll::Ready(Some(
+                    ImageStreamEvent::parse(&name, &event.data).map_err(|e| anyhow::anyhow!(e)),
+                ))
+            }
+            Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -2,7 +2,6 @@
 //#![feature(str_split_remainder)]
 use anyhow::{anyhow, Result};
 use lazy_static::lazy_static;
-use reqwest;
 
 pub extern crate futures_util;
 
@@ -15,15 +14,35 @@ lazy_static! {
 pub struct Client {
     req_client: reqwest::Client,
     key: String,
+    admin_key: Option<String>,
+    user: Option<String>,
+    stream_idle_timeout: Option<std::time::Duration>,
+    stream_retry: Option<sse::StreamRetryPolicy>,
     base_url: reqwest::Url,
 }
 
+pub mod assistants;
+pub mod audio;
+pub mod audit_logs;
+pub mod batch;
 pub mod chat;
 pub mod completions;
 pub mod edits;
 pub mod embeddings;
+pub mod files;
+pub mod fine_tuning;
 pub mod images;
 pub mod models;
+pub mod moderations;
+pub mod organization;
+pub mod pagination;
+#[cfg(feature = "realtime")]
+pub mod realtime;
+pub mod responses;
+pub mod sse;
+pub mod uploads;
+pub mod usage;
+pub mod vector_stores;
 
 impl Client {
     /// Create a new client.
@@ -33,6 +52,10 @@ impl Client {
         Client {
             req_client,
             key: api_key.to_owned(),
+            admin_key: None,
+            user: None,
+            stream_idle_timeout: None,
+            stream_retry: None,
             base_url: DEFAULT_BASE_URL.clone(),
         }
     }
@@ -42,6 +65,10 @@ impl Client {
         Client {
             req_client,
             key: api_key.to_owned(),
+            admin_key: None,
+            user: None,
+            stream_idle_timeout: None,
+            stream_retry: None,
             base_url: DEFAULT_BASE_URL.clone(),
         }
     }
@@ -53,6 +80,10 @@ impl Client {
         Client {
             req_client,
             key: api_key.to_owned(),
+            admin_key: None,
+            user: None,
+            stream_idle_timeout: None,
+            stream_retry: None,
             base_url,
         }
     }
@@ -65,10 +96,83 @@ impl Client {
         Client {
             req_client,
             key: api_key.to_owned(),
+            admin_key: None,
+            user: None,
+            stream_idle_timeout: None,
+            stream_retry: None,
             base_url: reqwest::Url::parse(base_url).unwrap(),
         }
     }
 
+    /// Use a separate [admin API key](https://platform.openai.com/settings/organization/admin-keys)
+    /// for the organization administration endpoints, such as [Client::list_usage] or [Client::list_admin_api_keys].
+    /// Without it, the regular API key is used for them as well.
+    ///
+    /// ```
+    /// let client = openai_rust2::Client::new("sk-...").with_admin_key("sk-admin-...");
+    /// ```
+    pub fn with_admin_key(mut self, admin_key: &str) -> Client {
+        self.admin_key = Some(admin_key.to_owned());
+        self
+    }
+
+    /// Attribute the requests to an end-user, to help OpenAI monitor and detect abuse.
+    ///
+    /// The identifier is sent as the `user` of the chat, completion, embeddings, image and response requests
+    /// whose arguments don't set their own.
+    ///
+    /// ```
+    /// let client = openai_rust2::Client::new("sk-...").with_user("user-1234");
+    /// ```
+    pub fn with_user(mut self, user: &str) -> Client {
+        self.user = Some(user.to_owned());
+        self
+    }
+
+    /// Fail the streams that receive no data for longer than `timeout`, instead of waiting forever on a stalled connection.
+    ///
+    /// The stream then yields a [sse::IdleTimeoutError] and ends.
+    ///
+    /// ```
+    /// let client = openai_rust2::Client::new("sk-...")
+    ///     .with_stream_idle_timeout(std::time::Duration::from_secs(30));
+    /// ```
+    pub fn with_stream_idle_timeout(mut self, timeout: std::time::Duration) -> Client {
+        self.stream_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Send a chat stream request again when its connection drops mid-generation,
+    /// for example because of a network error or the [idle timeout](Client::with_stream_idle_timeout).
+    ///
+    /// The content already received is skipped from the new generation on a best-effort basis,
+    /// it only lines up if the model repeats itself, which a [seed](chat::ChatArguments::seed) makes more likely.
+    /// This applies to [Client::create_chat_stream].
+    ///
+    /// ```
+    /// let client = openai_rust2::Client::new("sk-...")
+    ///     .with_stream_retry(openai_rust2::sse::StreamRetryPolicy::default());
+    /// ```
+    pub fn with_stream_retry(mut self, policy: sse::StreamRetryPolicy) -> Client {
+        self.stream_retry = Some(policy);
+        self
+    }
+
+    /// The body of a streaming response, with the idle timeout set by [Client::with_stream_idle_timeout].
+    fn byte_stream(&self, res: reqwest::Response) -> sse::ByteStream {
+        sse::byte_stream(res, self.stream_idle_timeout)
+    }
+
+    /// The `user` of a request, falling back to the one set with [Client::with_user].
+    fn default_user(&self, user: Option<String>) -> Option<String> {
+        user.or_else(|| self.user.clone())
+    }
+
+    /// The key used by the organization administration endpoints.
+    fn admin_key(&self) -> &str {
+        self.admin_key.as_deref().unwrap_or(&self.key)
+    }
+
     /// List and describe the various models available in the API. You can refer to the [Models](https://platform.openai.com/docs/models) documentation to understand what models are available and the differences between them.
     ///
     /// ```
@@ -112,10 +216,7 @@ impl Client {
     /// # let api_key = "";
     /// let client = openai_rust::Client::new(api_key);
     /// let args = openai_rust::chat::ChatArguments::new("gpt-3.5-turbo", vec![
-    ///    openai_rust::chat::Message {
-    ///        role: "user".to_owned(),
-    ///        content: "Hello GPT!".to_owned(),
-    ///    }
+    ///    openai_rust::chat::Message::user("Hello GPT!")
     /// ]);
     /// let res = client.create_chat(args, None).await.unwrap();
     /// println!("{}", res.choices[0].message.content);
@@ -129,6 +230,9 @@ impl Client {
         let mut url = self.base_url.clone();
         url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/chat/completions")));
 
+        let mut args = args.with_token_limit_for_model();
+        args.user = self.default_user(args.user);
+
         let res = self
             .req_client
             .post(url)
@@ -144,6 +248,122 @@ impl Client {
         }
     }
 
+    /// Like [Client::create_chat] but runs the tools requested by the model, with the handlers of `tools`,
+    /// and sends their outputs back until the model answers without calling tools.
+    ///
+    /// The tools of the registry are added to the [chat::ChatArguments::tools] of `args`.
+    /// Fails with a [chat::tools::MaxIterationsError] if the model still calls tools after `max_iterations` completions.
+    ///
+    /// ```no_run
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # use openai_rust2 as openai_rust;
+    /// # let client = openai_rust::Client::new("");
+    /// use openai_rust::chat::{tools::ToolRegistry, ChatArguments, ChatTool, Message};
+    /// let tools = ToolRegistry::new().register(
+    ///     ChatTool::function(
+    ///         "get_weather",
+    ///         "Get the current weather in a given location",
+    ///         serde_json::json!({
+    ///             "type": "object",
+    ///             "properties": {"location": {"type": "string"}},
+    ///             "required": ["location"]
+    ///         }),
+    ///     ),
+    ///     |_arguments| async { Ok("Sunny, 22°C".to_owned()) },
+    /// );
+    /// let args = ChatArguments::new("gpt-4o-mini", vec![
+    ///     Message::user("What is the weather like in Boston?")
+    /// ]);
+    /// let run = client.create_chat_with_tools(args, &tools, 5, None).await.unwrap();
+    /// println!("{}", run.message().content);
+    /// # })
+    /// ```
+    pub async fn create_chat_with_tools(
+        &self,
+        args: chat::ChatArguments,
+        tools: &chat::tools::ToolRegistry,
+        max_iterations: usize,
+        opt_url_path: Option<String>,
+    ) -> Result<chat::tools::ToolRun> {
+        let mut args = args;
+        let definitions = args.tools.get_or_insert_with(Vec::new);
+        for tool in tools.definitions() {
+            if !definitions.iter().any(|d| d.name() == tool.name()) {
+                definitions.push(tool.clone());
+            }
+        }
+
+        let mut last_completion = None;
+        for _ in 0..max_iterations {
+            let completion = self.create_chat(args.clone(), opt_url_path.clone()).await?;
+            let message = match completion.choices.first() {
+                Some(choice) => choice.message.clone(),
+                None => return Err(anyhow!("the response has no choices")),
+            };
+            let calls = message.tool_calls.clone().unwrap_or_default();
+            args.messages.push(message);
+            if calls.is_empty() {
+                return Ok(chat::tools::ToolRun {
+                    messages: args.messages,
+                    completion,
+                });
+            }
+
+            let outputs = futures_util::future::join_all(calls.iter().map(|call| tools.call(call)));
+            args.messages.extend(outputs.await);
+            last_completion = Some(completion);
+        }
+        Err(chat::tools::MaxIterationsError {
+            max_iterations,
+            messages: args.messages,
+            completion: last_completion,
+        }
+        .into())
+    }
+
+    /// Like [Client::create_chat] but with [Structured Outputs](https://platform.openai.com/docs/guides/structured-outputs),
+    /// deserializing the answer of the model into `T`.
+    ///
+    /// The response format of `args` is replaced by the strict JSON schema of `T`, see [chat::JsonSchemaFormat::from_type].
+    /// If the model refuses or produces JSON that doesn't match `T`, the error is a [chat::StructuredOutputError].
+    ///
+    /// Requires the `schemars` feature.
+    ///
+    /// ```no_run
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # use openai_rust2 as openai_rust;
+    /// # let client = openai_rust::Client::new("");
+    /// #[derive(serde::Deserialize, schemars::JsonSchema)]
+    /// struct Weather {
+    ///     temperature: f32,
+    ///     summary: String,
+    /// }
+    /// let args = openai_rust::chat::ChatArguments::new("gpt-4o-mini", vec![
+    ///     openai_rust::chat::Message::user("What is the weather like in Boston?")
+    /// ]);
+    /// let weather: Weather = client.create_chat_structured(args, None).await.unwrap();
+    /// # })
+    /// ```
+    #[cfg(feature = "schemars")]
+    pub async fn create_chat_structured<T>(
+        &self,
+        args: chat::ChatArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned + schemars::JsonSchema,
+    {
+        let mut args = args;
+        args.response_format = Some(chat::ResponseFormat::JsonSchema {
+            json_schema: chat::JsonSchemaFormat::from_type::<T>(),
+        });
+
+        let completion = self.create_chat(args, opt_url_path).await?;
+        Ok(completion.parse_structured()?)
+    }
+
     /// Like [Client::create_chat] but with streaming.
     ///
     /// See <https://platform.openai.com/docs/api-reference/chat>.
@@ -157,10 +377,7 @@ impl Client {
     /// # use std::io::Write;
     /// # let client = openai_rust::Client::new("");
     /// # let args = openai_rust::chat::ChatArguments::new("gpt-3.5-turbo", vec![
-    /// #    openai_rust::chat::Message {
-    /// #        role: "user".to_owned(),
-    /// #        content: "Hello GPT!".to_owned(),
-    /// #    }
+    /// #    openai_rust::chat::Message::user("Hello GPT!")
     /// # ]);
     /// use openai_rust::futures_util::StreamExt;
     /// let mut res = client.create_chat_stream(args, None).await.unwrap();
@@ -180,21 +397,74 @@ impl Client {
         url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/chat/completions")));
 
         // Enable streaming
-        let mut args = args;
+        let mut args = args.with_token_limit_for_model();
         args.stream = Some(true);
+        args.user = self.default_user(args.user);
+
+        let request = self.req_client.post(url).bearer_auth(&self.key).json(&args);
+        let reconnect = self.stream_retry.and_then(|policy| {
+            Some(chat::stream::Reconnect {
+                request: request.try_clone()?,
+                policy,
+                idle_timeout: self.stream_idle_timeout,
+                choices: args.n.unwrap_or(1),
+            })
+        });
+        let res = request.send().await?;
+
+        if res.status() == 200 {
+            Ok(chat::stream::ChatCompletionChunkStream::new(
+                self.byte_stream(res),
+                reconnect,
+            ))
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// POSTs a JSON body to any streaming endpoint and returns the raw [sse::Event]s of the response.
+    ///
+    /// This allows to use streaming endpoints this crate has no types for yet.
+    /// The body has to enable streaming itself, usually with `"stream": true`.
+    ///
+    /// ```no_run
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # use openai_rust2 as openai_rust;
+    /// # let client = openai_rust::Client::new("");
+    /// use openai_rust::futures_util::StreamExt;
+    /// let body = serde_json::json!({
+    ///     "model": "gpt-4o-mini",
+    ///     "messages": [{"role": "user", "content": "Hello GPT!"}],
+    ///     "stream": true,
+    /// });
+    /// let mut events = client
+    ///     .create_event_stream("/v1/chat/completions", &body)
+    ///     .await
+    ///     .unwrap();
+    /// while let Some(event) = events.next().await {
+    ///     println!("{}", event.unwrap().data);
+    /// }
+    /// # })
+    /// ```
+    pub async fn create_event_stream<T: serde::Serialize + ?Sized>(
+        &self,
+        path: &str,
+        body: &T,
+    ) -> Result<sse::EventStream> {
+        let mut url = self.base_url.clone();
+        url.set_path(path);
 
         let res = self
             .req_client
             .post(url)
             .bearer_auth(&self.key)
-            .json(&args)
+            .json(body)
             .send()
             .await?;
 
         if res.status() == 200 {
-            Ok(chat::stream::ChatCompletionChunkStream::new(Box::pin(
-                res.bytes_stream(),
-            )))
+            Ok(sse::EventStream::new(self.byte_stream(res)))
         } else {
             Err(anyhow!(res.text().await?))
         }
@@ -223,6 +493,115 @@ impl Client {
         let mut url = self.base_url.clone();
         url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/completions")));
 
+        let mut args = args;
+        args.user = self.default_user(args.user);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Like [Client::create_completion] but with streaming.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/completions>.
+    ///
+    /// This method will return a stream of [completions::stream::CompletionChunk]s. Use with [futures_util::StreamExt::next].
+    ///
+    /// ```no_run
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # use openai_rust2 as openai_rust;
+    /// # use std::io::Write;
+    /// # let client = openai_rust::Client::new("");
+    /// let args = openai_rust::completions::CompletionArguments::new("gpt-3.5-turbo-instruct", "The quick brown fox".to_owned());
+    /// use openai_rust::futures_util::StreamExt;
+    /// let mut res = client.create_completion_stream(args, None).await.unwrap();
+    /// while let Some(chunk) = res.next().await {
+    ///     print!("{}", chunk.unwrap());
+    ///     std::io::stdout().flush().unwrap();
+    /// }
+    /// # })
+    /// ```
+    pub async fn create_completion_stream(
+        &self,
+        args: completions::CompletionArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<completions::stream::CompletionChunkStream> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/completions")));
+
+        // Enable streaming
+        let mut args = args;
+        args.stream = Some(true);
+        args.user = self.default_user(args.user);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(completions::stream::CompletionChunkStream::new(
+                self.byte_stream(res),
+            ))
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Creates a new edit for the provided input and instruction.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/edits>.
+    ///
+    /// The `/v1/edits` endpoint is deprecated. Unless [edits::EditArguments::backend] is set to
+    /// [edits::EditBackend::Edits], the edit is executed through [Client::create_chat] and the
+    /// result converted into an [edits::EditResponse]. `opt_url_path` only overrides the path of
+    /// `/v1/edits`, the chat backend always uses `/v1/chat/completions`.
+    ///
+    /// ```no_run
+    /// # #![allow(deprecated)]
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = openai_rust::edits::EditArguments::new(
+    ///     "text-davinci-edit-001",
+    ///     "The quick brown fox".to_owned(),
+    ///     "Complete this sentence.".to_owned(),
+    /// );
+    /// println!("{}", c.create_edit(args, None).await.unwrap());
+    /// # })
+    /// ```
+    #[allow(deprecated)]
+    pub async fn create_edit(
+        &self,
+        args: edits::EditArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<edits::EditResponse> {
+        if let edits::EditBackend::Chat(model) = args.backend.clone() {
+            let chat = self
+                .create_chat(args.into_chat_arguments(&model), None)
+                .await?;
+            return Ok(chat.into());
+        }
+
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/edits")));
+
         let res = self
             .req_client
             .post(url)
@@ -261,6 +640,9 @@ impl Client {
         let mut url = self.base_url.clone();
         url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/embeddings")));
 
+        let mut args = args;
+        args.user = self.default_user(args.user);
+
         let res = self
             .req_client
             .post(url)
@@ -285,6 +667,125 @@ impl Client {
         let mut url = self.base_url.clone();
         url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/images/generations")));
 
+        let mut args = args.without_unsupported_fields();
+        args.user = self.default_user(args.user);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json::<images::ImageResponse>().await?.into_data())
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Transcribes audio into the input language.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/audio/createTranscription>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let file = std::fs::read("speech.mp3").unwrap();
+    /// let args = openai_rust::audio::TranscriptionArguments::new("whisper-1", "speech.mp3", file);
+    /// println!("{}", c.create_transcription(args, None).await.unwrap());
+    /// # })
+    /// ```
+    pub async fn create_transcription(
+        &self,
+        args: audio::TranscriptionArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<audio::TranscriptionResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/audio/transcriptions")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .multipart(args.into_form())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Translates audio into English.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/audio/createTranslation>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let file = std::fs::read("german.m4a").unwrap();
+    /// let args = openai_rust::audio::TranslationArguments::new("whisper-1", "german.m4a", file);
+    /// println!("{}", c.create_translation(args, None).await.unwrap());
+    /// # })
+    /// ```
+    pub async fn create_translation(
+        &self,
+        args: audio::TranslationArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<audio::TranslationResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/audio/translations")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .multipart(args.into_form())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Generates audio from the input text.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/audio/createSpeech>.
+    ///
+    /// This returns the complete audio file. To start playing audio before it is fully generated, use [Client::create_speech_stream].
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = openai_rust::audio::SpeechArguments::new("tts-1", "Hello world!", "alloy");
+    /// let audio = c.create_speech(args, None).await.unwrap();
+    /// std::fs::write("speech.mp3", audio).unwrap();
+    /// # })
+    /// ```
+    pub async fn create_speech(
+        &self,
+        args: audio::SpeechArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<bytes::Bytes> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/audio/speech")));
+
         let res = self
             .req_client
             .post(url)
@@ -294,18 +795,3107 @@ impl Client {
             .await?;
 
         if res.status() == 200 {
-            Ok(res
-                .json::<images::ImageResponse>()
-                .await?
-                .data
-                .iter()
-                .map(|o| match o {
-                    images::ImageObject::Url(s) => s.to_string(),
-                    images::ImageObject::Base64JSON(s) => s.to_string(),
-                })
-                .collect())
+            Ok(res.bytes().await?)
         } else {
             Err(anyhow!(res.text().await?))
         }
     }
+
+    /// Like [Client::create_speech] but with streaming.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/audio/createSpeech>.
+    ///
+    /// This method will return a stream of audio [bytes::Bytes]. Use with [futures_util::StreamExt::next].
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # use std::io::Write;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// use openai_rust::futures_util::StreamExt;
+    /// let args = openai_rust::audio::SpeechArguments::new("tts-1", "Hello world!", "alloy");
+    /// let mut res = c.create_speech_stream(args, None).await.unwrap();
+    /// let mut file = std::fs::File::create("speech.mp3").unwrap();
+    /// while let Some(chunk) = res.next().await {
+    ///     file.write_all(&chunk.unwrap()).unwrap();
+    /// }
+    /// # })
+    /// ```
+    pub async fn create_speech_stream(
+        &self,
+        args: audio::SpeechArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<audio::SpeechStream> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/audio/speech")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(audio::SpeechStream::new(self.byte_stream(res)))
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Classifies if text is potentially harmful.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/moderations>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = openai_rust::moderations::ModerationArguments::new("I want to kill them.".to_owned());
+    /// println!("{}", c.create_moderation(args, None).await.unwrap().results[0].flagged);
+    /// # })
+    /// ```
+    pub async fn create_moderation(
+        &self,
+        args: moderations::ModerationArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<moderations::ModerationResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/moderations")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Upload a file that can be used across various endpoints.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/files/create>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// use openai_rust::files::{FilePurpose, UploadFileArguments};
+    /// let c = openai_rust::Client::new(api_key);
+    /// let file = std::fs::read("mydata.jsonl").unwrap();
+    /// let args = UploadFileArguments::new("mydata.jsonl", file, FilePurpose::FineTune);
+    /// println!("{}", c.upload_file(args, None).await.unwrap().id);
+    /// # })
+    /// ```
+    pub async fn upload_file(
+        &self,
+        args: files::UploadFileArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<files::FileObject> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/files")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .multipart(args.into_form())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of files.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/files/list>.
+    pub async fn list_files(&self, opt_url_path: Option<String>) -> Result<Vec<files::FileObject>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/files")));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json::<files::ListFilesResponse>().await?.data)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns information about a specific file.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/files/retrieve>.
+    pub async fn retrieve_file(
+        &self,
+        file_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<files::FileObject> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/files/{}", file_id)));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Delete a file.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/files/delete>.
+    pub async fn delete_file(
+        &self,
+        file_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<files::DeleteFileResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/files/{}", file_id)));
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns the contents of the specified file.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/files/retrieve-contents>.
+    ///
+    /// The whole file is loaded into memory. For large files, such as batch outputs, use [Client::retrieve_file_content_into].
+    pub async fn retrieve_file_content(
+        &self,
+        file_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<bytes::Bytes> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/files/{}/content", file_id)));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.bytes().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Like [Client::retrieve_file_content] but streams the contents into `writer` as they arrive.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// let mut file = tokio::fs::File::create("output.jsonl").await.unwrap();
+    /// c.retrieve_file_content_into("file-abc123", &mut file, None).await.unwrap();
+    /// # })
+    /// ```
+    pub async fn retrieve_file_content_into<W>(
+        &self,
+        file_id: &str,
+        writer: &mut W,
+        opt_url_path: Option<String>,
+    ) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use futures_util::StreamExt;
+        use tokio::io::AsyncWriteExt;
+
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/files/{}/content", file_id)));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            let mut written = 0;
+            let mut stream = res.bytes_stream();
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk?;
+                writer.write_all(&chunk).await?;
+                written += chunk.len() as u64;
+            }
+            writer.flush().await?;
+            Ok(written)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Creates a fine-tuning job which begins the process of creating a new model from a given dataset.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/fine-tuning/create>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = openai_rust::fine_tuning::FineTuningJobArguments::new("gpt-4o-mini-2024-07-18", "file-abc123");
+    /// println!("{}", c.create_fine_tuning_job(args, None).await.unwrap().id);
+    /// # })
+    /// ```
+    pub async fn create_fine_tuning_job(
+        &self,
+        args: fine_tuning::FineTuningJobArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<fine_tuning::FineTuningJob> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/fine_tuning/jobs")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// List your organization's fine-tuning jobs.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/fine-tuning/list>.
+    ///
+    /// Supports the `after` and `limit` [pagination](pagination::ListArguments) arguments.
+    pub async fn list_fine_tuning_jobs(
+        &self,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<fine_tuning::FineTuningJob>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/fine_tuning/jobs")));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Get info about a fine-tuning job.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/fine-tuning/retrieve>.
+    pub async fn retrieve_fine_tuning_job(
+        &self,
+        fine_tuning_job_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<fine_tuning::FineTuningJob> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/fine_tuning/jobs/{}", fine_tuning_job_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Get status updates for a fine-tuning job.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/fine-tuning/list-events>.
+    ///
+    /// Supports the `after` and `limit` [pagination](pagination::ListArguments) arguments.
+    pub async fn list_fine_tuning_events(
+        &self,
+        fine_tuning_job_id: &str,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<fine_tuning::FineTuningJobEvent>> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/fine_tuning/jobs/{}/events", fine_tuning_job_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Immediately cancel a fine-tuning job.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/fine-tuning/cancel>.
+    pub async fn cancel_fine_tuning_job(
+        &self,
+        fine_tuning_job_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<fine_tuning::FineTuningJob> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/fine_tuning/jobs/{}/cancel", fine_tuning_job_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// List checkpoints for a fine-tuning job.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/fine-tuning/list-checkpoints>.
+    ///
+    /// Supports the `after` and `limit` [pagination](pagination::ListArguments) arguments.
+    pub async fn list_fine_tuning_checkpoints(
+        &self,
+        fine_tuning_job_id: &str,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<fine_tuning::FineTuningCheckpoint>> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| {
+                format!("/v1/fine_tuning/jobs/{}/checkpoints", fine_tuning_job_id)
+            }),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Create an assistant with a model and instructions.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/assistants/createAssistant>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let mut args = openai_rust::assistants::AssistantArguments::new("gpt-4o");
+    /// args.instructions = Some("You are a personal math tutor.".to_owned());
+    /// println!("{}", c.create_assistant(args, None).await.unwrap().id);
+    /// # })
+    /// ```
+    pub async fn create_assistant(
+        &self,
+        args: assistants::AssistantArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::Assistant> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/assistants")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of assistants.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/assistants/listAssistants>.
+    pub async fn list_assistants(
+        &self,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<assistants::Assistant>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/assistants")));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves an assistant.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/assistants/getAssistant>.
+    pub async fn retrieve_assistant(
+        &self,
+        assistant_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::Assistant> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/assistants/{}", assistant_id)));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Modifies an assistant.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/assistants/modifyAssistant>.
+    pub async fn modify_assistant(
+        &self,
+        assistant_id: &str,
+        args: assistants::ModifyAssistantArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::Assistant> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/assistants/{}", assistant_id)));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Delete an assistant.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/assistants/deleteAssistant>.
+    pub async fn delete_assistant(
+        &self,
+        assistant_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::DeleteAssistantResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/assistants/{}", assistant_id)));
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Create a thread.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/threads/createThread>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// use openai_rust::assistants::{messages::MessageArguments, threads::ThreadArguments};
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = ThreadArguments {
+    ///     messages: vec![MessageArguments::new("user", "Hello, what is AI?")],
+    ///     ..Default::default()
+    /// };
+    /// println!("{}", c.create_thread(args, None).await.unwrap().id);
+    /// # })
+    /// ```
+    pub async fn create_thread(
+        &self,
+        args: assistants::threads::ThreadArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::threads::Thread> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/threads")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a thread.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/threads/getThread>.
+    pub async fn retrieve_thread(
+        &self,
+        thread_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::threads::Thread> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/threads/{}", thread_id)));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Modifies a thread.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/threads/modifyThread>.
+    pub async fn modify_thread(
+        &self,
+        thread_id: &str,
+        args: assistants::threads::ModifyThreadArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::threads::Thread> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/threads/{}", thread_id)));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Delete a thread.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/threads/deleteThread>.
+    pub async fn delete_thread(
+        &self,
+        thread_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::threads::DeleteThreadResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/threads/{}", thread_id)));
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Create a message in a thread.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/messages/createMessage>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// use openai_rust::assistants::messages::MessageArguments;
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = MessageArguments::new("user", "How does AI work? Explain it in simple terms.");
+    /// println!("{}", c.create_message("thread_abc123", args, None).await.unwrap().id);
+    /// # })
+    /// ```
+    pub async fn create_message(
+        &self,
+        thread_id: &str,
+        args: assistants::messages::MessageArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::messages::Message> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/threads/{}/messages", thread_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of messages for a given thread.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/messages/listMessages>.
+    pub async fn list_messages(
+        &self,
+        thread_id: &str,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<assistants::messages::Message>> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/threads/{}/messages", thread_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieve a message.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/messages/getMessage>.
+    pub async fn retrieve_message(
+        &self,
+        thread_id: &str,
+        message_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::messages::Message> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/threads/{}/messages/{}", thread_id, message_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Modifies a message.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/messages/modifyMessage>.
+    pub async fn modify_message(
+        &self,
+        thread_id: &str,
+        message_id: &str,
+        args: assistants::messages::ModifyMessageArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::messages::Message> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/threads/{}/messages/{}", thread_id, message_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Create a run.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/runs/createRun>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// use std::time::Duration;
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = openai_rust::assistants::runs::RunArguments::new("asst_abc123");
+    /// let run = c.create_run("thread_abc123", args, None).await.unwrap();
+    /// let run = c
+    ///     .wait_for_run(&run.thread_id, &run.id, Duration::from_secs(1), Duration::from_secs(60))
+    ///     .await
+    ///     .unwrap();
+    /// println!("{:?}", run.status);
+    /// # })
+    /// ```
+    pub async fn create_run(
+        &self,
+        thread_id: &str,
+        args: assistants::runs::RunArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::runs::Run> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/threads/{}/runs", thread_id)));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of runs belonging to a thread.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/runs/listRuns>.
+    pub async fn list_runs(
+        &self,
+        thread_id: &str,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<assistants::runs::Run>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/threads/{}/runs", thread_id)));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a run.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/runs/getRun>.
+    pub async fn retrieve_run(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::runs::Run> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/threads/{}/runs/{}", thread_id, run_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Cancels a run that is `in_progress`.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/runs/cancelRun>.
+    pub async fn cancel_run(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::runs::Run> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/threads/{}/runs/{}/cancel", thread_id, run_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Polls [Client::retrieve_run] every `poll_interval` until the run reaches a
+    /// [terminal status](assistants::runs::RunStatus::is_terminal) or requires action, and returns it.
+    ///
+    /// Fails if the run is still going after `timeout`.
+    pub async fn wait_for_run(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        poll_interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> Result<assistants::runs::Run> {
+        let deadline = std::time::Instant::now() + timeout;
+        loop {
+            let run = self.retrieve_run(thread_id, run_id, None).await?;
+            if run.status.is_terminal() || run.status == assistants::runs::RunStatus::RequiresAction
+            {
+                return Ok(run);
+            }
+            if std::time::Instant::now() + poll_interval > deadline {
+                return Err(anyhow!(
+                    "timed out waiting for run {} (status: {:?})",
+                    run_id,
+                    run.status
+                ));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Returns a list of run steps belonging to a run.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/run-steps/listRunSteps>.
+    pub async fn list_run_steps(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<assistants::run_steps::RunStep>> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/threads/{}/runs/{}/steps", thread_id, run_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a run step.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/run-steps/getRunStep>.
+    pub async fn retrieve_run_step(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        step_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::run_steps::RunStep> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| {
+            format!(
+                "/v1/threads/{}/runs/{}/steps/{}",
+                thread_id, run_id, step_id
+            )
+        }));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Like [Client::create_run] but with streaming.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/runs/createRun>.
+    ///
+    /// This method will return a stream of [assistants::stream::AssistantStreamEvent]s. Use with [futures_util::StreamExt::next].
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # use std::io::Write;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// use openai_rust::assistants::stream::AssistantStreamEvent;
+    /// use openai_rust::futures_util::StreamExt;
+    /// let args = openai_rust::assistants::runs::RunArguments::new("asst_abc123");
+    /// let mut res = c.create_run_stream("thread_abc123", args, None).await.unwrap();
+    /// while let Some(event) = res.next().await {
+    ///     if let AssistantStreamEvent::MessageDelta(delta) = event.unwrap() {
+    ///         print!("{}", delta);
+    ///         std::io::stdout().flush().unwrap();
+    ///     }
+    /// }
+    /// # })
+    /// ```
+    pub async fn create_run_stream(
+        &self,
+        thread_id: &str,
+        args: assistants::runs::RunArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::stream::AssistantEventStream> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/threads/{}/runs", thread_id)));
+
+        // Enable streaming
+        let mut args = args;
+        args.stream = Some(true);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(assistants::stream::AssistantEventStream::new(
+                self.byte_stream(res),
+            ))
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// When a run has the status `requires_action` and `required_action.type` is `submit_tool_outputs`,
+    /// this endpoint can be used to submit the outputs from the tool calls once they're all completed.
+    /// All outputs must be submitted in a single request.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/runs/submitToolOutputs>.
+    pub async fn submit_tool_outputs(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        args: assistants::runs::SubmitToolOutputsArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::runs::Run> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| {
+            format!(
+                "/v1/threads/{}/runs/{}/submit_tool_outputs",
+                thread_id, run_id
+            )
+        }));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Like [Client::submit_tool_outputs] but with streaming.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/runs/submitToolOutputs>.
+    ///
+    /// This method will return a stream of [assistants::stream::AssistantStreamEvent]s for the continued run.
+    pub async fn submit_tool_outputs_stream(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        args: assistants::runs::SubmitToolOutputsArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::stream::AssistantEventStream> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| {
+            format!(
+                "/v1/threads/{}/runs/{}/submit_tool_outputs",
+                thread_id, run_id
+            )
+        }));
+
+        // Enable streaming
+        let mut args = args;
+        args.stream = Some(true);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(assistants::stream::AssistantEventStream::new(
+                self.byte_stream(res),
+            ))
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Create a thread and run it in one request.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/runs/createThreadAndRun>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// use openai_rust::assistants::{messages::MessageArguments, runs::ThreadAndRunArguments};
+    /// let c = openai_rust::Client::new(api_key);
+    /// let mut args = ThreadAndRunArguments::new("asst_abc123");
+    /// args.thread.messages = vec![MessageArguments::new("user", "Explain deep learning to a 5 year old.")];
+    /// let run = c.create_thread_and_run(args, None).await.unwrap();
+    /// println!("{}", run.thread_id);
+    /// # })
+    /// ```
+    pub async fn create_thread_and_run(
+        &self,
+        args: assistants::runs::ThreadAndRunArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<assistants::runs::Run> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/threads/runs")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Create a vector store file by attaching a [File](files::FileObject) to a vector store.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores-files/createFile>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = openai_rust::vector_stores::VectorStoreFileArguments::new("file-abc123");
+    /// let file = c.create_vector_store_file("vs_abc123", args, None).await.unwrap();
+    /// println!("{:?}", file.status);
+    /// # })
+    /// ```
+    pub async fn create_vector_store_file(
+        &self,
+        vector_store_id: &str,
+        args: vector_stores::VectorStoreFileArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<vector_stores::VectorStoreFile> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/vector_stores/{}/files", vector_store_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of vector store files.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores-files/listFiles>.
+    pub async fn list_vector_store_files(
+        &self,
+        vector_store_id: &str,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<vector_stores::VectorStoreFile>> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/vector_stores/{}/files", vector_store_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a vector store file.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores-files/getFile>.
+    pub async fn retrieve_vector_store_file(
+        &self,
+        vector_store_id: &str,
+        file_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<vector_stores::VectorStoreFile> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| {
+                format!("/v1/vector_stores/{}/files/{}", vector_store_id, file_id)
+            }),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Delete a vector store file. This will remove the file from the vector store but the file itself will not be deleted.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores-files/deleteFile>.
+    pub async fn delete_vector_store_file(
+        &self,
+        vector_store_id: &str,
+        file_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<vector_stores::DeleteVectorStoreFileResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| {
+                format!("/v1/vector_stores/{}/files/{}", vector_store_id, file_id)
+            }),
+        );
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Create a vector store file batch.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores-file-batches/createBatch>.
+    pub async fn create_vector_store_file_batch(
+        &self,
+        vector_store_id: &str,
+        args: vector_stores::VectorStoreFileBatchArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<vector_stores::VectorStoreFileBatch> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/vector_stores/{}/file_batches", vector_store_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a vector store file batch.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores-file-batches/getBatch>.
+    pub async fn retrieve_vector_store_file_batch(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<vector_stores::VectorStoreFileBatch> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| {
+            format!(
+                "/v1/vector_stores/{}/file_batches/{}",
+                vector_store_id, batch_id
+            )
+        }));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Cancel a vector store file batch. This attempts to cancel the processing of files in this batch as soon as possible.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores-file-batches/cancelBatch>.
+    pub async fn cancel_vector_store_file_batch(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<vector_stores::VectorStoreFileBatch> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| {
+            format!(
+                "/v1/vector_stores/{}/file_batches/{}/cancel",
+                vector_store_id, batch_id
+            )
+        }));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of vector store files in a batch.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores-file-batches/listBatchFiles>.
+    pub async fn list_vector_store_file_batch_files(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<vector_stores::VectorStoreFile>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| {
+            format!(
+                "/v1/vector_stores/{}/file_batches/{}/files",
+                vector_store_id, batch_id
+            )
+        }));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Polls [Client::retrieve_vector_store_file_batch] every `poll_interval` until the batch
+    /// is no longer `in_progress`, and returns it.
+    ///
+    /// Fails if the batch is still in progress after `timeout`.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// use std::time::Duration;
+    /// let args = openai_rust::vector_stores::VectorStoreFileBatchArguments::new(vec!["file-abc123".to_owned()]);
+    /// let batch = c.create_vector_store_file_batch("vs_abc123", args, None).await.unwrap();
+    /// let batch = c
+    ///     .wait_for_vector_store_file_batch(&batch.vector_store_id, &batch.id, Duration::from_secs(1), Duration::from_secs(300))
+    ///     .await
+    ///     .unwrap();
+    /// println!("{} files failed", batch.file_counts.failed);
+    /// # })
+    /// ```
+    pub async fn wait_for_vector_store_file_batch(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        poll_interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> Result<vector_stores::VectorStoreFileBatch> {
+        let deadline = std::time::Instant::now() + timeout;
+        loop {
+            let batch = self
+                .retrieve_vector_store_file_batch(vector_store_id, batch_id, None)
+                .await?;
+            if batch.status.is_terminal() {
+                return Ok(batch);
+            }
+            if std::time::Instant::now() + poll_interval > deadline {
+                return Err(anyhow!(
+                    "timed out waiting for vector store file batch {} ({}/{} files completed)",
+                    batch_id,
+                    batch.file_counts.completed,
+                    batch.file_counts.total
+                ));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Search a vector store for relevant chunks based on a query and file attributes filter.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores/search>.
+    ///
+    /// This can be used for retrieval without running an assistant.
+    pub async fn search_vector_store(
+        &self,
+        vector_store_id: &str,
+        args: vector_stores::VectorStoreSearchArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<vector_stores::VectorStoreSearchResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/vector_stores/{}/search", vector_store_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .header("OpenAI-Beta", assistants::BETA_HEADER)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Creates and executes a batch from an uploaded file of requests.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/batch/create>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = openai_rust::batch::BatchArguments::new("file-abc123", "/v1/chat/completions");
+    /// println!("{}", c.create_batch(args, None).await.unwrap().id);
+    /// # })
+    /// ```
+    pub async fn create_batch(
+        &self,
+        args: batch::BatchArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<batch::Batch> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/batches")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a batch.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/batch/retrieve>.
+    pub async fn retrieve_batch(
+        &self,
+        batch_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<batch::Batch> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/batches/{}", batch_id)));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Cancels an in-progress batch.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/batch/cancel>.
+    ///
+    /// The batch will be in status `cancelling` for up to 10 minutes, before changing to `cancelled`,
+    /// where it will have partial results (if any) available in the output file.
+    pub async fn cancel_batch(
+        &self,
+        batch_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<batch::Batch> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/batches/{}/cancel", batch_id)));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// List your organization's batches.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/batch/list>.
+    ///
+    /// Supports the `after` and `limit` [pagination](pagination::ListArguments) arguments.
+    pub async fn list_batches(
+        &self,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<batch::Batch>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/batches")));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Polls [Client::retrieve_batch] until the batch reaches a [terminal status](batch::BatchStatus::is_terminal), and returns it.
+    /// The results can then be downloaded from [batch::Batch::output_file_id] and [batch::Batch::error_file_id]
+    /// with [Client::retrieve_file_content].
+    ///
+    /// The delay between polls starts at `poll_interval` and doubles after every poll, up to `max_poll_interval`.
+    /// Fails if the batch is still going after `timeout`.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// use std::time::Duration;
+    /// let batch = c
+    ///     .wait_for_batch(
+    ///         "batch_abc123",
+    ///         Duration::from_secs(10),
+    ///         Duration::from_secs(600),
+    ///         Duration::from_secs(24 * 60 * 60),
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// if let Some(output_file_id) = batch.output_file_id {
+    ///     let output = c.retrieve_file_content(&output_file_id, None).await.unwrap();
+    /// }
+    /// # })
+    /// ```
+    pub async fn wait_for_batch(
+        &self,
+        batch_id: &str,
+        poll_interval: std::time::Duration,
+        max_poll_interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> Result<batch::Batch> {
+        let deadline = std::time::Instant::now() + timeout;
+        let mut poll_interval = poll_interval;
+        loop {
+            let batch = self.retrieve_batch(batch_id, None).await?;
+            if batch.status.is_terminal() {
+                return Ok(batch);
+            }
+            if std::time::Instant::now() + poll_interval > deadline {
+                return Err(anyhow!(
+                    "timed out waiting for batch {} (status: {:?})",
+                    batch_id,
+                    batch.status
+                ));
+            }
+            tokio::time::sleep(poll_interval).await;
+            poll_interval = std::cmp::min(poll_interval * 2, max_poll_interval);
+        }
+    }
+
+    /// Creates a model response from text, image or file inputs.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/responses/create>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let args = openai_rust::responses::ResponseArguments::new("gpt-4.1", "Hello GPT!");
+    /// println!("{}", c.create_response(args, None).await.unwrap());
+    /// # })
+    /// ```
+    pub async fn create_response(
+        &self,
+        args: responses::ResponseArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<responses::Response> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/responses")));
+
+        let mut args = args;
+        args.user = self.default_user(args.user);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Like [Client::create_response] but with streaming.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/responses/create>.
+    ///
+    /// This method will return a stream of [responses::stream::ResponseStreamEvent]s. Use with [futures_util::StreamExt::next].
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # use std::io::Write;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// use openai_rust::responses::stream::ResponseStreamEvent;
+    /// use openai_rust::futures_util::StreamExt;
+    /// let args = openai_rust::responses::ResponseArguments::new("gpt-4.1", "Hello GPT!");
+    /// let mut res = c.create_response_stream(args, None).await.unwrap();
+    /// while let Some(event) = res.next().await {
+    ///     if let ResponseStreamEvent::OutputTextDelta(delta) = event.unwrap() {
+    ///         print!("{}", delta);
+    ///         std::io::stdout().flush().unwrap();
+    ///     }
+    /// }
+    /// # })
+    /// ```
+    pub async fn create_response_stream(
+        &self,
+        args: responses::ResponseArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<responses::stream::ResponseEventStream> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/responses")));
+
+        // Enable streaming
+        let mut args = args;
+        args.stream = Some(true);
+        args.user = self.default_user(args.user);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(responses::stream::ResponseEventStream::new(
+                self.byte_stream(res),
+            ))
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a model response with the given ID.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/responses/get>.
+    ///
+    /// Only responses created with [ResponseArguments::store](responses::ResponseArguments::store) enabled (the default) can be retrieved.
+    pub async fn retrieve_response(
+        &self,
+        response_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<responses::Response> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/responses/{}", response_id)));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Deletes a model response with the given ID.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/responses/delete>.
+    pub async fn delete_response(
+        &self,
+        response_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<responses::DeleteResponseResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/responses/{}", response_id)));
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Cancels a model response that was created with [ResponseArguments::background](responses::ResponseArguments::background) enabled.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/responses/cancel>.
+    pub async fn cancel_response(
+        &self,
+        response_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<responses::Response> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/responses/{}/cancel", response_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Polls [Client::retrieve_response] every `poll_interval` until a background response
+    /// reaches a [terminal status](responses::ResponseStatus::is_terminal), and returns it.
+    ///
+    /// Fails if the response is still being generated after `timeout`.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// use std::time::Duration;
+    /// let mut args = openai_rust::responses::ResponseArguments::new("o3", "Write a very long novel.");
+    /// args.background = Some(true);
+    /// let res = c.create_response(args, None).await.unwrap();
+    /// let res = c
+    ///     .wait_for_response(&res.id, Duration::from_secs(2), Duration::from_secs(3600))
+    ///     .await
+    ///     .unwrap();
+    /// println!("{}", res);
+    /// # })
+    /// ```
+    pub async fn wait_for_response(
+        &self,
+        response_id: &str,
+        poll_interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> Result<responses::Response> {
+        let deadline = std::time::Instant::now() + timeout;
+        loop {
+            let res = self.retrieve_response(response_id, None).await?;
+            if res.status.is_terminal() {
+                return Ok(res);
+            }
+            if std::time::Instant::now() + poll_interval > deadline {
+                return Err(anyhow!(
+                    "timed out waiting for response {} (status: {:?})",
+                    response_id,
+                    res.status
+                ));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Opens a WebSocket session with the Realtime API.
+    ///
+    /// See <https://platform.openai.com/docs/guides/realtime-websocket>.
+    ///
+    /// Requires the `realtime` feature.
+    #[cfg(feature = "realtime")]
+    pub async fn connect_realtime(
+        &self,
+        model: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<realtime::RealtimeSession> {
+        use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+        use tokio_tungstenite::tungstenite::http::HeaderValue;
+
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/realtime")));
+        url.query_pairs_mut().append_pair("model", model);
+        let scheme = if url.scheme() == "http" { "ws" } else { "wss" };
+        url.set_scheme(scheme)
+            .map_err(|_| anyhow!("invalid realtime url: {}", url))?;
+
+        let mut request = url.as_str().into_client_request()?;
+        let headers = request.headers_mut();
+        headers.insert(
+            "Authorization",
+            HeaderValue::from_str(&format!("Bearer {}", self.key))?,
+        );
+        headers.insert(
+            "OpenAI-Beta",
+            HeaderValue::from_static(realtime::BETA_HEADER),
+        );
+
+        let (socket, _) = tokio_tungstenite::connect_async(request).await?;
+        Ok(realtime::RealtimeSession::new(socket))
+    }
+
+    /// Creates an edited or extended image given an original image and a prompt.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/images/createEdit>.
+    ///
+    /// Like [Client::create_image], this returns the URLs or base64 encoded images, depending on the `response_format`.
+    pub async fn create_image_edit(
+        &self,
+        args: images::ImageEditArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<Vec<String>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/images/edits")));
+
+        let mut args = args;
+        args.user = self.default_user(args.user);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .multipart(args.into_form())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json::<images::ImageResponse>().await?.into_data())
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Creates a variation of a given image.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/images/createVariation>.
+    ///
+    /// Like [Client::create_image], this returns the URLs or base64 encoded images, depending on the `response_format`.
+    pub async fn create_image_variation(
+        &self,
+        args: images::ImageVariationArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<Vec<String>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/images/variations")));
+
+        let mut args = args;
+        args.user = self.default_user(args.user);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .multipart(args.into_form())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json::<images::ImageResponse>().await?.into_data())
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Like [Client::create_image] but with streaming, sending [images::ImageArguments::partial_images]
+    /// progressively refined frames before the final image. Only supported by `gpt-image-1`.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/images-streaming>.
+    ///
+    /// This method will return a stream of [images::ImageStreamEvent]s. Use with [futures_util::StreamExt::next].
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// use openai_rust::futures_util::StreamExt;
+    /// use openai_rust::images::ImageStreamEvent;
+    /// let mut args = openai_rust::images::ImageArguments::new("A river made of lava");
+    /// args.model = Some("gpt-image-1".to_owned());
+    /// args.partial_images = Some(2);
+    /// let mut res = c.create_image_stream(args, None).await.unwrap();
+    /// while let Some(event) = res.next().await {
+    ///     if let ImageStreamEvent::PartialImage(image) = event.unwrap() {
+    ///         // display image.b64_json
+    ///     }
+    /// }
+    /// # })
+    /// ```
+    pub async fn create_image_stream(
+        &self,
+        args: images::ImageArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<images::ImageEventStream> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/images/generations")));
+
+        // Enable streaming
+        let mut args = args.without_unsupported_fields();
+        args.stream = Some(true);
+        args.user = self.default_user(args.user);
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(images::ImageEventStream::new(self.byte_stream(res)))
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a model instance, providing basic information about the model such as the owner and permissioning.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/models/retrieve>.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let api_key = "";
+    /// let c = openai_rust::Client::new(api_key);
+    /// let model = c.retrieve_model("gpt-4o", None).await.unwrap();
+    /// println!("{} is owned by {}", model.id, model.owned_by);
+    /// # })
+    /// ```
+    pub async fn retrieve_model(
+        &self,
+        model: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<models::Model> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/models/{}", model)));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Delete a fine-tuned model. You must have the Owner role in your organization to delete a model.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/models/delete>.
+    pub async fn delete_model(
+        &self,
+        model: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<models::DeleteModelResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/models/{}", model)));
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns the usage of the organization, in time buckets.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/usage>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// use openai_rust::usage::{UsageArguments, UsageKind};
+    /// let page = c.list_usage(UsageKind::Completions, UsageArguments::new(1730419200), None).await.unwrap();
+    /// # })
+    /// ```
+    pub async fn list_usage(
+        &self,
+        kind: usage::UsageKind,
+        args: usage::UsageArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<usage::UsagePage> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/usage/{}", kind.as_str())),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .query(&args.to_query())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns the costs of the organization, in daily buckets.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/usage/costs>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn list_costs(
+        &self,
+        args: usage::CostsArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<usage::UsagePage> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/organization/costs")));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .query(&args.to_query())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of the admin API keys of the organization.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/admin-api-keys/list>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn list_admin_api_keys(
+        &self,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<organization::AdminApiKey>> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| String::from("/v1/organization/admin_api_keys")),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Creates an admin API key. Its secret is only returned in [organization::AdminApiKey::value] of this response.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/admin-api-keys/create>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn create_admin_api_key(
+        &self,
+        args: organization::AdminApiKeyArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::AdminApiKey> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| String::from("/v1/organization/admin_api_keys")),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(self.admin_key())
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves an admin API key.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/admin-api-keys>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn retrieve_admin_api_key(
+        &self,
+        key_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::AdminApiKey> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/admin_api_keys/{}", key_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Deletes an admin API key.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/admin-api-keys/delete>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn delete_admin_api_key(
+        &self,
+        key_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::DeleteApiKeyResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/admin_api_keys/{}", key_id)),
+        );
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of the API keys of a project.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/project-api-keys/list>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn list_project_api_keys(
+        &self,
+        project_id: &str,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<organization::ProjectApiKey>> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/organization/projects/{}/api_keys", project_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves an API key of a project.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/project-api-keys/retrieve>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn retrieve_project_api_key(
+        &self,
+        project_id: &str,
+        key_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::ProjectApiKey> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| {
+            format!(
+                "/v1/organization/projects/{}/api_keys/{}",
+                project_id, key_id
+            )
+        }));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Deletes an API key of a project.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/project-api-keys/delete>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    ///
+    /// Project API keys are created from the dashboard, the API does not support creating them.
+    pub async fn delete_project_api_key(
+        &self,
+        project_id: &str,
+        key_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::DeleteApiKeyResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| {
+            format!(
+                "/v1/organization/projects/{}/api_keys/{}",
+                project_id, key_id
+            )
+        }));
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of the projects of the organization.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/projects/list>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn list_projects(
+        &self,
+        args: organization::ListProjectsArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<organization::Project>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/organization/projects")));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Creates a project in the organization.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/projects/create>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// let c = openai_rust::Client::new("").with_admin_key("sk-admin-...");
+    /// let args = openai_rust::organization::ProjectArguments::new("Project ABC");
+    /// println!("{}", c.create_project(args, None).await.unwrap().id);
+    /// # })
+    /// ```
+    pub async fn create_project(
+        &self,
+        args: organization::ProjectArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::Project> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/organization/projects")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(self.admin_key())
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a project.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/projects/retrieve>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn retrieve_project(
+        &self,
+        project_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::Project> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/projects/{}", project_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Modifies a project.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/projects/modify>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn modify_project(
+        &self,
+        project_id: &str,
+        args: organization::ProjectArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::Project> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/projects/{}", project_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(self.admin_key())
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Archives a project. Archived projects cannot be used or updated.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/projects/archive>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn archive_project(
+        &self,
+        project_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::Project> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path
+                .unwrap_or_else(|| format!("/v1/organization/projects/{}/archive", project_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of the users of the organization.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/users/list>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn list_users(
+        &self,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<organization::User>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/organization/users")));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves a user of the organization.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/users/retrieve>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn retrieve_user(
+        &self,
+        user_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::User> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/users/{}", user_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Modifies the role of a user of the organization.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/users/modify>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn modify_user(
+        &self,
+        user_id: &str,
+        args: organization::ModifyUserArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::User> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/users/{}", user_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(self.admin_key())
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Removes a user from the organization.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/users/delete>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn delete_user(
+        &self,
+        user_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::DeleteUserResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/users/{}", user_id)),
+        );
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of the invites of the organization.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/invite/list>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn list_invites(
+        &self,
+        args: pagination::ListArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<organization::Invite>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/organization/invites")));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .query(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Invites a user to the organization. The invite must be accepted by the user before they have access.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/invite/create>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// let c = openai_rust::Client::new("").with_admin_key("sk-admin-...");
+    /// let args = openai_rust::organization::InviteArguments::new("anotheruser@example.com", "reader");
+    /// println!("{}", c.create_invite(args, None).await.unwrap().id);
+    /// # })
+    /// ```
+    pub async fn create_invite(
+        &self,
+        args: organization::InviteArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::Invite> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/organization/invites")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(self.admin_key())
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Retrieves an invite.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/invite/retrieve>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn retrieve_invite(
+        &self,
+        invite_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::Invite> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/invites/{}", invite_id)),
+        );
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Deletes an invite. If the invite has already been accepted, it cannot be deleted.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/invite/delete>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    pub async fn delete_invite(
+        &self,
+        invite_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<organization::DeleteInviteResponse> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/organization/invites/{}", invite_id)),
+        );
+
+        let res = self
+            .req_client
+            .delete(url)
+            .bearer_auth(self.admin_key())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Returns a list of user actions and configuration changes within the organization.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/audit-logs/list>.
+    ///
+    /// Requires an admin API key, see [Client::with_admin_key].
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// let c = openai_rust::Client::new("").with_admin_key("sk-admin-...");
+    /// let mut args = openai_rust::audit_logs::AuditLogArguments::default();
+    /// args.actor_emails = vec!["user@example.com".to_owned()];
+    /// for log in c.list_audit_logs(args, None).await.unwrap().data {
+    ///     println!("{} {:?}", log.event_type, log.event);
+    /// }
+    /// # })
+    /// ```
+    pub async fn list_audit_logs(
+        &self,
+        args: audit_logs::AuditLogArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<pagination::ListResponse<audit_logs::AuditLog>> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/organization/audit_logs")));
+
+        let res = self
+            .req_client
+            .get(url)
+            .bearer_auth(self.admin_key())
+            .query(&args.to_query())
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Creates an intermediate upload object that parts can be added to.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/uploads/create>.
+    ///
+    /// An upload expires after an hour, all parts must be added and the upload completed before then.
+    pub async fn create_upload(
+        &self,
+        args: uploads::UploadArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<uploads::Upload> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| String::from("/v1/uploads")));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Adds a part of at most [uploads::MAX_PART_SIZE] bytes to an upload.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/uploads/add-part>.
+    pub async fn add_upload_part(
+        &self,
+        upload_id: &str,
+        data: Vec<u8>,
+        opt_url_path: Option<String>,
+    ) -> Result<uploads::UploadPart> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/uploads/{}/parts", upload_id)));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .multipart(uploads::part_form(data))
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Completes an upload, creating the [files::FileObject] made of the parts in the given order.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/uploads/complete>.
+    pub async fn complete_upload(
+        &self,
+        upload_id: &str,
+        args: uploads::CompleteUploadArguments,
+        opt_url_path: Option<String>,
+    ) -> Result<uploads::Upload> {
+        let mut url = self.base_url.clone();
+        url.set_path(
+            &opt_url_path.unwrap_or_else(|| format!("/v1/uploads/{}/complete", upload_id)),
+        );
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .json(&args)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Cancels an upload. No parts may be added after an upload is cancelled.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/uploads/cancel>.
+    pub async fn cancel_upload(
+        &self,
+        upload_id: &str,
+        opt_url_path: Option<String>,
+    ) -> Result<uploads::Upload> {
+        let mut url = self.base_url.clone();
+        url.set_path(&opt_url_path.unwrap_or_else(|| format!("/v1/uploads/{}/cancel", upload_id)));
+
+        let res = self
+            .req_client
+            .post(url)
+            .bearer_auth(&self.key)
+            .send()
+            .await?;
+
+        if res.status() == 200 {
+            Ok(res.json().await?)
+        } else {
+            Err(anyhow!(res.text().await?))
+        }
+    }
+
+    /// Uploads a file from disk with the Uploads API, one part at a time, and returns the created file.
+    ///
+    /// Only one part of at most [uploads::MAX_PART_SIZE] bytes is held in memory at once.
+    /// The upload is cancelled if a part fails.
+    ///
+    /// ```no_run
+    /// # use openai_rust2 as openai_rust;
+    /// # use tokio_test;
+    /// # tokio_test::block_on(async {
+    /// # let c = openai_rust::Client::new("");
+    /// use openai_rust::files::FilePurpose;
+    /// let file = c
+    ///     .upload_large_file("training_examples.jsonl", FilePurpose::FineTune, "text/jsonl")
+    ///     .await
+    ///     .unwrap();
+    /// println!("{}", file.id);
+    /// # })
+    /// ```
+    pub async fn upload_large_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        purpose: files::FilePurpose,
+        mime_type: &str,
+    ) -> Result<files::FileObject> {
+        use tokio::io::AsyncReadExt;
+
+        let path = path.as_ref();
+        let mut file = tokio::fs::File::open(path).await?;
+        let bytes = file.metadata().await?.len();
+        let filename = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| anyhow!("invalid file name: {}", path.display()))?;
+
+        let args = uploads::UploadArguments::new(filename, bytes, purpose, mime_type);
+        let upload = self.create_upload(args, None).await?;
+
+        let mut part_ids = vec![];
+        loop {
+            let mut data = Vec::with_capacity(uploads::MAX_PART_SIZE.min(bytes as usize));
+            let read = (&mut file)
+                .take(uploads::MAX_PART_SIZE as u64)
+                .read_to_end(&mut data)
+                .await;
+            match read {
+                Ok(0) => break,
+                Ok(_) => match self.add_upload_part(&upload.id, data, None).await {
+                    Ok(part) => part_ids.push(part.id),
+                    Err(e) => {
+                        let _ = self.cancel_upload(&upload.id, None).await;
+                        return Err(e);
+                    }
+                },
+                Err(e) => {
+                    let _ = self.cancel_upload(&upload.id, None).await;
+                    return Err(e.into());
+                }
+            }
+        }
+
+        let args = uploads::CompleteUploadArguments::new(part_ids);
+        self.complete_upload(&upload.id, args, None)
+            .await?
+            .file
+            .ok_or_else(|| anyhow!("upload {} completed without a file", upload.id))
+    }
 }
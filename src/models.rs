@@ -1,5 +1,7 @@
 //! See <https://platform.openai.com/docs/api-reference/models>.
-//! Use with [Client::list_models](crate::Client::list_models).
+//! Use with [Client::list_models](crate::Client::list_models),
+//! [Client::retrieve_model](crate::Client::retrieve_model)
+//! or [Client::delete_model](crate::Client::delete_model).
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
@@ -18,6 +20,13 @@ pub struct Model {
     pub created: u64,
 }
 
+/// The response of a model deletion request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteModelResponse {
+    pub id: String,
+    pub deleted: bool,
+}
+
 // /// Permissions of a model
 // #[derive(Deserialize, Debug)]
 // pub struct ModelPermission {
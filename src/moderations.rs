@@ -0,0 +1,209 @@
+//! See <https://platform.openai.com/docs/api-reference/moderations>.
+//! Use with [Client::create_moderation](crate::Client::create_moderation).
+use serde::{Deserialize, Serialize};
+
+/// Request arguments for moderations.
+///
+/// See <https://platform.openai.com/docs/api-reference/moderations/create>.
+///
+/// ```
+/// let args = openai_rust2::moderations::ModerationArguments::new(
+///     "I want to kill them.".to_owned(),
+/// );
+/// // or classify several texts in one request
+/// let args = openai_rust2::moderations::ModerationArguments::new(vec![
+///     "I want to kill them.".to_owned(),
+///     "I love them.".to_owned(),
+/// ]);
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct ModerationArguments {
+    /// The input text(s) to classify.
+    pub input: ModerationInput,
+
+    /// The content moderation model you would like to use, such as `omni-moderation-latest` or `text-moderation-latest`.
+    /// Defaults to `omni-moderation-latest`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+impl ModerationArguments {
+    pub fn new(input: impl Into<ModerationInput>) -> ModerationArguments {
+        ModerationArguments {
+            input: input.into(),
+            model: None,
+        }
+    }
+}
+
+/// The input of a moderation request, either a single text or a batch of texts.
+///
+/// A batch returns one [ModerationResult] per input, in the same order.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ModerationInput {
+    Text(String),
+    Batch(Vec<String>),
+}
+
+impl From<&str> for ModerationInput {
+    fn from(text: &str) -> Self {
+        ModerationInput::Text(text.to_owned())
+    }
+}
+
+impl From<String> for ModerationInput {
+    fn from(text: String) -> Self {
+        ModerationInput::Text(text)
+    }
+}
+
+impl From<Vec<String>> for ModerationInput {
+    fn from(texts: Vec<String>) -> Self {
+        ModerationInput::Batch(texts)
+    }
+}
+
+/// The response of a moderation request.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"modr-XXXXX\",
+/// #  \"model\": \"text-moderation-007\",
+/// #  \"results\": [
+/// #    {
+/// #      \"flagged\": true,
+/// #      \"categories\": {
+/// #        \"sexual\": false,
+/// #        \"hate\": false,
+/// #        \"harassment\": false,
+/// #        \"self-harm\": false,
+/// #        \"sexual/minors\": false,
+/// #        \"hate/threatening\": false,
+/// #        \"violence/graphic\": false,
+/// #        \"self-harm/intent\": false,
+/// #        \"self-harm/instructions\": false,
+/// #        \"harassment/threatening\": true,
+/// #        \"violence\": true
+/// #      },
+/// #      \"category_scores\": {
+/// #        \"sexual\": 1.2282071e-06,
+/// #        \"hate\": 0.010696256,
+/// #        \"harassment\": 0.29842457,
+/// #        \"self-harm\": 1.5236925e-08,
+/// #        \"sexual/minors\": 5.7246268e-08,
+/// #        \"hate/threatening\": 0.0060676364,
+/// #        \"violence/graphic\": 4.435014e-06,
+/// #        \"self-harm/intent\": 8.098441e-10,
+/// #        \"self-harm/instructions\": 2.8498655e-11,
+/// #        \"harassment/threatening\": 0.63055265,
+/// #        \"violence\": 0.99011886
+/// #      }
+/// #    }
+/// #  ]
+/// # }";
+/// # let res = serde_json::from_str::<openai_rust2::moderations::ModerationResponse>(json).unwrap();
+/// let flagged = res.results[0].flagged;
+/// let violence = res.results[0].category_scores.violence;
+/// assert_eq!(res.results[0].categories.flagged(), vec!["harassment/threatening", "violence"]);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct ModerationResponse {
+    pub id: String,
+    pub model: String,
+    pub results: Vec<ModerationResult>,
+}
+
+/// A moderation result for a single input.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ModerationResult {
+    /// Whether any of the categories are flagged.
+    pub flagged: bool,
+    /// Whether each category was flagged.
+    pub categories: ModerationCategories,
+    /// The score the model assigned to each category.
+    pub category_scores: ModerationCategoryScores,
+}
+
+/// Whether each moderation category was flagged.
+///
+/// The `illicit` categories are only returned by the `omni-moderation` models.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ModerationCategories {
+    pub harassment: bool,
+    #[serde(rename = "harassment/threatening")]
+    pub harassment_threatening: bool,
+    pub hate: bool,
+    #[serde(rename = "hate/threatening")]
+    pub hate_threatening: bool,
+    #[serde(default)]
+    pub illicit: Option<bool>,
+    #[serde(rename = "illicit/violent", default)]
+    pub illicit_violent: Option<bool>,
+    #[serde(rename = "self-harm")]
+    pub self_harm: bool,
+    #[serde(rename = "self-harm/intent")]
+    pub self_harm_intent: bool,
+    #[serde(rename = "self-harm/instructions")]
+    pub self_harm_instructions: bool,
+    pub sexual: bool,
+    #[serde(rename = "sexual/minors")]
+    pub sexual_minors: bool,
+    pub violence: bool,
+    #[serde(rename = "violence/graphic")]
+    pub violence_graphic: bool,
+}
+
+impl ModerationCategories {
+    /// The API names of the flagged categories, such as `"violence/graphic"`.
+    pub fn flagged(&self) -> Vec<&'static str> {
+        [
+            ("harassment", self.harassment),
+            ("harassment/threatening", self.harassment_threatening),
+            ("hate", self.hate),
+            ("hate/threatening", self.hate_threatening),
+            ("illicit", self.illicit.unwrap_or(false)),
+            ("illicit/violent", self.illicit_violent.unwrap_or(false)),
+            ("self-harm", self.self_harm),
+            ("self-harm/intent", self.self_harm_intent),
+            ("self-harm/instructions", self.self_harm_instructions),
+            ("sexual", self.sexual),
+            ("sexual/minors", self.sexual_minors),
+            ("violence", self.violence),
+            ("violence/graphic", self.violence_graphic),
+        ]
+        .into_iter()
+        .filter_map(|(name, flagged)| flagged.then_some(name))
+        .collect()
+    }
+}
+
+/// The score the model assigned to each moderation category, between 0 and 1.
+///
+/// The `illicit` categories are only returned by the `omni-moderation` models.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ModerationCategoryScores {
+    pub harassment: f32,
+    #[serde(rename = "harassment/threatening")]
+    pub harassment_threatening: f32,
+    pub hate: f32,
+    #[serde(rename = "hate/threatening")]
+    pub hate_threatening: f32,
+    #[serde(default)]
+    pub illicit: Option<f32>,
+    #[serde(rename = "illicit/violent", default)]
+    pub illicit_violent: Option<f32>,
+    #[serde(rename = "self-harm")]
+    pub self_harm: f32,
+    #[serde(rename = "self-harm/intent")]
+    pub self_harm_intent: f32,
+    #[serde(rename = "self-harm/instructions")]
+    pub self_harm_instructions: f32,
+    pub sexual: f32,
+    #[serde(rename = "sexual/minors")]
+    pub sexual_minors: f32,
+    pub violence: f32,
+    #[serde(rename = "violence/graphic")]
+    pub violence_graphic: f32,
+}
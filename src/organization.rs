@@ -0,0 +1,295 @@
+//! See <https://platform.openai.com/docs/api-reference/administration>.
+//! These endpoints require an admin API key, see [Client::with_admin_key](crate::Client::with_admin_key).
+//!
+//! Use with [Client::list_admin_api_keys](crate::Client::list_admin_api_keys),
+//! [Client::create_admin_api_key](crate::Client::create_admin_api_key),
+//! [Client::retrieve_admin_api_key](crate::Client::retrieve_admin_api_key),
+//! [Client::delete_admin_api_key](crate::Client::delete_admin_api_key),
+//! [Client::list_project_api_keys](crate::Client::list_project_api_keys),
+//! [Client::retrieve_project_api_key](crate::Client::retrieve_project_api_key),
+//! [Client::delete_project_api_key](crate::Client::delete_project_api_key),
+//! [Client::list_projects](crate::Client::list_projects),
+//! [Client::create_project](crate::Client::create_project),
+//! [Client::retrieve_project](crate::Client::retrieve_project),
+//! [Client::modify_project](crate::Client::modify_project),
+//! [Client::archive_project](crate::Client::archive_project),
+//! [Client::list_users](crate::Client::list_users),
+//! [Client::retrieve_user](crate::Client::retrieve_user),
+//! [Client::modify_user](crate::Client::modify_user),
+//! [Client::delete_user](crate::Client::delete_user),
+//! [Client::list_invites](crate::Client::list_invites),
+//! [Client::create_invite](crate::Client::create_invite),
+//! [Client::retrieve_invite](crate::Client::retrieve_invite)
+//! or [Client::delete_invite](crate::Client::delete_invite).
+use serde::{Deserialize, Serialize};
+
+/// Request arguments for creating an admin API key.
+///
+/// See <https://platform.openai.com/docs/api-reference/admin-api-keys/create>.
+#[derive(Serialize, Debug, Clone)]
+pub struct AdminApiKeyArguments {
+    /// The name of the key.
+    pub name: String,
+}
+
+impl AdminApiKeyArguments {
+    pub fn new(name: impl AsRef<str>) -> AdminApiKeyArguments {
+        AdminApiKeyArguments {
+            name: name.as_ref().to_owned(),
+        }
+    }
+}
+
+/// An API key used to administer the organization.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"object\": \"organization.admin_api_key\",
+/// #   \"id\": \"key_xyz\",
+/// #   \"name\": \"New Admin Key\",
+/// #   \"redacted_value\": \"sk-admin...xyz\",
+/// #   \"created_at\": 1711471533,
+/// #   \"last_used_at\": 1711471534,
+/// #   \"owner\": {
+/// #     \"type\": \"user\",
+/// #     \"object\": \"organization.user\",
+/// #     \"id\": \"user_123\",
+/// #     \"name\": \"John Doe\",
+/// #     \"created_at\": 1711471533,
+/// #     \"role\": \"owner\"
+/// #   },
+/// #   \"value\": \"sk-admin-1234abcd\"
+/// # }";
+/// # let key = serde_json::from_str::<openai_rust2::organization::AdminApiKey>(json).unwrap();
+/// let secret = key.value.unwrap();
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct AdminApiKey {
+    pub id: String,
+    pub name: Option<String>,
+    /// The redacted value of the key, e.g. `sk-admin...xyz`.
+    pub redacted_value: String,
+    /// The secret value of the key, only returned by [crate::Client::create_admin_api_key].
+    pub value: Option<String>,
+    pub created_at: u64,
+    pub last_used_at: Option<u64>,
+    pub owner: ApiKeyOwner,
+}
+
+/// An API key of a project.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProjectApiKey {
+    pub id: String,
+    pub name: Option<String>,
+    /// The redacted value of the key, e.g. `sk-abc...def`.
+    pub redacted_value: String,
+    pub created_at: u64,
+    pub last_used_at: Option<u64>,
+    pub owner: ProjectApiKeyOwner,
+}
+
+/// The owner of an [AdminApiKey].
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiKeyOwner {
+    /// `user` or `service_account`.
+    #[serde(rename = "type")]
+    pub owner_type: Option<String>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub created_at: Option<u64>,
+    pub role: Option<String>,
+}
+
+/// The owner of a [ProjectApiKey], a user or a service account.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProjectApiKeyOwner {
+    /// `user` or `service_account`.
+    #[serde(rename = "type")]
+    pub owner_type: String,
+    pub user: Option<ApiKeyOwner>,
+    pub service_account: Option<ApiKeyOwner>,
+}
+
+/// The response of an API key deletion request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteApiKeyResponse {
+    pub id: String,
+    pub deleted: bool,
+}
+
+/// Request arguments for creating or modifying a project.
+///
+/// See <https://platform.openai.com/docs/api-reference/projects/create>.
+#[derive(Serialize, Debug, Clone)]
+pub struct ProjectArguments {
+    /// The friendly name of the project, this name appears in reports.
+    pub name: String,
+}
+
+impl ProjectArguments {
+    pub fn new(name: impl AsRef<str>) -> ProjectArguments {
+        ProjectArguments {
+            name: name.as_ref().to_owned(),
+        }
+    }
+}
+
+/// Query arguments for listing projects.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ListProjectsArguments {
+    /// A cursor for use in pagination. `after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+
+    /// A limit on the number of objects to be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Whether to include archived projects. Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_archived: Option<bool>,
+}
+
+/// The status of a [Project].
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectStatus {
+    Active,
+    Archived,
+}
+
+/// A project of the organization.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"id\": \"proj_abc\",
+/// #   \"object\": \"organization.project\",
+/// #   \"name\": \"Project example\",
+/// #   \"created_at\": 1711471533,
+/// #   \"archived_at\": null,
+/// #   \"status\": \"active\"
+/// # }";
+/// # let project = serde_json::from_str::<openai_rust2::organization::Project>(json).unwrap();
+/// assert_eq!(project.status, openai_rust2::organization::ProjectStatus::Active);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    pub created_at: u64,
+    pub archived_at: Option<u64>,
+    pub status: ProjectStatus,
+}
+
+/// A member of the organization.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"object\": \"organization.user\",
+/// #   \"id\": \"user_abc\",
+/// #   \"name\": \"First Last\",
+/// #   \"email\": \"user@example.com\",
+/// #   \"role\": \"owner\",
+/// #   \"added_at\": 1711471533
+/// # }";
+/// # let user = serde_json::from_str::<openai_rust2::organization::User>(json).unwrap();
+/// println!("{} is {}", user.email, user.role);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct User {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    /// `owner` or `reader`.
+    pub role: String,
+    pub added_at: u64,
+}
+
+/// Request arguments for modifying a user.
+#[derive(Serialize, Debug, Clone)]
+pub struct ModifyUserArguments {
+    /// `owner` or `reader`.
+    pub role: String,
+}
+
+impl ModifyUserArguments {
+    pub fn new(role: impl AsRef<str>) -> ModifyUserArguments {
+        ModifyUserArguments {
+            role: role.as_ref().to_owned(),
+        }
+    }
+}
+
+/// The response of a user deletion request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteUserResponse {
+    pub id: String,
+    pub deleted: bool,
+}
+
+/// Request arguments for inviting a user.
+///
+/// See <https://platform.openai.com/docs/api-reference/invite/create>.
+#[derive(Serialize, Debug, Clone)]
+pub struct InviteArguments {
+    /// Send an email to this address.
+    pub email: String,
+    /// `owner` or `reader`.
+    pub role: String,
+    /// The projects the user will join once the invite is accepted.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<InviteProject>,
+}
+
+impl InviteArguments {
+    pub fn new(email: impl AsRef<str>, role: impl AsRef<str>) -> InviteArguments {
+        InviteArguments {
+            email: email.as_ref().to_owned(),
+            role: role.as_ref().to_owned(),
+            projects: vec![],
+        }
+    }
+}
+
+/// A project of an [Invite].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InviteProject {
+    /// The ID of the project.
+    pub id: String,
+    /// The role in the project, `member` or `owner`.
+    pub role: String,
+}
+
+/// The status of an [Invite].
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InviteStatus {
+    Accepted,
+    Expired,
+    Pending,
+}
+
+/// An invitation for a user to join the organization.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Invite {
+    pub id: String,
+    pub email: String,
+    /// `owner` or `reader`.
+    pub role: String,
+    pub status: InviteStatus,
+    pub invited_at: u64,
+    pub expires_at: u64,
+    pub accepted_at: Option<u64>,
+    #[serde(default)]
+    pub projects: Vec<InviteProject>,
+}
+
+/// The response of an invite deletion request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteInviteResponse {
+    pub id: String,
+    pub deleted: bool,
+}
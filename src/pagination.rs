@@ -0,0 +1,49 @@
+//! Cursor based pagination shared by the list endpoints.
+//!
+//! See <https://platform.openai.com/docs/api-reference/pagination>.
+
+use serde::{Deserialize, Serialize};
+
+/// Query arguments for paginated list endpoints.
+///
+/// Not every endpoint supports every argument, leave the unsupported ones as `None`.
+///
+/// ```
+/// let args = openai_rust2::pagination::ListArguments {
+///     limit: Some(10),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ListArguments {
+    /// A cursor for use in pagination. `after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+
+    /// A cursor for use in pagination. `before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+
+    /// A limit on the number of objects to be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Sort order by the `created_at` timestamp of the objects. `asc` for ascending order and `desc` for descending order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+/// A page of objects returned by a list endpoint.
+///
+/// To fetch the next page, pass [ListResponse::last_id] as [ListArguments::after].
+#[derive(Deserialize, Debug, Clone)]
+pub struct ListResponse<T> {
+    pub data: Vec<T>,
+    /// Whether there are more objects available after this page.
+    #[serde(default)]
+    pub has_more: bool,
+    #[serde(default)]
+    pub first_id: Option<String>,
+    #[serde(default)]
+    pub last_id: Option<String>,
+}
@@ -0,0 +1,743 @@
+//! See <https://platform.openai.com/docs/api-reference/realtime>.
+//! Use with [Client::connect_realtime](crate::Client::connect_realtime).
+//!
+//! Requires the `realtime` feature.
+//!
+//! A [RealtimeSession] sends [ClientEvent]s and receives [ServerEvent]s over a WebSocket.
+//! It can be [split](RealtimeSession::split) to send and receive from different tasks.
+//! ```no_run
+//! # use openai_rust2 as openai_rust;
+//! # use tokio_test;
+//! # tokio_test::block_on(async {
+//! # let c = openai_rust::Client::new("");
+//! use openai_rust::futures_util::StreamExt;
+//! use openai_rust::realtime::{ClientEvent, ServerEvent};
+//! let session = c.connect_realtime("gpt-4o-realtime-preview", None).await.unwrap();
+//! let (mut sender, mut receiver) = session.split();
+//! sender.send(ClientEvent::conversation_item_text("user", "Hello!")).await.unwrap();
+//! sender.send(ClientEvent::ResponseCreate { response: None }).await.unwrap();
+//! while let Some(event) = receiver.next().await {
+//!     match event.unwrap() {
+//!         ServerEvent::ResponseTextDelta(delta) => print!("{}", delta),
+//!         ServerEvent::ResponseDone(_) => break,
+//!         _ => {}
+//!     }
+//! }
+//! # })
+//! ```
+//!
+//! Audio is sent with [ClientEvent::input_audio_buffer_append] and received with [AudioAssembler].
+//!
+//! Functions declared in [SessionConfig::tools] are called through [ServerEvent::ResponseFunctionCallArgumentsDone],
+//! to be answered with [RealtimeFunctionCall::output] followed by a `response.create`.
+use base64::Engine;
+use bytes::Bytes;
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::task::Poll;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+pub(crate) const BETA_HEADER: &str = "realtime=v1";
+
+type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// An event sent by the client.
+///
+/// See <https://platform.openai.com/docs/api-reference/realtime-client-events>.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum ClientEvent {
+    /// Update the configuration of the session.
+    #[serde(rename = "session.update")]
+    SessionUpdate { session: SessionConfig },
+    /// Add an item to the conversation.
+    #[serde(rename = "conversation.item.create")]
+    ConversationItemCreate {
+        item: ConversationItem,
+        /// The ID of the item after which to insert the new one. Appended at the end if `None`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        previous_item_id: Option<String>,
+    },
+    /// Append base64-encoded audio bytes to the input audio buffer.
+    #[serde(rename = "input_audio_buffer.append")]
+    InputAudioBufferAppend { audio: String },
+    /// Commit the input audio buffer, creating a user message item from it.
+    /// Not needed when server-side voice activity detection is enabled.
+    #[serde(rename = "input_audio_buffer.commit")]
+    InputAudioBufferCommit,
+    /// Clear the input audio buffer.
+    #[serde(rename = "input_audio_buffer.clear")]
+    InputAudioBufferClear,
+    /// Ask the server to create a response.
+    #[serde(rename = "response.create")]
+    ResponseCreate {
+        /// Overrides of the session configuration for this response.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        response: Option<SessionConfig>,
+    },
+    /// Cancel the response in progress.
+    #[serde(rename = "response.cancel")]
+    ResponseCancel,
+}
+
+impl ClientEvent {
+    /// A `conversation.item.create` event adding a text message.
+    pub fn conversation_item_text(role: impl AsRef<str>, text: impl AsRef<str>) -> ClientEvent {
+        ClientEvent::ConversationItemCreate {
+            item: ConversationItem::text(role, text),
+            previous_item_id: None,
+        }
+    }
+
+    /// A `conversation.item.create` event with the result of a function call.
+    pub fn function_call_output(call_id: impl AsRef<str>, output: impl AsRef<str>) -> ClientEvent {
+        ClientEvent::ConversationItemCreate {
+            item: ConversationItem::FunctionCallOutput {
+                id: None,
+                call_id: call_id.as_ref().to_owned(),
+                output: output.as_ref().to_owned(),
+            },
+            previous_item_id: None,
+        }
+    }
+
+    /// An `input_audio_buffer.append` event with raw audio bytes,
+    /// in the session's `input_audio_format` (16-bit PCM at 24kHz, mono, little-endian by default).
+    ///
+    /// ```
+    /// use openai_rust2::realtime::ClientEvent;
+    /// let pcm = vec![0u8; 4800]; // 100ms of silence
+    /// let events = [ClientEvent::input_audio_buffer_append(&pcm), ClientEvent::InputAudioBufferCommit];
+    /// ```
+    pub fn input_audio_buffer_append(audio: &[u8]) -> ClientEvent {
+        ClientEvent::InputAudioBufferAppend {
+            audio: base64::engine::general_purpose::STANDARD.encode(audio),
+        }
+    }
+}
+
+/// The configuration of a realtime session.
+///
+/// See <https://platform.openai.com/docs/api-reference/realtime-client-events/session/update>.
+///
+/// ```
+/// let mut config = openai_rust2::realtime::SessionConfig::default();
+/// config.modalities = Some(vec!["text".to_owned()]);
+/// config.instructions = Some("Be brief.".to_owned());
+/// ```
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SessionConfig {
+    /// The set of modalities the model can respond with, `text` and/or `audio`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modalities: Option<Vec<String>>,
+    /// The default system instructions prepended to model calls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// The voice the model uses to respond, such as `alloy`, `ash` or `verse`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<String>,
+    /// The format of input audio, `pcm16`, `g711_ulaw` or `g711_alaw`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_audio_format: Option<String>,
+    /// The format of output audio, `pcm16`, `g711_ulaw` or `g711_alaw`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_audio_format: Option<String>,
+    /// Configuration for turn detection, or `null` to turn it off.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turn_detection: Option<serde_json::Value>,
+    /// Sampling temperature for the model, limited to between 0.6 and 1.2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Tools (functions) available to the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<RealtimeTool>>,
+    /// How the model chooses tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<RealtimeToolChoice>,
+}
+
+/// How the model chooses the [SessionConfig::tools] to call.
+///
+/// ```
+/// use openai_rust2::realtime::RealtimeToolChoice;
+/// assert_eq!(serde_json::to_value(RealtimeToolChoice::Required).unwrap(), "required");
+/// assert_eq!(
+///     serde_json::to_value(RealtimeToolChoice::function("get_weather")).unwrap(),
+///     serde_json::json!({"type": "function", "name": "get_weather"})
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RealtimeToolChoice {
+    /// The model can pick between responding and calling one or more tools.
+    Auto,
+    /// The model will not call any tool.
+    None,
+    /// The model must call one or more tools.
+    Required,
+    /// The model must call the function with this name.
+    Function(String),
+}
+
+impl RealtimeToolChoice {
+    /// Forces the model to call the function named `name`.
+    pub fn function(name: impl AsRef<str>) -> RealtimeToolChoice {
+        RealtimeToolChoice::Function(name.as_ref().to_owned())
+    }
+}
+
+impl Serialize for RealtimeToolChoice {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            RealtimeToolChoice::Auto => serializer.serialize_str("auto"),
+            RealtimeToolChoice::None => serializer.serialize_str("none"),
+            RealtimeToolChoice::Required => serializer.serialize_str("required"),
+            RealtimeToolChoice::Function(name) => serde_json::json!({
+                "type": "function",
+                "name": name,
+            })
+            .serialize(serializer),
+        }
+    }
+}
+
+/// A tool available to the model in a realtime session.
+///
+/// ```
+/// let tool = openai_rust2::realtime::RealtimeTool::Function {
+///     name: "get_weather".to_owned(),
+///     description: Some("Get the current weather in a given location.".to_owned()),
+///     parameters: serde_json::json!({
+///         "type": "object",
+///         "properties": { "location": { "type": "string" } },
+///         "required": ["location"]
+///     }),
+/// };
+/// ```
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RealtimeTool {
+    Function {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// The parameters the function accepts, described as a JSON Schema object.
+        parameters: serde_json::Value,
+    },
+}
+
+/// An item of a realtime conversation.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConversationItem {
+    Message {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// `user`, `assistant` or `system`.
+        role: String,
+        content: Vec<ContentPart>,
+    },
+    /// A function call made by the model.
+    FunctionCall {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        call_id: String,
+        name: String,
+        /// A JSON string of the arguments to pass to the function.
+        arguments: String,
+    },
+    /// The result of a function call.
+    FunctionCallOutput {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        call_id: String,
+        output: String,
+    },
+}
+
+impl ConversationItem {
+    /// A text message.
+    pub fn text(role: impl AsRef<str>, text: impl AsRef<str>) -> ConversationItem {
+        let role = role.as_ref().to_owned();
+        let text = text.as_ref().to_owned();
+        let part = if role == "assistant" {
+            ContentPart::Text { text }
+        } else {
+            ContentPart::InputText { text }
+        };
+        ConversationItem::Message {
+            id: None,
+            role,
+            content: vec![part],
+        }
+    }
+}
+
+/// A part of the content of a [ConversationItem::Message].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    InputText {
+        text: String,
+    },
+    InputAudio {
+        /// Base64-encoded audio bytes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        audio: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        transcript: Option<String>,
+    },
+    Text {
+        text: String,
+    },
+    Audio {
+        /// Base64-encoded audio bytes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        audio: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        transcript: Option<String>,
+    },
+}
+
+/// An event sent by the server.
+///
+/// See <https://platform.openai.com/docs/api-reference/realtime-server-events>.
+#[derive(Debug, Clone)]
+pub enum ServerEvent {
+    /// `error`
+    Error(RealtimeError),
+    /// `session.created`
+    SessionCreated(Session),
+    /// `session.updated`
+    SessionUpdated(Session),
+    /// `conversation.item.created`
+    ConversationItemCreated(ConversationItem),
+    /// `input_audio_buffer.committed`
+    InputAudioBufferCommitted(InputAudioBufferEvent),
+    /// `input_audio_buffer.cleared`
+    InputAudioBufferCleared,
+    /// `input_audio_buffer.speech_started`
+    InputAudioBufferSpeechStarted(InputAudioBufferEvent),
+    /// `input_audio_buffer.speech_stopped`
+    InputAudioBufferSpeechStopped(InputAudioBufferEvent),
+    /// `response.created`
+    ResponseCreated(RealtimeResponse),
+    /// `response.done`
+    ResponseDone(RealtimeResponse),
+    /// `response.output_item.added`
+    ResponseOutputItemAdded(ConversationItem),
+    /// `response.output_item.done`
+    ResponseOutputItemDone(ConversationItem),
+    /// `response.text.delta`
+    ResponseTextDelta(ContentDelta),
+    /// `response.text.done`
+    ResponseTextDone(ContentDone),
+    /// `response.audio.delta`
+    ResponseAudioDelta(ContentDelta),
+    /// `response.audio.done`
+    ResponseAudioDone(ContentDone),
+    /// `response.audio_transcript.delta`
+    ResponseAudioTranscriptDelta(ContentDelta),
+    /// `response.audio_transcript.done`
+    ResponseAudioTranscriptDone(ContentDone),
+    /// `response.function_call_arguments.delta`
+    ResponseFunctionCallArgumentsDelta(FunctionCallArgumentsDelta),
+    /// `response.function_call_arguments.done`
+    ResponseFunctionCallArgumentsDone(RealtimeFunctionCall),
+    /// An event not known to this crate.
+    Unknown {
+        event_type: String,
+        data: serde_json::Value,
+    },
+}
+
+impl ServerEvent {
+    fn parse(text: &str) -> serde_json::Result<ServerEvent> {
+        use ServerEvent::*;
+        let data: serde_json::Value = serde_json::from_str(text)?;
+        let field = |name: &str| data.get(name).cloned().unwrap_or_default();
+        let event_type = data["type"].as_str().unwrap_or_default().to_owned();
+        Ok(match event_type.as_str() {
+            "error" => Error(serde_json::from_value(field("error"))?),
+            "session.created" => SessionCreated(serde_json::from_value(field("session"))?),
+            "session.updated" => SessionUpdated(serde_json::from_value(field("session"))?),
+            "conversation.item.created" => {
+                ConversationItemCreated(serde_json::from_value(field("item"))?)
+            }
+            "input_audio_buffer.committed" => {
+                InputAudioBufferCommitted(serde_json::from_value(data)?)
+            }
+            "input_audio_buffer.cleared" => InputAudioBufferCleared,
+            "input_audio_buffer.speech_started" => {
+                InputAudioBufferSpeechStarted(serde_json::from_value(data)?)
+            }
+            "input_audio_buffer.speech_stopped" => {
+                InputAudioBufferSpeechStopped(serde_json::from_value(data)?)
+            }
+            "response.created" => ResponseCreated(serde_json::from_value(field("response"))?),
+            "response.done" => ResponseDone(serde_json::from_value(field("response"))?),
+            "response.output_item.added" => {
+                ResponseOutputItemAdded(serde_json::from_value(field("item"))?)
+            }
+            "response.output_item.done" => {
+                ResponseOutputItemDone(serde_json::from_value(field("item"))?)
+            }
+            "response.text.delta" => ResponseTextDelta(serde_json::from_value(data)?),
+            "response.text.done" => ResponseTextDone(serde_json::from_value(data)?),
+            "response.audio.delta" => ResponseAudioDelta(serde_json::from_value(data)?),
+            "response.audio.done" => ResponseAudioDone(serde_json::from_value(data)?),
+            "response.audio_transcript.delta" => {
+                ResponseAudioTranscriptDelta(serde_json::from_value(data)?)
+            }
+            "response.audio_transcript.done" => {
+                ResponseAudioTranscriptDone(serde_json::from_value(data)?)
+            }
+            "response.function_call_arguments.delta" => {
+                ResponseFunctionCallArgumentsDelta(serde_json::from_value(data)?)
+            }
+            "response.function_call_arguments.done" => {
+                ResponseFunctionCallArgumentsDone(serde_json::from_value(data)?)
+            }
+            _ => Unknown { event_type, data },
+        })
+    }
+}
+
+/// An error sent by the server. The session stays open.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RealtimeError {
+    #[serde(rename = "type")]
+    pub error_type: String,
+    pub code: Option<String>,
+    pub message: String,
+    pub param: Option<String>,
+    /// The `event_id` of the client event that caused the error, if applicable.
+    pub event_id: Option<String>,
+}
+
+/// The current configuration of a realtime session.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Session {
+    pub id: String,
+    pub model: String,
+    #[serde(default)]
+    pub modalities: Vec<String>,
+    pub instructions: Option<String>,
+    pub voice: Option<String>,
+}
+
+/// A response generated by the model, made of output items.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RealtimeResponse {
+    pub id: String,
+    /// `in_progress`, `completed`, `cancelled`, `failed` or `incomplete`.
+    pub status: String,
+    #[serde(default)]
+    pub output: Vec<ConversationItem>,
+    pub usage: Option<serde_json::Value>,
+}
+
+/// A chunk of text, audio or audio transcript of a content part.
+///
+/// It implements [Display](std::fmt::Display) as a shortcut to easily extract the delta.
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"event_id\": \"event_4142\",
+/// #   \"type\": \"response.text.delta\",
+/// #   \"response_id\": \"resp_001\",
+/// #   \"item_id\": \"msg_007\",
+/// #   \"output_index\": 0,
+/// #   \"content_index\": 0,
+/// #   \"delta\": \"Sure, I can h\"
+/// # }";
+/// # let delta = serde_json::from_str::<openai_rust2::realtime::ContentDelta>(json).unwrap();
+/// print!("{}", delta);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct ContentDelta {
+    pub response_id: String,
+    pub item_id: String,
+    pub output_index: u32,
+    pub content_index: u32,
+    /// The text, or the base64-encoded audio.
+    pub delta: String,
+}
+
+impl std::fmt::Display for ContentDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.delta)?;
+        Ok(())
+    }
+}
+
+impl ContentDelta {
+    /// Decode the base64 audio of a `response.audio.delta` event.
+    pub fn audio_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(base64::engine::general_purpose::STANDARD.decode(&self.delta)?)
+    }
+}
+
+/// The end of a content part.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ContentDone {
+    pub response_id: String,
+    pub item_id: String,
+    pub output_index: u32,
+    pub content_index: u32,
+    /// The final text of a `response.text.done` event.
+    pub text: Option<String>,
+    /// The final transcript of a `response.audio_transcript.done` event.
+    pub transcript: Option<String>,
+}
+
+/// A chunk of the arguments of a function call.
+#[derive(Deserialize, Debug, Clone)]
+pub struct FunctionCallArgumentsDelta {
+    pub response_id: String,
+    pub item_id: String,
+    pub output_index: u32,
+    pub call_id: String,
+    pub delta: String,
+}
+
+/// A function call requested by the model, with its complete arguments.
+///
+/// ```
+/// # let json = "{
+/// #   \"event_id\": \"event_5354\",
+/// #   \"type\": \"response.function_call_arguments.done\",
+/// #   \"response_id\": \"resp_002\",
+/// #   \"item_id\": \"fc_001\",
+/// #   \"output_index\": 0,
+/// #   \"call_id\": \"call_001\",
+/// #   \"name\": \"get_weather\",
+/// #   \"arguments\": \"{\\\"location\\\": \\\"Paris\\\"}\"
+/// # }";
+/// # let call = serde_json::from_str::<openai_rust2::realtime::RealtimeFunctionCall>(json).unwrap();
+/// #[derive(serde::Deserialize)]
+/// struct Location {
+///     location: String,
+/// }
+/// let args: Location = call.parse_arguments().unwrap();
+/// let events = [
+///     call.output(format!("It is sunny in {}", args.location)),
+///     openai_rust2::realtime::ClientEvent::ResponseCreate { response: None },
+/// ];
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct RealtimeFunctionCall {
+    pub response_id: String,
+    pub item_id: String,
+    pub output_index: u32,
+    pub call_id: String,
+    /// The name of the function, if sent by the server.
+    /// It is also available in the [ConversationItem::FunctionCall] of `response.output_item.added`.
+    pub name: Option<String>,
+    /// A JSON string of the arguments to pass to the function.
+    pub arguments: String,
+}
+
+impl RealtimeFunctionCall {
+    /// Deserialize the arguments.
+    pub fn parse_arguments<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_str(&self.arguments)
+    }
+
+    /// The `conversation.item.create` event answering this call with `output`.
+    pub fn output(&self, output: impl AsRef<str>) -> ClientEvent {
+        ClientEvent::function_call_output(&self.call_id, output)
+    }
+}
+
+/// A change of the input audio buffer.
+#[derive(Deserialize, Debug, Clone)]
+pub struct InputAudioBufferEvent {
+    /// The ID of the user message item created, or to be created, from the buffer.
+    pub item_id: String,
+    /// The ID of the item after which the user message item will be inserted.
+    pub previous_item_id: Option<String>,
+    /// Milliseconds from the start of the buffer at which speech was detected.
+    pub audio_start_ms: Option<u64>,
+    /// Milliseconds from the start of the buffer at which speech stopped.
+    pub audio_end_ms: Option<u64>,
+}
+
+/// Reassembles the audio of `response.audio.delta` events.
+///
+/// Use one assembler per response audio.
+/// [AudioAssembler::push] returns the new audio as chunks aligned on whole samples,
+/// ready to be fed to an audio output, and the whole audio is kept for [AudioAssembler::into_wav].
+///
+/// ```
+/// # use openai_rust2::realtime::ContentDelta;
+/// # let delta = |audio: &str| serde_json::from_value::<ContentDelta>(serde_json::json!({
+/// #     "response_id": "resp_001", "item_id": "msg_007", "output_index": 0, "content_index": 0, "delta": audio
+/// # })).unwrap();
+/// use openai_rust2::realtime::AudioAssembler;
+/// let mut assembler = AudioAssembler::pcm16();
+/// // Three bytes: the second sample is incomplete
+/// assert_eq!(&assembler.push(&delta("AAEC")).unwrap()[..], &[0, 1]);
+/// assert_eq!(&assembler.push(&delta("Aw==")).unwrap()[..], &[2, 3]);
+/// let wav = assembler.into_wav(24000);
+/// assert_eq!(wav.len(), 44 + 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AudioAssembler {
+    sample_size: usize,
+    audio: Vec<u8>,
+    // bytes of audio already returned by push
+    emitted: usize,
+}
+
+impl AudioAssembler {
+    /// An assembler for audio made of samples of `sample_size` bytes.
+    pub fn new(sample_size: usize) -> AudioAssembler {
+        AudioAssembler {
+            sample_size: sample_size.max(1),
+            audio: vec![],
+            emitted: 0,
+        }
+    }
+
+    /// An assembler for the `pcm16` format: 16-bit PCM at 24kHz, mono, little-endian.
+    pub fn pcm16() -> AudioAssembler {
+        AudioAssembler::new(2)
+    }
+
+    /// An assembler for the `g711_ulaw` and `g711_alaw` formats.
+    pub fn g711() -> AudioAssembler {
+        AudioAssembler::new(1)
+    }
+
+    /// Decode a delta and return the audio that is now complete.
+    /// The returned chunk may be empty if the delta didn't complete a sample.
+    pub fn push(&mut self, delta: &ContentDelta) -> anyhow::Result<Bytes> {
+        self.audio.extend(delta.audio_bytes()?);
+        let complete = self.audio.len() - self.audio.len() % self.sample_size;
+        let chunk = Bytes::copy_from_slice(&self.audio[self.emitted..complete]);
+        self.emitted = complete;
+        Ok(chunk)
+    }
+
+    /// All the audio received so far.
+    pub fn audio(&self) -> &[u8] {
+        &self.audio
+    }
+
+    /// Wrap the `pcm16` audio received in a mono WAV file with the given sample rate (24000 for the Realtime API).
+    pub fn into_wav(self, sample_rate: u32) -> Vec<u8> {
+        let len = (self.audio.len() - self.audio.len() % 2) as u32;
+        let mut wav = Vec::with_capacity(44 + len as usize);
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + len).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes()); // size of the fmt chunk
+        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        wav.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // byte rate
+        wav.extend_from_slice(&2u16.to_le_bytes()); // block align
+        wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&len.to_le_bytes());
+        wav.extend_from_slice(&self.audio[..len as usize]);
+        wav
+    }
+}
+
+/// A WebSocket session with the Realtime API, returned by [crate::Client::connect_realtime].
+pub struct RealtimeSession {
+    sender: RealtimeSender,
+    receiver: RealtimeReceiver,
+}
+
+impl RealtimeSession {
+    pub(crate) fn new(socket: WebSocket) -> Self {
+        let (sink, stream) = socket.split();
+        Self {
+            sender: RealtimeSender { sink },
+            receiver: RealtimeReceiver { stream },
+        }
+    }
+
+    /// Split the session to send and receive events concurrently.
+    pub fn split(self) -> (RealtimeSender, RealtimeReceiver) {
+        (self.sender, self.receiver)
+    }
+
+    /// Send an event to the server.
+    pub async fn send(&mut self, event: ClientEvent) -> anyhow::Result<()> {
+        self.sender.send(event).await
+    }
+
+    /// Close the WebSocket connection.
+    pub async fn close(&mut self) -> anyhow::Result<()> {
+        self.sender.close().await
+    }
+}
+
+impl Stream for RealtimeSession {
+    type Item = anyhow::Result<ServerEvent>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+/// The sending half of a [RealtimeSession].
+pub struct RealtimeSender {
+    sink: SplitSink<WebSocket, Message>,
+}
+
+impl RealtimeSender {
+    /// Send an event to the server.
+    pub async fn send(&mut self, event: ClientEvent) -> anyhow::Result<()> {
+        let text = serde_json::to_string(&event)?;
+        self.sink.send(Message::Text(text.into())).await?;
+        Ok(())
+    }
+
+    /// Close the WebSocket connection.
+    pub async fn close(&mut self) -> anyhow::Result<()> {
+        self.sink.close().await?;
+        Ok(())
+    }
+}
+
+/// The receiving half of a [RealtimeSession], a stream of [ServerEvent]s.
+pub struct RealtimeReceiver {
+    stream: SplitStream<WebSocket>,
+}
+
+impl Stream for RealtimeReceiver {
+    type Item = anyhow::Result<ServerEvent>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(Ok(Message::Text(text)))) => {
+                    return Poll::Ready(Some(
+                        ServerEvent::parse(&text).map_err(|e| anyhow::anyhow!(e)),
+                    ))
+                }
+                Poll::Ready(Some(Ok(Message::Close(_)))) | Poll::Ready(None) => {
+                    return Poll::Ready(None)
+                }
+                // Pings are answered by tungstenite
+                Poll::Ready(Some(Ok(_))) => continue,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e.into()))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,590 @@
+//! See <https://platform.openai.com/docs/api-reference/responses>.
+//! Use with [Client::create_response](crate::Client::create_response),
+//! [Client::create_response_stream](crate::Client::create_response_stream),
+//! [Client::retrieve_response](crate::Client::retrieve_response),
+//! [Client::delete_response](crate::Client::delete_response),
+//! [Client::cancel_response](crate::Client::cancel_response)
+//! or [Client::wait_for_response](crate::Client::wait_for_response).
+//!
+//! Responses are stored by default, so a conversation can be continued
+//! by passing the ID of the last response as [ResponseArguments::previous_response_id]
+//! instead of resending the whole history:
+//! ```no_run
+//! # use openai_rust2 as openai_rust;
+//! # use tokio_test;
+//! # tokio_test::block_on(async {
+//! # let c = openai_rust::Client::new("");
+//! use openai_rust::responses::ResponseArguments;
+//! let first = c.create_response(ResponseArguments::new("gpt-4.1", "Tell me a joke."), None).await.unwrap();
+//! let mut args = ResponseArguments::new("gpt-4.1", "Explain why it is funny.");
+//! args.previous_response_id = Some(first.id);
+//! println!("{}", c.create_response(args, None).await.unwrap());
+//! # })
+//! ```
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::vector_stores::RankingOptions;
+
+pub mod stream;
+
+/// Request arguments for creating a model response.
+///
+/// See <https://platform.openai.com/docs/api-reference/responses/create>.
+///
+/// ```
+/// let mut args = openai_rust2::responses::ResponseArguments::new(
+///     "gpt-4.1",
+///     "Tell me a three sentence bedtime story about a unicorn.",
+/// );
+/// args.instructions = Some("Talk like a pirate.".to_owned());
+/// args.tools.push(openai_rust2::responses::ResponseTool::web_search());
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct ResponseArguments {
+    /// Model ID used to generate the response, like `gpt-4o` or `o3`.
+    pub model: String,
+
+    /// Text, image, or file inputs to the model, used to generate a response.
+    pub input: ResponseInput,
+
+    /// A system (or developer) message inserted into the model's context.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// An upper bound for the number of tokens that can be generated for a response, including visible output tokens and reasoning tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+
+    /// What sampling temperature to use, between 0 and 2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// The ID of a previous response, to continue the conversation it belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_response_id: Option<String>,
+
+    /// Whether to store the generated response so it can be retrieved later. Defaults to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<bool>,
+
+    /// Whether to run the model response in the background.
+    /// The request then returns immediately with a `queued` response,
+    /// to be polled with [crate::Client::retrieve_response] or [crate::Client::wait_for_response].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<bool>,
+
+    /// An array of tools the model may call while generating a response.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<ResponseTool>,
+
+    /// Additional output data to include in the response, such as `file_search_call.results`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+
+    /// Whether to stream back the response as server-sent events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stream: Option<bool>,
+}
+
+impl ResponseArguments {
+    pub fn new(model: impl AsRef<str>, input: impl Into<ResponseInput>) -> ResponseArguments {
+        ResponseArguments {
+            model: model.as_ref().to_owned(),
+            input: input.into(),
+            instructions: None,
+            max_output_tokens: None,
+            temperature: None,
+            top_p: None,
+            metadata: None,
+            user: None,
+            previous_response_id: None,
+            store: None,
+            background: None,
+            tools: vec![],
+            include: vec![],
+            stream: None,
+        }
+    }
+}
+
+/// A tool the model may use to generate a response.
+///
+/// See <https://platform.openai.com/docs/guides/tools>.
+///
+/// ```
+/// use openai_rust2::responses::ResponseTool;
+/// let tools = vec![
+///     ResponseTool::web_search(),
+///     ResponseTool::file_search(vec!["vs_abc123".to_owned()]),
+/// ];
+/// ```
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseTool {
+    /// Search the Internet for sources related to the prompt.
+    WebSearch {
+        /// How much context to retrieve from the web, `low`, `medium` or `high`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        search_context_size: Option<String>,
+        /// The approximate location of the user, used to refine the search results.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        user_location: Option<UserLocation>,
+    },
+    /// Search the contents of uploaded files.
+    FileSearch {
+        /// The IDs of the vector stores to search.
+        vector_store_ids: Vec<String>,
+        /// The maximum number of results to return, between 1 and 50.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_num_results: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ranking_options: Option<RankingOptions>,
+        /// A filter to apply based on file attributes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filters: Option<serde_json::Value>,
+    },
+    /// A function defined by you, called with the arguments generated by the model.
+    Function {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// The parameters the function accepts, described as a JSON Schema object.
+        parameters: serde_json::Value,
+        /// Whether to enforce strict parameter validation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        strict: Option<bool>,
+    },
+}
+
+impl ResponseTool {
+    /// The `web_search` tool with its default settings.
+    pub fn web_search() -> ResponseTool {
+        ResponseTool::WebSearch {
+            search_context_size: None,
+            user_location: None,
+        }
+    }
+
+    /// The `file_search` tool, searching the given vector stores.
+    pub fn file_search(vector_store_ids: Vec<String>) -> ResponseTool {
+        ResponseTool::FileSearch {
+            vector_store_ids,
+            max_num_results: None,
+            ranking_options: None,
+            filters: None,
+        }
+    }
+}
+
+/// The approximate location of the user of a [ResponseTool::WebSearch].
+#[derive(Serialize, Debug, Clone)]
+pub struct UserLocation {
+    /// Always `approximate`.
+    #[serde(rename = "type")]
+    pub location_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// The two-letter [ISO country code](https://en.wikipedia.org/wiki/ISO_3166-1) of the user, e.g. `US`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// The [IANA timezone](https://timeapi.io/documentation/iana-timezones) of the user, e.g. `America/Los_Angeles`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+impl Default for UserLocation {
+    fn default() -> Self {
+        UserLocation {
+            location_type: "approximate".to_owned(),
+            city: None,
+            country: None,
+            region: None,
+            timezone: None,
+        }
+    }
+}
+
+/// The input of a response, either a plain text or a list of [InputItem]s.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ResponseInput {
+    Text(String),
+    Items(Vec<InputItem>),
+}
+
+impl From<&str> for ResponseInput {
+    fn from(text: &str) -> Self {
+        ResponseInput::Text(text.to_owned())
+    }
+}
+
+impl From<String> for ResponseInput {
+    fn from(text: String) -> Self {
+        ResponseInput::Text(text)
+    }
+}
+
+impl From<Vec<InputItem>> for ResponseInput {
+    fn from(items: Vec<InputItem>) -> Self {
+        ResponseInput::Items(items)
+    }
+}
+
+/// An item of the input given to the model.
+///
+/// ```
+/// use openai_rust2::responses::{InputContentPart, InputItem};
+/// let items = vec![
+///     InputItem::message("developer", "Only answer in French."),
+///     InputItem::message_parts("user", vec![
+///         InputContentPart::InputText { text: "What is in this image?".to_owned() },
+///         InputContentPart::InputImage {
+///             image_url: Some("https://example.com/cat.png".to_owned()),
+///             file_id: None,
+///             detail: None,
+///         },
+///     ]),
+/// ];
+/// ```
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InputItem {
+    /// A message with a role of `user`, `assistant`, `system` or `developer`.
+    Message { role: String, content: InputContent },
+    /// A function tool call previously made by the model.
+    FunctionCall {
+        call_id: String,
+        name: String,
+        arguments: String,
+    },
+    /// The output of a function tool call.
+    FunctionCallOutput { call_id: String, output: String },
+    /// A reference to an existing item, by its ID.
+    ItemReference { id: String },
+}
+
+impl InputItem {
+    /// A text message.
+    pub fn message(role: impl AsRef<str>, text: impl AsRef<str>) -> InputItem {
+        InputItem::Message {
+            role: role.as_ref().to_owned(),
+            content: InputContent::Text(text.as_ref().to_owned()),
+        }
+    }
+
+    /// A message made of multiple content parts, such as text and images.
+    pub fn message_parts(role: impl AsRef<str>, parts: Vec<InputContentPart>) -> InputItem {
+        InputItem::Message {
+            role: role.as_ref().to_owned(),
+            content: InputContent::Parts(parts),
+        }
+    }
+
+    /// The output of the function call identified by `call_id`.
+    pub fn function_call_output(call_id: impl AsRef<str>, output: impl AsRef<str>) -> InputItem {
+        InputItem::FunctionCallOutput {
+            call_id: call_id.as_ref().to_owned(),
+            output: output.as_ref().to_owned(),
+        }
+    }
+}
+
+/// The content of an input message.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum InputContent {
+    Text(String),
+    Parts(Vec<InputContentPart>),
+}
+
+/// A part of the content of an input message.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InputContentPart {
+    InputText {
+        text: String,
+    },
+    InputImage {
+        /// A fully qualified URL or base64 encoded data URL.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        image_url: Option<String>,
+        /// The ID of an uploaded file.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_id: Option<String>,
+        /// One of `high`, `low`, or `auto`. Defaults to `auto`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        detail: Option<String>,
+    },
+    InputFile {
+        /// The ID of an uploaded file.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+        /// The base64 encoded content of the file.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_data: Option<String>,
+    },
+}
+
+/// The status of a [Response].
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseStatus {
+    Queued,
+    InProgress,
+    Completed,
+    Incomplete,
+    Failed,
+    Cancelled,
+}
+
+impl ResponseStatus {
+    /// Whether the response has stopped and will not change status anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ResponseStatus::Completed
+                | ResponseStatus::Incomplete
+                | ResponseStatus::Failed
+                | ResponseStatus::Cancelled
+        )
+    }
+}
+
+/// A model response.
+///
+/// It implements [Display](std::fmt::Display) as a shortcut to easily extract the output text.
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"id\": \"resp_67ccd2bed1ec8190b14f964abc0542670bb6a6b452d3795b\",
+/// #   \"object\": \"response\",
+/// #   \"created_at\": 1741476542,
+/// #   \"status\": \"completed\",
+/// #   \"error\": null,
+/// #   \"incomplete_details\": null,
+/// #   \"model\": \"gpt-4.1-2025-04-14\",
+/// #   \"output\": [
+/// #     {
+/// #       \"type\": \"web_search_call\",
+/// #       \"id\": \"ws_67ccd2bf17f0819081ff3bb2cf6508e6\",
+/// #       \"status\": \"completed\"
+/// #     },
+/// #     {
+/// #       \"type\": \"message\",
+/// #       \"id\": \"msg_67ccd2bf17f0819081ff3bb2cf6508e60bb6a6b452d3795b\",
+/// #       \"status\": \"completed\",
+/// #       \"role\": \"assistant\",
+/// #       \"content\": [
+/// #         {
+/// #           \"type\": \"output_text\",
+/// #           \"text\": \"In a peaceful grove beneath a silver moon, a unicorn named Lumina discovered a hidden pool.\",
+/// #           \"annotations\": [
+/// #             {
+/// #               \"type\": \"url_citation\",
+/// #               \"start_index\": 0,
+/// #               \"end_index\": 10,
+/// #               \"url\": \"https://example.com/unicorns\",
+/// #               \"title\": \"Unicorns\"
+/// #             }
+/// #           ]
+/// #         }
+/// #       ]
+/// #     }
+/// #   ],
+/// #   \"usage\": {
+/// #     \"input_tokens\": 36,
+/// #     \"output_tokens\": 87,
+/// #     \"total_tokens\": 123
+/// #   },
+/// #   \"metadata\": {}
+/// # }";
+/// # let res = serde_json::from_str::<openai_rust2::responses::Response>(json).unwrap();
+/// let text = res.output_text();
+/// // or
+/// let text = res.to_string();
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Response {
+    pub id: String,
+    pub created_at: u64,
+    pub status: ResponseStatus,
+    pub model: String,
+    /// The content items generated by the model.
+    pub output: Vec<OutputItem>,
+    pub error: Option<ResponseError>,
+    pub incomplete_details: Option<IncompleteDetails>,
+    pub usage: Option<ResponseUsage>,
+    pub previous_response_id: Option<String>,
+    /// Whether the response was run in the background.
+    pub background: Option<bool>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+impl Response {
+    /// The concatenated text of all the `output_text` parts of the output messages.
+    pub fn output_text(&self) -> String {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                OutputItem::Message { content, .. } => Some(content),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|content| match content {
+                OutputContent::OutputText { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.output_text())?;
+        Ok(())
+    }
+}
+
+/// The response of a response deletion request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteResponseResponse {
+    pub id: String,
+    pub deleted: bool,
+}
+
+/// An item generated by the model.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputItem {
+    Message {
+        id: String,
+        role: String,
+        status: Option<String>,
+        content: Vec<OutputContent>,
+    },
+    /// A call to a function tool. Answer it with [InputItem::function_call_output].
+    FunctionCall {
+        id: Option<String>,
+        call_id: String,
+        name: String,
+        /// A JSON string of the arguments to pass to the function.
+        arguments: String,
+        status: Option<String>,
+    },
+    /// A search of the web by the [ResponseTool::WebSearch] tool.
+    WebSearchCall {
+        id: String,
+        status: String,
+        /// The action taken by the tool, such as a search query.
+        action: Option<serde_json::Value>,
+    },
+    /// A search of files by the [ResponseTool::FileSearch] tool.
+    FileSearchCall {
+        id: String,
+        status: String,
+        /// The queries used to search for files.
+        #[serde(default)]
+        queries: Vec<String>,
+        /// Only included when `file_search_call.results` is in [ResponseArguments::include].
+        results: Option<Vec<FileSearchResult>>,
+    },
+    /// The chain of thought of a reasoning model.
+    Reasoning {
+        id: String,
+        #[serde(default)]
+        summary: Vec<serde_json::Value>,
+    },
+    /// An output item not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A part of the content of an output message.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputContent {
+    OutputText {
+        text: String,
+        #[serde(default)]
+        annotations: Vec<OutputAnnotation>,
+    },
+    Refusal {
+        refusal: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// A citation in an output text, added by the built-in tools.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputAnnotation {
+    /// A citation of a web page found by [ResponseTool::WebSearch].
+    UrlCitation {
+        url: String,
+        title: String,
+        start_index: u32,
+        end_index: u32,
+    },
+    /// A citation of a file found by [ResponseTool::FileSearch].
+    FileCitation {
+        file_id: String,
+        filename: Option<String>,
+        index: Option<u32>,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// A file chunk found by [ResponseTool::FileSearch].
+#[derive(Deserialize, Debug, Clone)]
+pub struct FileSearchResult {
+    pub file_id: String,
+    pub filename: String,
+    /// The relevance score of the file, between 0 and 1.
+    pub score: f32,
+    /// The text that was retrieved from the file.
+    pub text: String,
+    #[serde(default)]
+    pub attributes: HashMap<String, serde_json::Value>,
+}
+
+/// The error returned when the model fails to generate a response.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ResponseError {
+    pub code: String,
+    pub message: String,
+}
+
+/// Details about why a response is incomplete.
+#[derive(Deserialize, Debug, Clone)]
+pub struct IncompleteDetails {
+    pub reason: String,
+}
+
+/// Token usage of a [Response].
+#[derive(Deserialize, Debug, Clone)]
+pub struct ResponseUsage {
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub total_tokens: u32,
+}
@@ -0,0 +1,233 @@
+//! Structs and deserialization method for the events
+//! received when streaming responses.
+//!
+//! See <https://platform.openai.com/docs/api-reference/responses-streaming>.
+use futures_util::Stream;
+use serde::Deserialize;
+use std::pin::Pin;
+use std::task::Poll;
+
+use super::{OutputContent, OutputItem, Response};
+
+/// An event received while streaming a [Response].
+///
+/// ```
+/// # use futures_util::StreamExt;
+/// # async fn example(mut stream: openai_rust2::responses::stream::ResponseEventStream) {
+/// use openai_rust2::responses::stream::ResponseStreamEvent;
+/// while let Some(event) = stream.next().await {
+///     match event.unwrap() {
+///         ResponseStreamEvent::OutputTextDelta(delta) => print!("{}", delta),
+///         ResponseStreamEvent::Completed(res) => println!("\n{} completed", res.id),
+///         _ => {}
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub enum ResponseStreamEvent {
+    /// `response.created`
+    Created(Response),
+    /// `response.queued`
+    Queued(Response),
+    /// `response.in_progress`
+    InProgress(Response),
+    /// `response.completed`
+    Completed(Response),
+    /// `response.incomplete`
+    Incomplete(Response),
+    /// `response.failed`
+    Failed(Response),
+    /// `response.output_item.added`
+    OutputItemAdded(OutputItemEvent),
+    /// `response.output_item.done`
+    OutputItemDone(OutputItemEvent),
+    /// `response.content_part.added`
+    ContentPartAdded(ContentPartEvent),
+    /// `response.content_part.done`
+    ContentPartDone(ContentPartEvent),
+    /// `response.output_text.delta`
+    OutputTextDelta(TextDelta),
+    /// `response.output_text.done`
+    OutputTextDone(TextDone),
+    /// `response.refusal.delta`
+    RefusalDelta(TextDelta),
+    /// `response.refusal.done`
+    RefusalDone(RefusalDone),
+    /// `response.function_call_arguments.delta`
+    FunctionCallArgumentsDelta(TextDelta),
+    /// `response.function_call_arguments.done`
+    FunctionCallArgumentsDone(FunctionCallArgumentsDone),
+    /// `error`
+    Error(ResponseStreamError),
+    /// An event not known to this crate.
+    Unknown { event: String, data: String },
+}
+
+/// The payload of the events carrying a whole [Response].
+#[derive(Deserialize)]
+struct ResponseEvent {
+    response: Response,
+}
+
+impl ResponseStreamEvent {
+    fn parse(event: &str, data: &str) -> serde_json::Result<ResponseStreamEvent> {
+        use ResponseStreamEvent::*;
+        let response = |data| serde_json::from_str::<ResponseEvent>(data).map(|e| e.response);
+        Ok(match event {
+            "response.created" => Created(response(data)?),
+            "response.queued" => Queued(response(data)?),
+            "response.in_progress" => InProgress(response(data)?),
+            "response.completed" => Completed(response(data)?),
+            "response.incomplete" => Incomplete(response(data)?),
+            "response.failed" => Failed(response(data)?),
+            "response.output_item.added" => OutputItemAdded(serde_json::from_str(data)?),
+            "response.output_item.done" => OutputItemDone(serde_json::from_str(data)?),
+            "response.content_part.added" => ContentPartAdded(serde_json::from_str(data)?),
+            "response.content_part.done" => ContentPartDone(serde_json::from_str(data)?),
+            "response.output_text.delta" => OutputTextDelta(serde_json::from_str(data)?),
+            "response.output_text.done" => OutputTextDone(serde_json::from_str(data)?),
+            "response.refusal.delta" => RefusalDelta(serde_json::from_str(data)?),
+            "response.refusal.done" => RefusalDone(serde_json::from_str(data)?),
+            "response.function_call_arguments.delta" => {
+                FunctionCallArgumentsDelta(serde_json::from_str(data)?)
+            }
+            "response.function_call_arguments.done" => {
+                FunctionCallArgumentsDone(serde_json::from_str(data)?)
+            }
+            "error" => Error(serde_json::from_str(data)?),
+            _ => Unknown {
+                event: event.to_owned(),
+                data: data.to_owned(),
+            },
+        })
+    }
+}
+
+/// An output item that was added or completed.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OutputItemEvent {
+    pub output_index: u32,
+    pub item: OutputItem,
+}
+
+/// A content part of an output message that was added or completed.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ContentPartEvent {
+    pub item_id: String,
+    pub output_index: u32,
+    pub content_index: u32,
+    pub part: OutputContent,
+}
+
+/// A chunk of text, refusal or function call arguments.
+///
+/// It implements [Display](std::fmt::Display) as a shortcut to easily extract the delta.
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"type\": \"response.output_text.delta\",
+/// #   \"item_id\": \"msg_123\",
+/// #   \"output_index\": 0,
+/// #   \"content_index\": 0,
+/// #   \"delta\": \"In\",
+/// #   \"sequence_number\": 1
+/// # }";
+/// # let delta = serde_json::from_str::<openai_rust2::responses::stream::TextDelta>(json).unwrap();
+/// print!("{}", delta);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct TextDelta {
+    pub item_id: String,
+    pub output_index: u32,
+    /// Absent for function call arguments.
+    pub content_index: Option<u32>,
+    pub delta: String,
+}
+
+impl std::fmt::Display for TextDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.delta)?;
+        Ok(())
+    }
+}
+
+/// The finalized text of an output text part.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TextDone {
+    pub item_id: String,
+    pub output_index: u32,
+    pub content_index: u32,
+    pub text: String,
+}
+
+/// The finalized refusal of a refusal part.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RefusalDone {
+    pub item_id: String,
+    pub output_index: u32,
+    pub content_index: u32,
+    pub refusal: String,
+}
+
+/// The finalized arguments of a function call.
+#[derive(Deserialize, Debug, Clone)]
+pub struct FunctionCallArgumentsDone {
+    pub item_id: String,
+    pub output_index: u32,
+    pub arguments: String,
+}
+
+/// An error that occurred while streaming.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ResponseStreamError {
+    pub code: Option<String>,
+    pub message: String,
+    pub param: Option<String>,
+}
+
+/// The type of an event, for servers that don't send the `event:` field.
+#[derive(Deserialize)]
+struct EventType {
+    #[serde(rename = "type")]
+    event_type: String,
+}
+
+/// A stream of [ResponseStreamEvent]s returned by [crate::Client::create_response_stream].
+pub struct ResponseEventStream {
+    event_stream: crate::sse::EventStream,
+}
+
+impl ResponseEventStream {
+    pub(crate) fn new(stream: crate::sse::ByteStream) -> Self {
+        Self {
+            event_stream: crate::sse::EventStream::new(stream),
+        }
+    }
+}
+
+impl Stream for ResponseEventStream {
+    type Item = anyhow::Result<ResponseStreamEvent>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        match Pin::new(&mut self.event_stream).poll_next(cx) {
+            Poll::Ready(Some(Ok(event))) => {
+                let name = match event.event {
+                    Some(name) => name,
+                    None => serde_json::from_str::<EventType>(&event.data)
+                        .map(|t| t.event_type)
+                        .unwrap_or_default(),
+                };
+                Poll::Ready(Some(
+                    ResponseStreamEvent::parse(&name, &event.data).map_err(|e| anyhow::anyhow!(e)),
+                ))
+            }
+            Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
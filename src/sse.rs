@@ -0,0 +1,294 @@
+//! Parsing of [server-sent events](https://html.spec.whatwg.org/multipage/server-sent-events.html),
+//! shared by the streaming endpoints.
+//!
+//! Use [Client::create_event_stream](crate::Client::create_event_stream) or [EventStream::from_response]
+//! to consume a streaming endpoint this crate has no types for yet.
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::Duration;
+
+/// The raw body of a streaming response.
+pub(crate) type ByteStream = Pin<Box<dyn Stream<Item = anyhow::Result<Bytes>> + Send>>;
+
+/// The body of a streaming response, which fails if no data arrives within `idle_timeout`.
+pub(crate) fn byte_stream(res: reqwest::Response, idle_timeout: Option<Duration>) -> ByteStream {
+    let stream = res.bytes_stream().map(|bytes| bytes.map_err(Into::into));
+    match idle_timeout {
+        Some(timeout) => Box::pin(IdleTimeout {
+            byte_stream: Box::pin(stream),
+            timeout,
+            sleep: Box::pin(tokio::time::sleep(timeout)),
+            timed_out: false,
+        }),
+        None => Box::pin(stream),
+    }
+}
+
+/// The error of a stream that received no data for longer than the
+/// [idle timeout](crate::Client::with_stream_idle_timeout).
+///
+/// It can be recovered with [anyhow::Error::downcast_ref].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleTimeoutError {
+    pub timeout: Duration,
+}
+
+impl fmt::Display for IdleTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no data received from the stream for {:?}", self.timeout)
+    }
+}
+
+impl std::error::Error for IdleTimeoutError {}
+
+/// How a chat stream recovers when its connection drops mid-generation,
+/// see [Client::with_stream_retry](crate::Client::with_stream_retry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamRetryPolicy {
+    /// How many times the request may be sent again over the whole stream.
+    pub max_retries: u32,
+    /// The delay before the first retry, doubled for each of the next ones.
+    pub backoff: Duration,
+}
+
+impl Default for StreamRetryPolicy {
+    fn default() -> Self {
+        StreamRetryPolicy {
+            max_retries: 3,
+            backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Ends a byte stream with an [IdleTimeoutError] when it stalls.
+struct IdleTimeout {
+    byte_stream: ByteStream,
+    timeout: Duration,
+    sleep: Pin<Box<tokio::time::Sleep>>,
+    timed_out: bool,
+}
+
+impl Stream for IdleTimeout {
+    type Item = anyhow::Result<Bytes>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        if self.timed_out {
+            return Poll::Ready(None);
+        }
+        match self.byte_stream.as_mut().poll_next(cx) {
+            Poll::Ready(item) => {
+                let deadline = tokio::time::Instant::now() + self.timeout;
+                self.sleep.as_mut().reset(deadline);
+                Poll::Ready(item)
+            }
+            Poll::Pending => match self.sleep.as_mut().poll(cx) {
+                Poll::Ready(()) => {
+                    self.timed_out = true;
+                    let timeout = self.timeout;
+                    Poll::Ready(Some(Err(IdleTimeoutError { timeout }.into())))
+                }
+                Poll::Pending => Poll::Pending,
+            },
+        }
+    }
+}
+
+/// A single server-sent event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// The name of the event, if the server sent one.
+    pub event: Option<String>,
+    /// The payload of the event.
+    pub data: String,
+}
+
+/// Splits a byte stream into [Event]s.
+///
+/// Lines may end with `\n`, `\r\n` or `\r`, and the network chunks can split lines and
+/// even UTF-8 sequences anywhere, so the bytes are buffered until a line is complete.
+///
+/// ```
+/// # use tokio_test;
+/// # tokio_test::block_on(async {
+/// # use openai_rust2 as openai_rust;
+/// use openai_rust::futures_util::{stream, StreamExt};
+/// use openai_rust::sse::EventStream;
+/// let chunks = stream::iter([
+///     Ok(": keep-alive\r\n\r\nevent: greeting\r\ndata: Hel".into()),
+///     Ok("lo\r\ndata: World\r\n\r\n".into()),
+/// ]);
+/// let events = EventStream::from_byte_stream(chunks)
+///     .map(|event| event.unwrap())
+///     .collect::<Vec<_>>()
+///     .await;
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(events[0].event.as_deref(), Some("greeting"));
+/// assert_eq!(events[0].data, "Hello\nWorld");
+/// # })
+/// ```
+pub struct EventStream {
+    byte_stream: ByteStream,
+    // bytes not yet split into lines
+    buf: Vec<u8>,
+    // fields of the event being parsed
+    event: Option<String>,
+    data: Option<String>,
+    // the byte stream has ended
+    eof: bool,
+}
+
+impl EventStream {
+    pub(crate) fn new(stream: ByteStream) -> Self {
+        Self {
+            byte_stream: stream,
+            buf: Vec::new(),
+            event: None,
+            data: None,
+            eof: false,
+        }
+    }
+
+    /// Parses the events of any byte stream.
+    pub fn from_byte_stream<S>(stream: S) -> Self
+    where
+        S: Stream<Item = reqwest::Result<Bytes>> + Send + 'static,
+    {
+        Self::new(Box::pin(stream.map(|bytes| bytes.map_err(Into::into))))
+    }
+
+    /// Parses the events of a streaming response, which should have been checked for errors.
+    pub fn from_response(res: reqwest::Response) -> Self {
+        Self::new(byte_stream(res, None))
+    }
+
+    /// If possible, removes the first complete line from the buffer.
+    fn take_line(&mut self) -> Option<Vec<u8>> {
+        let Some(end) = self.buf.iter().position(|b| *b == b'\n' || *b == b'\r') else {
+            // A last line without line ending was cut short
+            if self.eof {
+                self.buf.clear();
+            }
+            return None;
+        };
+        let len = match (self.buf[end], self.buf.get(end + 1)) {
+            (b'\r', Some(b'\n')) => 2,
+            // A \r at the end of the buffer may be followed by a \n in the next chunk
+            (b'\r', None) if !self.eof => return None,
+            _ => 1,
+        };
+        let line = self.buf[..end].to_vec();
+        self.buf.drain(..end + len);
+        Some(line)
+    }
+
+    /// If possible, parses the first complete event from the buffer.
+    fn take_event(&mut self) -> Option<anyhow::Result<Event>> {
+        while let Some(line) = self.take_line() {
+            let line = match String::from_utf8(line) {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            // A blank line ends the event, events without data are skipped
+            if line.is_empty() {
+                let event = self.event.take();
+                match self.data.take() {
+                    Some(data) if !data.is_empty() => return Some(Ok(Event { event, data })),
+                    _ => continue,
+                }
+            }
+
+            // Lines starting with a colon are comments, used as keep-alives
+            if line.starts_with(':') {
+                continue;
+            }
+            let (field, value) = match line.split_once(':') {
+                Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+                None => (line.as_str(), ""),
+            };
+            match field {
+                "event" => self.event = Some(value.to_owned()),
+                // Multiple data lines are joined with newlines
+                "data" => match &mut self.data {
+                    Some(data) => {
+                        data.push('\n');
+                        data.push_str(value);
+                    }
+                    None => self.data = Some(value.to_owned()),
+                },
+                _ => {}
+            }
+        }
+
+        // An event without the blank line ending it was cut short
+        if self.eof {
+            self.event = None;
+            self.data = None;
+        }
+        None
+    }
+}
+
+impl Stream for EventStream {
+    type Item = anyhow::Result<Event>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(event) = self.take_event() {
+                return Poll::Ready(Some(event));
+            }
+            if self.eof {
+                return Poll::Ready(None);
+            }
+
+            match self.byte_stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => self.buf.extend_from_slice(&bytes),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                // Stream terminated, parse the complete events left
+                Poll::Ready(None) => self.eof = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn parse(chunks: Vec<&'static str>) -> Vec<Event> {
+        let chunks = chunks.into_iter().map(|chunk| Ok(Bytes::from(chunk)));
+        EventStream::from_byte_stream(futures_util::stream::iter(chunks))
+            .map(|event| event.unwrap())
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn incomplete_event_at_eof_is_discarded() {
+        let events = parse(vec!["data: {\"id\": 1}\n\n", "data: {\"id\":", " 2"]).await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "{\"id\": 1}");
+
+        // Complete lines, but no blank line ending the event
+        let events = parse(vec!["event: delta\ndata: {\"id\": 3}\n"]).await;
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn empty_data_is_skipped() {
+        let events = parse(vec!["data:\n\ndata: \n\n", "data: [DONE]\n\n"]).await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "[DONE]");
+    }
+}
@@ -0,0 +1,137 @@
+//! See <https://platform.openai.com/docs/api-reference/uploads>.
+//! Use with [Client::create_upload](crate::Client::create_upload),
+//! [Client::add_upload_part](crate::Client::add_upload_part),
+//! [Client::complete_upload](crate::Client::complete_upload),
+//! [Client::cancel_upload](crate::Client::cancel_upload)
+//! or [Client::upload_large_file](crate::Client::upload_large_file).
+//!
+//! Uploads are used for files larger than the 512 MB accepted by [Client::upload_file](crate::Client::upload_file),
+//! sent in parts of at most [MAX_PART_SIZE] bytes.
+use reqwest::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::files::{FileObject, FilePurpose};
+
+/// The maximum size of an upload part, 64 MB.
+pub const MAX_PART_SIZE: usize = 64 * 1024 * 1024;
+
+/// Request arguments for creating an upload.
+///
+/// See <https://platform.openai.com/docs/api-reference/uploads/create>.
+///
+/// ```
+/// use openai_rust2::files::FilePurpose;
+/// use openai_rust2::uploads::UploadArguments;
+/// let args = UploadArguments::new("training_examples.jsonl", 2147483648, FilePurpose::FineTune, "text/jsonl");
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct UploadArguments {
+    /// The name of the file to upload.
+    pub filename: String,
+    /// The intended purpose of the uploaded file.
+    pub purpose: FilePurpose,
+    /// The number of bytes in the file you are uploading.
+    pub bytes: u64,
+    /// The MIME type of the file, e.g. `text/jsonl` or `application/pdf`.
+    pub mime_type: String,
+}
+
+impl UploadArguments {
+    pub fn new(
+        filename: impl AsRef<str>,
+        bytes: u64,
+        purpose: FilePurpose,
+        mime_type: impl AsRef<str>,
+    ) -> UploadArguments {
+        UploadArguments {
+            filename: filename.as_ref().to_owned(),
+            purpose,
+            bytes,
+            mime_type: mime_type.as_ref().to_owned(),
+        }
+    }
+}
+
+/// Request arguments for completing an upload.
+///
+/// See <https://platform.openai.com/docs/api-reference/uploads/complete>.
+#[derive(Serialize, Debug, Clone)]
+pub struct CompleteUploadArguments {
+    /// The ordered list of part IDs.
+    pub part_ids: Vec<String>,
+    /// The optional md5 checksum for the file contents to verify if the bytes uploaded matches what you expect.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub md5: Option<String>,
+}
+
+impl CompleteUploadArguments {
+    pub fn new(part_ids: Vec<String>) -> CompleteUploadArguments {
+        CompleteUploadArguments {
+            part_ids,
+            md5: None,
+        }
+    }
+}
+
+/// The data of an upload part, at most [MAX_PART_SIZE] bytes.
+pub(crate) fn part_form(data: Vec<u8>) -> Form {
+    Form::new().part("data", Part::bytes(data).file_name("part"))
+}
+
+/// The status of an [Upload].
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadStatus {
+    Pending,
+    Completed,
+    Cancelled,
+    Expired,
+}
+
+/// An upload that parts can be added to.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"id\": \"upload_abc123\",
+/// #   \"object\": \"upload\",
+/// #   \"bytes\": 2147483648,
+/// #   \"created_at\": 1719184911,
+/// #   \"filename\": \"training_examples.jsonl\",
+/// #   \"purpose\": \"fine-tune\",
+/// #   \"status\": \"completed\",
+/// #   \"expires_at\": 1719127296,
+/// #   \"file\": {
+/// #     \"id\": \"file-xyz321\",
+/// #     \"object\": \"file\",
+/// #     \"bytes\": 2147483648,
+/// #     \"created_at\": 1719186911,
+/// #     \"filename\": \"training_examples.jsonl\",
+/// #     \"purpose\": \"fine-tune\"
+/// #   }
+/// # }";
+/// # let upload = serde_json::from_str::<openai_rust2::uploads::Upload>(json).unwrap();
+/// let file_id = upload.file.unwrap().id;
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct Upload {
+    pub id: String,
+    pub created_at: u64,
+    pub filename: String,
+    /// The intended number of bytes to be uploaded.
+    pub bytes: u64,
+    pub purpose: FilePurpose,
+    pub status: UploadStatus,
+    /// The Unix timestamp (in seconds) for when the upload will expire.
+    pub expires_at: u64,
+    /// The file created once the upload is completed.
+    pub file: Option<FileObject>,
+}
+
+/// A part of an [Upload].
+#[derive(Deserialize, Debug, Clone)]
+pub struct UploadPart {
+    pub id: String,
+    pub created_at: u64,
+    pub upload_id: String,
+}
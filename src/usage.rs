@@ -0,0 +1,369 @@
+//! See <https://platform.openai.com/docs/api-reference/usage>.
+//! Use with [Client::list_usage](crate::Client::list_usage)
+//! or [Client::list_costs](crate::Client::list_costs).
+use serde::Deserialize;
+
+/// The kind of usage to query with [crate::Client::list_usage].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageKind {
+    Completions,
+    Embeddings,
+    Moderations,
+    Images,
+    AudioSpeeches,
+    AudioTranscriptions,
+    VectorStores,
+    CodeInterpreterSessions,
+}
+
+impl UsageKind {
+    /// The name of the kind in the endpoint path.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsageKind::Completions => "completions",
+            UsageKind::Embeddings => "embeddings",
+            UsageKind::Moderations => "moderations",
+            UsageKind::Images => "images",
+            UsageKind::AudioSpeeches => "audio_speeches",
+            UsageKind::AudioTranscriptions => "audio_transcriptions",
+            UsageKind::VectorStores => "vector_stores",
+            UsageKind::CodeInterpreterSessions => "code_interpreter_sessions",
+        }
+    }
+}
+
+/// Query parameters of [crate::Client::list_usage].
+///
+/// See <https://platform.openai.com/docs/api-reference/usage/completions>.
+///
+/// ```
+/// let mut args = openai_rust2::usage::UsageArguments::new(1730419200);
+/// args.bucket_width = Some("1d".to_owned());
+/// args.group_by = vec!["model".to_owned()];
+/// ```
+#[derive(Debug, Clone)]
+pub struct UsageArguments {
+    /// Start time (Unix seconds) of the query time range, inclusive.
+    pub start_time: u64,
+    /// End time (Unix seconds) of the query time range, exclusive.
+    pub end_time: Option<u64>,
+    /// Width of each time bucket in response, `1m`, `1h` or `1d`. Defaults to `1d`.
+    pub bucket_width: Option<String>,
+    /// Return only usage for these projects.
+    pub project_ids: Vec<String>,
+    /// Return only usage for these users.
+    pub user_ids: Vec<String>,
+    /// Return only usage for these API keys.
+    pub api_key_ids: Vec<String>,
+    /// Return only usage for these models.
+    pub models: Vec<String>,
+    /// If `true`, return batch jobs only. If `false`, return non-batch jobs only. Only for completions.
+    pub batch: Option<bool>,
+    /// Group the usage data by the specified fields, such as `project_id`, `user_id`, `api_key_id` or `model`.
+    pub group_by: Vec<String>,
+    /// Number of buckets to return.
+    pub limit: Option<u32>,
+    /// A cursor for pagination, the [UsagePage::next_page] of the previous page.
+    pub page: Option<String>,
+}
+
+impl UsageArguments {
+    pub fn new(start_time: u64) -> UsageArguments {
+        UsageArguments {
+            start_time,
+            end_time: None,
+            bucket_width: None,
+            project_ids: vec![],
+            user_ids: vec![],
+            api_key_ids: vec![],
+            models: vec![],
+            batch: None,
+            group_by: vec![],
+            limit: None,
+            page: None,
+        }
+    }
+
+    /// The query string pairs, with arrays sent as repeated `name[]` parameters.
+    pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = time_range_query(self.start_time, self.end_time, &self.bucket_width);
+        array_query(&mut query, "project_ids[]", &self.project_ids);
+        array_query(&mut query, "user_ids[]", &self.user_ids);
+        array_query(&mut query, "api_key_ids[]", &self.api_key_ids);
+        array_query(&mut query, "models[]", &self.models);
+        if let Some(batch) = self.batch {
+            query.push(("batch", batch.to_string()));
+        }
+        array_query(&mut query, "group_by[]", &self.group_by);
+        page_query(&mut query, self.limit, &self.page);
+        query
+    }
+}
+
+/// Query parameters of [crate::Client::list_costs].
+///
+/// See <https://platform.openai.com/docs/api-reference/usage/costs>.
+#[derive(Debug, Clone)]
+pub struct CostsArguments {
+    /// Start time (Unix seconds) of the query time range, inclusive.
+    pub start_time: u64,
+    /// End time (Unix seconds) of the query time range, exclusive.
+    pub end_time: Option<u64>,
+    /// Width of each time bucket in response. Currently only `1d` is supported.
+    pub bucket_width: Option<String>,
+    /// Return only costs for these projects.
+    pub project_ids: Vec<String>,
+    /// Group the costs by the specified fields, `project_id` and/or `line_item`.
+    pub group_by: Vec<String>,
+    /// Number of buckets to return.
+    pub limit: Option<u32>,
+    /// A cursor for pagination, the [UsagePage::next_page] of the previous page.
+    pub page: Option<String>,
+}
+
+impl CostsArguments {
+    pub fn new(start_time: u64) -> CostsArguments {
+        CostsArguments {
+            start_time,
+            end_time: None,
+            bucket_width: None,
+            project_ids: vec![],
+            group_by: vec![],
+            limit: None,
+            page: None,
+        }
+    }
+
+    /// The query string pairs, with arrays sent as repeated `name[]` parameters.
+    pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = time_range_query(self.start_time, self.end_time, &self.bucket_width);
+        array_query(&mut query, "project_ids[]", &self.project_ids);
+        array_query(&mut query, "group_by[]", &self.group_by);
+        page_query(&mut query, self.limit, &self.page);
+        query
+    }
+}
+
+fn time_range_query(
+    start_time: u64,
+    end_time: Option<u64>,
+    bucket_width: &Option<String>,
+) -> Vec<(&'static str, String)> {
+    let mut query = vec![("start_time", start_time.to_string())];
+    if let Some(end_time) = end_time {
+        query.push(("end_time", end_time.to_string()));
+    }
+    if let Some(bucket_width) = bucket_width {
+        query.push(("bucket_width", bucket_width.clone()));
+    }
+    query
+}
+
+fn array_query(query: &mut Vec<(&'static str, String)>, name: &'static str, values: &[String]) {
+    query.extend(values.iter().map(|v| (name, v.clone())));
+}
+
+fn page_query(query: &mut Vec<(&'static str, String)>, limit: Option<u32>, page: &Option<String>) {
+    if let Some(limit) = limit {
+        query.push(("limit", limit.to_string()));
+    }
+    if let Some(page) = page {
+        query.push(("page", page.clone()));
+    }
+}
+
+/// A page of time buckets returned by [crate::Client::list_usage] and [crate::Client::list_costs].
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #   \"object\": \"page\",
+/// #   \"data\": [
+/// #     {
+/// #       \"object\": \"bucket\",
+/// #       \"start_time\": 1730419200,
+/// #       \"end_time\": 1730505600,
+/// #       \"results\": [
+/// #         {
+/// #           \"object\": \"organization.usage.completions.result\",
+/// #           \"input_tokens\": 1000,
+/// #           \"output_tokens\": 500,
+/// #           \"input_cached_tokens\": 800,
+/// #           \"input_audio_tokens\": 0,
+/// #           \"output_audio_tokens\": 0,
+/// #           \"num_model_requests\": 5,
+/// #           \"project_id\": null,
+/// #           \"user_id\": null,
+/// #           \"api_key_id\": null,
+/// #           \"model\": \"gpt-4o-mini-2024-07-18\",
+/// #           \"batch\": null
+/// #         },
+/// #         {
+/// #           \"object\": \"organization.costs.result\",
+/// #           \"amount\": { \"value\": 0.06, \"currency\": \"usd\" },
+/// #           \"line_item\": null,
+/// #           \"project_id\": null
+/// #         }
+/// #       ]
+/// #     }
+/// #   ],
+/// #   \"has_more\": false,
+/// #   \"next_page\": null
+/// # }";
+/// # let page = serde_json::from_str::<openai_rust2::usage::UsagePage>(json).unwrap();
+/// use openai_rust2::usage::UsageResult;
+/// for bucket in &page.data {
+///     for result in &bucket.results {
+///         if let UsageResult::Completions(usage) = result {
+///             println!("{}: {} input tokens", bucket.start_time, usage.input_tokens);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsagePage {
+    pub data: Vec<UsageBucket>,
+    pub has_more: bool,
+    /// The cursor of the next page, to be used as `page` in the arguments.
+    pub next_page: Option<String>,
+}
+
+/// The usage or costs of a time range.
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsageBucket {
+    pub start_time: u64,
+    pub end_time: u64,
+    /// One result per group, or a single one without `group_by`.
+    #[serde(alias = "result")]
+    pub results: Vec<UsageResult>,
+}
+
+/// A usage or cost result of a [UsageBucket].
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "object")]
+pub enum UsageResult {
+    #[serde(rename = "organization.usage.completions.result")]
+    Completions(CompletionsUsage),
+    #[serde(rename = "organization.usage.embeddings.result")]
+    Embeddings(TokensUsage),
+    #[serde(rename = "organization.usage.moderations.result")]
+    Moderations(TokensUsage),
+    #[serde(rename = "organization.usage.images.result")]
+    Images(ImagesUsage),
+    #[serde(rename = "organization.usage.audio_speeches.result")]
+    AudioSpeeches(AudioSpeechesUsage),
+    #[serde(rename = "organization.usage.audio_transcriptions.result")]
+    AudioTranscriptions(AudioTranscriptionsUsage),
+    #[serde(rename = "organization.usage.vector_stores.result")]
+    VectorStores(VectorStoresUsage),
+    #[serde(rename = "organization.usage.code_interpreter_sessions.result")]
+    CodeInterpreterSessions(CodeInterpreterSessionsUsage),
+    #[serde(rename = "organization.costs.result")]
+    Costs(CostsResult),
+    /// A result not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The fields a result is grouped by. They are only set when used in `group_by`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct UsageGroup {
+    pub project_id: Option<String>,
+    pub user_id: Option<String>,
+    pub api_key_id: Option<String>,
+    pub model: Option<String>,
+    pub batch: Option<bool>,
+}
+
+/// The usage of the completions endpoints.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CompletionsUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// The number of input tokens served from the cache.
+    #[serde(default)]
+    pub input_cached_tokens: u64,
+    #[serde(default)]
+    pub input_audio_tokens: u64,
+    #[serde(default)]
+    pub output_audio_tokens: u64,
+    pub num_model_requests: u64,
+    #[serde(flatten)]
+    pub group: UsageGroup,
+}
+
+/// The usage of the embeddings or moderations endpoints.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TokensUsage {
+    pub input_tokens: u64,
+    pub num_model_requests: u64,
+    #[serde(flatten)]
+    pub group: UsageGroup,
+}
+
+/// The usage of the images endpoints.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ImagesUsage {
+    /// The number of images processed.
+    pub images: u64,
+    pub num_model_requests: u64,
+    /// `image.generation`, `image.edit` or `image.variation`, when grouped by `source`.
+    pub source: Option<String>,
+    /// The image size, when grouped by `size`.
+    pub size: Option<String>,
+    #[serde(flatten)]
+    pub group: UsageGroup,
+}
+
+/// The usage of the text-to-speech endpoint.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AudioSpeechesUsage {
+    /// The number of characters processed.
+    pub characters: u64,
+    pub num_model_requests: u64,
+    #[serde(flatten)]
+    pub group: UsageGroup,
+}
+
+/// The usage of the audio transcriptions endpoint.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AudioTranscriptionsUsage {
+    /// The number of seconds processed.
+    pub seconds: u64,
+    pub num_model_requests: u64,
+    #[serde(flatten)]
+    pub group: UsageGroup,
+}
+
+/// The storage used by vector stores.
+#[derive(Deserialize, Debug, Clone)]
+pub struct VectorStoresUsage {
+    pub usage_bytes: u64,
+    pub project_id: Option<String>,
+}
+
+/// The code interpreter sessions.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CodeInterpreterSessionsUsage {
+    #[serde(alias = "sessions")]
+    pub num_sessions: u64,
+    pub project_id: Option<String>,
+}
+
+/// The costs of a [UsageBucket].
+#[derive(Deserialize, Debug, Clone)]
+pub struct CostsResult {
+    pub amount: CostAmount,
+    /// The line item, when grouped by `line_item`.
+    pub line_item: Option<String>,
+    /// The project, when grouped by `project_id`.
+    pub project_id: Option<String>,
+}
+
+/// A monetary amount.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CostAmount {
+    pub value: f64,
+    /// Lowercase ISO-4217 currency, e.g. `usd`.
+    pub currency: String,
+}
@@ -0,0 +1,359 @@
+//! See <https://platform.openai.com/docs/api-reference/vector-stores-files>
+//! and <https://platform.openai.com/docs/api-reference/vector-stores-file-batches>.
+//! Vector stores can be searched directly with [Client::search_vector_store](crate::Client::search_vector_store).
+//!
+//! Use with [Client::create_vector_store_file](crate::Client::create_vector_store_file), [Client::list_vector_store_files](crate::Client::list_vector_store_files),
+//! [Client::retrieve_vector_store_file](crate::Client::retrieve_vector_store_file), [Client::delete_vector_store_file](crate::Client::delete_vector_store_file),
+//! [Client::create_vector_store_file_batch](crate::Client::create_vector_store_file_batch), [Client::retrieve_vector_store_file_batch](crate::Client::retrieve_vector_store_file_batch),
+//! [Client::cancel_vector_store_file_batch](crate::Client::cancel_vector_store_file_batch), [Client::list_vector_store_file_batch_files](crate::Client::list_vector_store_file_batch_files)
+//! or [Client::wait_for_vector_store_file_batch](crate::Client::wait_for_vector_store_file_batch).
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Request arguments for attaching a file to a vector store.
+///
+/// See <https://platform.openai.com/docs/api-reference/vector-stores-files/createFile>.
+///
+/// ```
+/// let args = openai_rust2::vector_stores::VectorStoreFileArguments::new("file-abc123");
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct VectorStoreFileArguments {
+    /// A [File](crate::files::FileObject) ID that the vector store should use. Useful for tools like `file_search` that can access files.
+    pub file_id: String,
+
+    /// Set of 16 key-value pairs that can be attached to an object, used for filtering searches.
+    /// Values can be strings, booleans or numbers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+
+    /// The chunking strategy used to chunk the file. If not set, will use the `auto` strategy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl VectorStoreFileArguments {
+    pub fn new(file_id: impl AsRef<str>) -> VectorStoreFileArguments {
+        VectorStoreFileArguments {
+            file_id: file_id.as_ref().to_owned(),
+            attributes: None,
+            chunking_strategy: None,
+        }
+    }
+}
+
+/// How a file is split into chunks before being embedded.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChunkingStrategy {
+    /// Currently uses a `max_chunk_size_tokens` of 800 and `chunk_overlap_tokens` of 400.
+    Auto,
+    Static {
+        #[serde(rename = "static")]
+        static_strategy: StaticChunkingStrategy,
+    },
+    /// Returned for files that were indexed before the `chunking_strategy` concept was introduced.
+    Other,
+}
+
+/// The settings of a [ChunkingStrategy::Static].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StaticChunkingStrategy {
+    /// The maximum number of tokens in each chunk. The minimum value is 100 and the maximum value is 4096.
+    pub max_chunk_size_tokens: u32,
+    /// The number of tokens that overlap between chunks. Must not exceed half of `max_chunk_size_tokens`.
+    pub chunk_overlap_tokens: u32,
+}
+
+/// The ingestion status of a [VectorStoreFile].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorStoreFileStatus {
+    InProgress,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+impl VectorStoreFileStatus {
+    /// Whether the file is done being processed, successfully or not.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, VectorStoreFileStatus::InProgress)
+    }
+}
+
+/// A file attached to a vector store.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"file-abc123\",
+/// #  \"object\": \"vector_store.file\",
+/// #  \"usage_bytes\": 1234,
+/// #  \"created_at\": 1698107661,
+/// #  \"vector_store_id\": \"vs_abc123\",
+/// #  \"status\": \"completed\",
+/// #  \"last_error\": null,
+/// #  \"chunking_strategy\": {
+/// #    \"type\": \"static\",
+/// #    \"static\": {
+/// #      \"max_chunk_size_tokens\": 800,
+/// #      \"chunk_overlap_tokens\": 400
+/// #    }
+/// #  }
+/// # }";
+/// # let file = serde_json::from_str::<openai_rust2::vector_stores::VectorStoreFile>(json).unwrap();
+/// use openai_rust2::vector_stores::VectorStoreFileStatus;
+/// if file.status == VectorStoreFileStatus::Completed {
+///     println!("{} is ready to be searched", file.id);
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct VectorStoreFile {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the vector store file was created.
+    pub created_at: u64,
+    /// The ID of the vector store that the file is attached to.
+    pub vector_store_id: String,
+    /// The total vector store usage in bytes. Note that this may be different from the original file size.
+    pub usage_bytes: u64,
+    /// The status of the vector store file. `completed` indicates that the vector store file is ready for use.
+    pub status: VectorStoreFileStatus,
+    /// The last error associated with this vector store file. Will be `None` if there are no errors.
+    pub last_error: Option<VectorStoreFileError>,
+    /// The strategy used to chunk the file.
+    #[serde(default)]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+    #[serde(default)]
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// The error of a failed [VectorStoreFile].
+#[derive(Deserialize, Debug, Clone)]
+pub struct VectorStoreFileError {
+    /// One of `server_error`, `unsupported_file` or `invalid_file`.
+    pub code: String,
+    /// A human-readable description of the error.
+    pub message: String,
+}
+
+/// The response of a vector store file deletion request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeleteVectorStoreFileResponse {
+    pub id: String,
+    pub deleted: bool,
+}
+
+/// Request arguments for attaching multiple files to a vector store at once.
+///
+/// See <https://platform.openai.com/docs/api-reference/vector-stores-file-batches/createBatch>.
+///
+/// ```
+/// let args = openai_rust2::vector_stores::VectorStoreFileBatchArguments::new(vec![
+///     "file-abc123".to_owned(),
+///     "file-abc456".to_owned(),
+/// ]);
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct VectorStoreFileBatchArguments {
+    /// A list of [File](crate::files::FileObject) IDs that the vector store should use.
+    pub file_ids: Vec<String>,
+
+    /// Set of 16 key-value pairs that are attached to every file of the batch, used for filtering searches.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+
+    /// The chunking strategy used to chunk the files. If not set, will use the `auto` strategy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl VectorStoreFileBatchArguments {
+    pub fn new(file_ids: Vec<String>) -> VectorStoreFileBatchArguments {
+        VectorStoreFileBatchArguments {
+            file_ids,
+            attributes: None,
+            chunking_strategy: None,
+        }
+    }
+}
+
+/// A batch of files attached to a vector store.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"id\": \"vsfb_abc123\",
+/// #  \"object\": \"vector_store.file_batch\",
+/// #  \"created_at\": 1699061776,
+/// #  \"vector_store_id\": \"vs_abc123\",
+/// #  \"status\": \"in_progress\",
+/// #  \"file_counts\": {
+/// #    \"in_progress\": 1,
+/// #    \"completed\": 1,
+/// #    \"failed\": 0,
+/// #    \"cancelled\": 0,
+/// #    \"total\": 0
+/// #  }
+/// # }";
+/// # let batch = serde_json::from_str::<openai_rust2::vector_stores::VectorStoreFileBatch>(json).unwrap();
+/// println!("{}/{} files processed", batch.file_counts.completed, batch.file_counts.total);
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct VectorStoreFileBatch {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the vector store files batch was created.
+    pub created_at: u64,
+    /// The ID of the vector store that the files are attached to.
+    pub vector_store_id: String,
+    /// The status of the vector store files batch.
+    pub status: VectorStoreFileStatus,
+    pub file_counts: FileCounts,
+}
+
+/// The number of files in a [VectorStoreFileBatch], per status.
+#[derive(Deserialize, Debug, Clone)]
+pub struct FileCounts {
+    pub in_progress: u32,
+    pub completed: u32,
+    pub failed: u32,
+    pub cancelled: u32,
+    pub total: u32,
+}
+
+/// Request arguments for searching a vector store.
+///
+/// See <https://platform.openai.com/docs/api-reference/vector-stores/search>.
+///
+/// ```
+/// let mut args = openai_rust2::vector_stores::VectorStoreSearchArguments::new("What is the return policy?");
+/// args.max_num_results = Some(5);
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct VectorStoreSearchArguments {
+    /// A query string for a search.
+    pub query: String,
+
+    /// Whether to rewrite the natural language query for vector search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewrite_query: Option<bool>,
+
+    /// The maximum number of results to return. This number should be between 1 and 50 inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_num_results: Option<u32>,
+
+    /// A filter to apply based on file attributes.
+    ///
+    /// See <https://platform.openai.com/docs/api-reference/vector-stores/search#vector-stores-search-filters>.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<serde_json::Value>,
+
+    /// Ranking options for search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking_options: Option<RankingOptions>,
+}
+
+impl VectorStoreSearchArguments {
+    pub fn new(query: impl AsRef<str>) -> VectorStoreSearchArguments {
+        VectorStoreSearchArguments {
+            query: query.as_ref().to_owned(),
+            rewrite_query: None,
+            max_num_results: None,
+            filters: None,
+            ranking_options: None,
+        }
+    }
+}
+
+/// Ranking options of a [VectorStoreSearchArguments].
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct RankingOptions {
+    /// The ranker to use, `auto` or `default-2024-11-15`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranker: Option<String>,
+    /// Results with a score below this threshold, between 0 and 1, are left out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_threshold: Option<f32>,
+}
+
+/// The response of a vector store search, containing the ranked chunks.
+///
+/// ```
+/// # use serde_json;
+/// # let json = "{
+/// #  \"object\": \"vector_store.search_results.page\",
+/// #  \"search_query\": \"What is the return policy?\",
+/// #  \"data\": [
+/// #    {
+/// #      \"file_id\": \"file_123\",
+/// #      \"filename\": \"document.pdf\",
+/// #      \"score\": 0.95,
+/// #      \"attributes\": {
+/// #        \"author\": \"John Doe\",
+/// #        \"date\": \"2023-01-01\"
+/// #      },
+/// #      \"content\": [
+/// #        {
+/// #          \"type\": \"text\",
+/// #          \"text\": \"Relevant chunk\"
+/// #        }
+/// #      ]
+/// #    }
+/// #  ],
+/// #  \"has_more\": false,
+/// #  \"next_page\": null
+/// # }";
+/// # let res = serde_json::from_str::<openai_rust2::vector_stores::VectorStoreSearchResponse>(json).unwrap();
+/// for result in &res.data {
+///     println!("{} ({}): {}", result.filename, result.score, result.text());
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct VectorStoreSearchResponse {
+    /// The query used for the search, possibly rewritten.
+    pub search_query: serde_json::Value,
+    /// The results, ordered by descending score.
+    pub data: Vec<VectorStoreSearchResult>,
+    #[serde(default)]
+    pub has_more: bool,
+    #[serde(default)]
+    pub next_page: Option<String>,
+}
+
+/// A chunk returned by a vector store search.
+#[derive(Deserialize, Debug, Clone)]
+pub struct VectorStoreSearchResult {
+    /// The ID of the vector store file.
+    pub file_id: String,
+    /// The name of the vector store file.
+    pub filename: String,
+    /// The similarity score for the result.
+    pub score: f32,
+    #[serde(default)]
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+    /// Content chunks from the file.
+    pub content: Vec<SearchResultContent>,
+}
+
+impl VectorStoreSearchResult {
+    /// All the text chunks of the result, joined by newlines.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .map(|c| c.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A content chunk of a [VectorStoreSearchResult].
+#[derive(Deserialize, Debug, Clone)]
+pub struct SearchResultContent {
+    /// The type of content, currently always `text`.
+    #[serde(rename = "type")]
+    pub content_type: String,
+    pub text: String,
+}
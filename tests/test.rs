@@ -11,29 +11,33 @@ lazy_static! {
 pub async fn list_models() {
     let c = openai_rust::Client::new(&KEY);
     let models_vec = c.list_models(None).await.unwrap();
-    assert!(models_vec.len() > 0);
+    assert!(!models_vec.is_empty());
 
     let c_openai_manually = openai_rust::Client::new_with_base_url(&KEY, "https://api.openai.com");
     let models_vec = c_openai_manually.list_models(None).await.unwrap();
-    assert!(models_vec.len() > 0);
+    assert!(!models_vec.is_empty());
 
     let c_local_ollama = openai_rust::Client::new_with_base_url("", "http://localhost:11434");
     let models_vec = c_local_ollama.list_models(None).await.unwrap();
-    assert!(models_vec.len() > 0);
+    assert!(!models_vec.is_empty());
     models_vec.iter().for_each(|m| {
         println!("Local Ollama Model: {}", m.id);
     });
 }
 
+#[tokio::test]
+pub async fn retrieve_model() {
+    let c = openai_rust::Client::new(&KEY);
+    let model = c.retrieve_model("gpt-4o-mini", None).await.unwrap();
+    assert_eq!(model.id, "gpt-4o-mini");
+}
+
 #[tokio::test]
 pub async fn create_chat() {
     let c = openai_rust::Client::new(&KEY);
     let args = openai_rust::chat::ChatArguments::new(
         "gpt-3.5-turbo",
-        vec![openai_rust::chat::Message {
-            role: "user".to_owned(),
-            content: "Hello GPT!".to_owned(),
-        }],
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
     );
     c.create_chat(args, None).await.unwrap();
 }
@@ -43,10 +47,7 @@ pub async fn create_chat_stream() {
     let c = openai_rust::Client::new(&KEY);
     let args = openai_rust::chat::ChatArguments::new(
         "gpt-3.5-turbo",
-        vec![openai_rust::chat::Message {
-            role: "user".to_owned(),
-            content: "Hello GPT!".to_owned(),
-        }],
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
     );
 
     c.create_chat_stream(args, None)
@@ -56,6 +57,515 @@ pub async fn create_chat_stream() {
         .await;
 }
 
+#[tokio::test]
+pub async fn create_chat_stream_usage() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
+    );
+    args.stream_options = Some(openai_rust::chat::StreamOptions {
+        include_usage: true,
+    });
+
+    let chunks = c
+        .create_chat_stream(args, None)
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+    let last = chunks.last().unwrap().as_ref().unwrap();
+    assert!(last.usage.is_some());
+}
+
+#[tokio::test]
+pub async fn create_chat_stream_collect() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
+    );
+    args.stream_options = Some(openai_rust::chat::StreamOptions {
+        include_usage: true,
+    });
+
+    let completion = c
+        .create_chat_stream(args, None)
+        .await
+        .unwrap()
+        .collect_completion()
+        .await
+        .unwrap();
+    assert!(!completion.choices[0].message.content.to_string().is_empty());
+    assert_eq!(
+        completion.choices[0].finish_reason,
+        openai_rust::chat::FinishReason::Stop
+    );
+    assert!(completion.usage.total_tokens > 0);
+}
+
+#[tokio::test]
+pub async fn create_chat_stream_events() {
+    use openai_rust::chat::stream::ChatEvent;
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
+    );
+
+    let events = c
+        .create_chat_stream(args, None)
+        .await
+        .unwrap()
+        .events()
+        .collect::<Vec<_>>()
+        .await;
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Ok(ChatEvent::ContentDelta(_)))));
+    assert!(matches!(events.last(), Some(Ok(ChatEvent::Done))));
+}
+
+#[tokio::test]
+pub async fn create_chat_stream_channel() {
+    use openai_rust::chat::stream::ChatEvent;
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
+    );
+
+    let mut receiver = c
+        .create_chat_stream(args, None)
+        .await
+        .unwrap()
+        .events()
+        .into_channel(8);
+    let mut last = None;
+    while let Some(event) = receiver.recv().await {
+        last = Some(event.unwrap());
+    }
+    assert!(matches!(last, Some(ChatEvent::Done)));
+}
+
+#[tokio::test]
+pub async fn create_chat_stream_abort() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Count from 1 to 1000")],
+    );
+
+    let mut stream = c.create_chat_stream(args, None).await.unwrap();
+    assert!(stream.next().await.is_some());
+    stream.abort_handle().abort();
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+pub async fn create_event_stream() {
+    let c = openai_rust::Client::new(&KEY);
+    let body = serde_json::json!({
+        "model": "gpt-4o-mini",
+        "messages": [{"role": "user", "content": "Hello GPT!"}],
+        "stream": true,
+    });
+
+    let events = c
+        .create_event_stream("/v1/chat/completions", &body)
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(events.last().unwrap().as_ref().unwrap().data, "[DONE]");
+}
+
+#[tokio::test]
+pub async fn create_chat_stream_retry() {
+    let c = openai_rust::Client::new(&KEY)
+        .with_stream_idle_timeout(std::time::Duration::from_secs(30))
+        .with_stream_retry(openai_rust::sse::StreamRetryPolicy::default());
+    let args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
+    );
+
+    let completion = c
+        .create_chat_stream(args, None)
+        .await
+        .unwrap()
+        .collect_completion()
+        .await
+        .unwrap();
+    assert_eq!(
+        completion.choices[0].finish_reason,
+        openai_rust::chat::FinishReason::Stop
+    );
+}
+
+#[tokio::test]
+pub async fn create_chat_stream_tools() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user(
+            "What is the weather like in Boston and in Paris?",
+        )],
+    );
+    args.tools = Some(vec![openai_rust::chat::ChatTool::function(
+        "get_weather",
+        "Get the current weather in a given location",
+        serde_json::json!({
+            "type": "object",
+            "properties": {"location": {"type": "string"}},
+            "required": ["location"]
+        }),
+    )]);
+    args.tool_choice = Some(openai_rust::chat::ToolChoice::Required);
+
+    let mut stream = c.create_chat_stream(args, None).await.unwrap();
+    let mut accumulator = openai_rust::chat::stream::ToolCallAccumulator::new();
+    let mut tool_calls = vec![];
+    while let Some(chunk) = stream.next().await {
+        for choice in &chunk.unwrap().choices {
+            tool_calls.extend(accumulator.push(choice));
+        }
+    }
+    assert!(!tool_calls.is_empty());
+    assert!(tool_calls
+        .iter()
+        .all(|call| call.function.name == "get_weather"));
+}
+
+#[tokio::test]
+pub async fn create_chat_n() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
+    );
+    args.n = Some(2);
+    let res = c.create_chat(args, None).await.unwrap();
+    assert_eq!(res.choices.len(), 2);
+}
+
+#[tokio::test]
+pub async fn create_chat_stream_n() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
+    );
+    args.n = Some(2);
+    let mut finished = [false; 2];
+    let mut choices = c.create_chat_stream(args, None).await.unwrap().choices();
+    while let Some(choice) = choices.next().await {
+        let choice = choice.unwrap();
+        if choice.finish_reason.is_some() {
+            finished[choice.index as usize] = true;
+        }
+    }
+    assert_eq!(finished, [true, true]);
+}
+
+#[tokio::test]
+pub async fn create_chat_reasoning_effort() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::chat::ChatArguments::builder("o3-mini")
+        .message(openai_rust::chat::Message::user("Hello GPT!"))
+        .reasoning_effort(openai_rust::chat::ReasoningEffort::Low)
+        .build();
+    let res = c.create_chat(args, None).await.unwrap();
+    assert!(res.usage.completion_tokens_details.is_some());
+}
+
+#[tokio::test]
+pub async fn create_chat_developer() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::chat::ChatArguments::new(
+        "o3-mini",
+        vec![
+            openai_rust::chat::Message::system("Answer in French."),
+            openai_rust::chat::Message::user("Hello GPT!"),
+        ],
+    )
+    .with_developer_role_for_model();
+    let res = c.create_chat(args, None).await.unwrap();
+    assert!(!res.choices[0].message.content.to_string().is_empty());
+}
+
+#[tokio::test]
+pub async fn create_chat_prediction() {
+    let c = openai_rust::Client::new(&KEY);
+    let code = "fn main() {\n    println!(\"Hello, world!\");\n}\n";
+    let args = openai_rust::chat::ChatArguments::builder("gpt-4o-mini")
+        .message(openai_rust::chat::Message::user(
+            "Replace \"world\" with \"GPT\" in this code, answer with the code only.",
+        ))
+        .message(openai_rust::chat::Message::user(code))
+        .prediction(openai_rust::chat::Prediction::content(code))
+        .build();
+    let res = c.create_chat(args, None).await.unwrap();
+    let details = res.usage.completion_tokens_details.unwrap();
+    assert!(details.accepted_prediction_tokens + details.rejected_prediction_tokens > 0);
+}
+
+#[tokio::test]
+pub async fn create_chat_store() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::chat::ChatArguments::builder("gpt-4o-mini")
+        .message(openai_rust::chat::Message::user("Hello GPT!"))
+        .store(true)
+        .metadata_entry("test", "create_chat_store")
+        .build();
+    c.create_chat(args, None).await.unwrap();
+}
+
+#[tokio::test]
+pub async fn create_chat_service_tier() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::chat::ChatArguments::builder("gpt-4o-mini")
+        .message(openai_rust::chat::Message::user("Hello GPT!"))
+        .service_tier(openai_rust::chat::ServiceTier::Auto)
+        .build();
+    let res = c.create_chat(args, None).await.unwrap();
+    assert!(res.service_tier.is_some());
+}
+
+#[tokio::test]
+pub async fn create_chat_web_search() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::chat::ChatArguments::builder("gpt-4o-mini-search-preview")
+        .message(openai_rust::chat::Message::user(
+            "What was a positive news story from today?",
+        ))
+        .web_search_options(openai_rust::chat::WebSearchOptions::default())
+        .build();
+    let res = c.create_chat(args, None).await.unwrap();
+    assert!(!res.choices[0].message.content.to_string().is_empty());
+    assert!(!res.choices[0]
+        .message
+        .annotations
+        .as_ref()
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+pub async fn create_chat_logprobs() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
+    );
+    args.logprobs = Some(true);
+    args.top_logprobs = Some(2);
+    let res = c.create_chat(args, None).await.unwrap();
+    assert!(res.choices[0].logprobs.as_ref().unwrap().content.is_some());
+}
+
+#[tokio::test]
+pub async fn create_chat_penalties() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user("Hello GPT!")],
+    );
+    args.presence_penalty = Some(0.5);
+    args.frequency_penalty = Some(0.5);
+    c.create_chat(args, None).await.unwrap();
+
+    let mut args = openai_rust::completions::CompletionArguments::new(
+        "gpt-3.5-turbo-instruct",
+        "The quick brown fox".to_owned(),
+    );
+    args.presence_penalty = Some(0.5);
+    args.frequency_penalty = Some(0.5);
+    c.create_completion(args, None).await.unwrap();
+}
+
+#[tokio::test]
+pub async fn create_chat_strict_tool() {
+    let c = openai_rust::Client::new(&KEY);
+    let tool = openai_rust::chat::ChatTool::strict_function(
+        "get_weather",
+        "Get the current weather in a given location",
+        serde_json::json!({
+            "type": "object",
+            "properties": {"location": {"type": "string"}},
+            "required": ["location"],
+            "additionalProperties": false
+        }),
+    )
+    .unwrap();
+    let args = openai_rust::chat::ChatArguments::builder("gpt-4o-mini")
+        .message(openai_rust::chat::Message::user(
+            "What is the weather like in Boston?",
+        ))
+        .tool(tool)
+        .tool_choice(openai_rust::chat::ToolChoice::Required)
+        .build();
+    let res = c.create_chat(args, None).await.unwrap();
+    let tool_calls = res.choices[0].message.tool_calls.clone().unwrap();
+    let arguments: serde_json::Value = tool_calls[0].function.parse_arguments().unwrap();
+    assert!(arguments["location"].is_string());
+}
+
+#[tokio::test]
+pub async fn create_chat_with_tools() {
+    let c = openai_rust::Client::new(&KEY);
+    let tools = openai_rust::chat::tools::ToolRegistry::new().register(
+        openai_rust::chat::ChatTool::function(
+            "get_weather",
+            "Get the current weather in a given location",
+            serde_json::json!({
+                "type": "object",
+                "properties": {"location": {"type": "string"}},
+                "required": ["location"]
+            }),
+        ),
+        |_arguments| async { Ok("Sunny, 22°C".to_owned()) },
+    );
+    let args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user(
+            "What is the weather like in Boston?",
+        )],
+    );
+    let run = c
+        .create_chat_with_tools(args, &tools, 5, None)
+        .await
+        .unwrap();
+    assert!(run.messages.len() >= 4);
+    assert!(run.message().content.to_string().contains("22"));
+}
+
+#[tokio::test]
+pub async fn create_chat_with_tools_max_iterations() {
+    let c = openai_rust::Client::new(&KEY);
+    let tools = openai_rust::chat::tools::ToolRegistry::new().register(
+        openai_rust::chat::ChatTool::function(
+            "get_weather",
+            "Get the current weather in a given location",
+            serde_json::json!({
+                "type": "object",
+                "properties": {"location": {"type": "string"}},
+                "required": ["location"]
+            }),
+        ),
+        |_arguments| async { Ok("Sunny, 22°C".to_owned()) },
+    );
+    let mut args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user(
+            "What is the weather like in Boston?",
+        )],
+    );
+    args.tool_choice = Some(openai_rust::chat::ToolChoice::Required);
+    let error = c
+        .create_chat_with_tools(args, &tools, 1, None)
+        .await
+        .unwrap_err()
+        .downcast::<openai_rust::chat::tools::MaxIterationsError>()
+        .unwrap();
+    // The user message, the tool calls and their outputs
+    assert!(error.messages.len() >= 3);
+    assert!(error.completion.is_some());
+}
+
+#[tokio::test]
+pub async fn conversation() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut conversation = openai_rust::chat::Conversation::new("gpt-4o-mini")
+        .with_system("You are a helpful assistant.");
+    conversation
+        .send(&c, "My name is Ferris, remember it.")
+        .await
+        .unwrap();
+    let reply = conversation.send(&c, "What is my name?").await.unwrap();
+    assert!(reply.content.to_string().contains("Ferris"));
+    assert_eq!(conversation.messages().len(), 5);
+}
+
+#[tokio::test]
+pub async fn create_chat_tools() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user(
+            "What is the weather like in Boston?",
+        )],
+    );
+    args.tools = Some(vec![openai_rust::chat::ChatTool::function(
+        "get_weather",
+        "Get the current weather in a given location",
+        serde_json::json!({
+            "type": "object",
+            "properties": {"location": {"type": "string"}},
+            "required": ["location"]
+        }),
+    )]);
+    args.tool_choice = Some(openai_rust::chat::ToolChoice::function("get_weather"));
+    let res = c.create_chat(args.clone(), None).await.unwrap();
+    let message = res.choices[0].message.clone();
+    let tool_calls = message.tool_calls.clone().unwrap();
+    assert_eq!(tool_calls[0].function.name, "get_weather");
+
+    args.tool_choice = None;
+    args.messages.push(message);
+    args.messages
+        .extend(tool_calls.iter().map(|call| call.output("It is sunny")));
+    c.create_chat(args, None).await.unwrap();
+}
+
+#[cfg(feature = "schemars")]
+#[tokio::test]
+pub async fn create_chat_structured() {
+    #[derive(serde::Deserialize, schemars::JsonSchema)]
+    #[allow(dead_code)]
+    struct Weather {
+        temperature: f32,
+        summary: String,
+    }
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::chat::ChatArguments::new(
+        "gpt-4o-mini",
+        vec![openai_rust::chat::Message::user(
+            "Make up the weather in Boston.",
+        )],
+    );
+    c.create_chat_structured::<Weather>(args, None)
+        .await
+        .unwrap();
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+pub fn json_schema_from_type_property_named_properties() {
+    #[derive(serde::Deserialize, schemars::JsonSchema)]
+    #[allow(dead_code)]
+    struct Inner {
+        name: String,
+    }
+    #[derive(serde::Deserialize, schemars::JsonSchema)]
+    #[allow(dead_code)]
+    struct Item {
+        properties: Inner,
+        required: Option<bool>,
+    }
+    let format = openai_rust::chat::JsonSchemaFormat::from_type::<Item>();
+    let schema = format.schema.unwrap();
+    let properties = schema["properties"].as_object().unwrap();
+    let mut names = properties.keys().collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, ["properties", "required"]);
+    assert_eq!(
+        schema["required"],
+        serde_json::json!(["properties", "required"])
+    );
+    assert!(openai_rust::chat::validate_strict_schema(&schema).is_ok());
+}
+
 #[tokio::test]
 pub async fn create_completion() {
     let c = openai_rust::Client::new(&KEY);
@@ -66,6 +576,20 @@ pub async fn create_completion() {
     c.create_completion(args, None).await.unwrap();
 }
 
+#[tokio::test]
+pub async fn create_completion_stream() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::completions::CompletionArguments::new(
+        "text-davinci-003",
+        "The quick brown fox".to_owned(),
+    );
+    c.create_completion_stream(args, None)
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+}
+
 #[tokio::test]
 pub async fn create_completion_logprobs() {
     let c = openai_rust::Client::new(&KEY);
@@ -77,6 +601,58 @@ pub async fn create_completion_logprobs() {
     c.create_completion(args, None).await.unwrap();
 }
 
+#[tokio::test]
+pub async fn create_completion_sampling() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::completions::CompletionArguments::new(
+        "gpt-3.5-turbo-instruct",
+        "The quick brown fox".to_owned(),
+    );
+    args.echo = Some(true);
+    args.best_of = Some(2);
+    args.presence_penalty = Some(0.5);
+    args.frequency_penalty = Some(0.5);
+    args.logit_bias = Some([("50256".to_owned(), -100.0)].into());
+    let res = c.create_completion(args, None).await.unwrap();
+    assert!(res.choices[0].text.starts_with("The quick brown fox"));
+}
+
+#[tokio::test]
+pub async fn create_completion_suffix() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut args = openai_rust::completions::CompletionArguments::new(
+        "gpt-3.5-turbo-instruct",
+        "fn add(a: i32, b: i32) -> i32 {\n".to_owned(),
+    );
+    args.suffix = Some("\n}".to_owned());
+    args.max_tokens = Some(16);
+    c.create_completion(args, None).await.unwrap();
+}
+
+#[tokio::test]
+pub async fn create_completion_builder() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::completions::CompletionArguments::builder("gpt-3.5-turbo-instruct")
+        .prompt("Count to ten: 1, 2, 3,")
+        .max_tokens(32)
+        .temperature(0.0)
+        .stop(vec!["7", "\n"])
+        .build();
+    c.create_completion(args, None).await.unwrap();
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+pub async fn create_edit() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::edits::EditArguments::new(
+        "text-davinci-edit-001",
+        "The quick brown fox".to_owned(),
+        "Complete this sentence.".to_owned(),
+    );
+    c.create_edit(args, None).await.unwrap();
+}
+
 #[tokio::test]
 pub async fn create_embeddings() {
     let c = openai_rust::Client::new(&KEY);
@@ -104,3 +680,241 @@ pub async fn create_image() {
     let args = openai_rust::images::ImageArguments::new("Lovecraftian Dagon");
     c.create_image(args, None).await.unwrap();
 }
+
+#[tokio::test]
+pub async fn create_speech_and_transcription() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::audio::SpeechArguments::new("tts-1", "Hello world!", "alloy");
+    let audio = c.create_speech(args, None).await.unwrap();
+
+    let args =
+        openai_rust::audio::TranscriptionArguments::new("whisper-1", "speech.mp3", audio.to_vec());
+    c.create_transcription(args, None).await.unwrap();
+}
+
+#[tokio::test]
+pub async fn create_speech_stream() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::audio::SpeechArguments::new("tts-1", "Hello world!", "alloy");
+    let chunks = c
+        .create_speech_stream(args, None)
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+    assert!(chunks.iter().all(|c| c.is_ok()));
+}
+
+#[tokio::test]
+pub async fn create_moderation() {
+    let c = openai_rust::Client::new(&KEY);
+    let args =
+        openai_rust::moderations::ModerationArguments::new("I want to kill them.".to_owned());
+    c.create_moderation(args, None).await.unwrap();
+
+    let args = openai_rust::moderations::ModerationArguments::new(vec![
+        "I want to kill them.".to_owned(),
+        "I love them.".to_owned(),
+    ]);
+    let res = c.create_moderation(args, None).await.unwrap();
+    assert_eq!(res.results.len(), 2);
+}
+
+#[tokio::test]
+pub async fn files() {
+    use openai_rust::files::{FilePurpose, UploadFileArguments};
+    let c = openai_rust::Client::new(&KEY);
+    let line = r#"{"custom_id": "request-1", "method": "POST", "url": "/v1/chat/completions", "body": {"model": "gpt-3.5-turbo", "messages": [{"role": "user", "content": "Hello GPT!"}]}}"#;
+    let args =
+        UploadFileArguments::new("batch.jsonl", line.as_bytes().to_vec(), FilePurpose::Batch);
+    let file = c.upload_file(args, None).await.unwrap();
+
+    assert!(c
+        .list_files(None)
+        .await
+        .unwrap()
+        .iter()
+        .any(|f| f.id == file.id));
+    c.retrieve_file(&file.id, None).await.unwrap();
+    assert!(c.delete_file(&file.id, None).await.unwrap().deleted);
+}
+
+#[tokio::test]
+pub async fn list_fine_tuning_jobs() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::pagination::ListArguments {
+        limit: Some(1),
+        ..Default::default()
+    };
+    let jobs = c.list_fine_tuning_jobs(args, None).await.unwrap();
+    if let Some(job) = jobs.data.first() {
+        c.retrieve_fine_tuning_job(&job.id, None).await.unwrap();
+        c.list_fine_tuning_events(&job.id, Default::default(), None)
+            .await
+            .unwrap();
+        c.list_fine_tuning_checkpoints(&job.id, Default::default(), None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn assistants() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::assistants::AssistantArguments::new("gpt-4o-mini");
+    let assistant = c.create_assistant(args, None).await.unwrap();
+
+    let args = openai_rust::assistants::ModifyAssistantArguments {
+        name: Some("Test assistant".to_owned()),
+        ..Default::default()
+    };
+    c.modify_assistant(&assistant.id, args, None).await.unwrap();
+    c.retrieve_assistant(&assistant.id, None).await.unwrap();
+    c.list_assistants(Default::default(), None).await.unwrap();
+    assert!(
+        c.delete_assistant(&assistant.id, None)
+            .await
+            .unwrap()
+            .deleted
+    );
+}
+
+#[tokio::test]
+pub async fn threads() {
+    use openai_rust::assistants::{messages::MessageArguments, threads::*};
+    let c = openai_rust::Client::new(&KEY);
+    let args = ThreadArguments {
+        messages: vec![MessageArguments::new("user", "Hello GPT!")],
+        ..Default::default()
+    };
+    let thread = c.create_thread(args, None).await.unwrap();
+    c.modify_thread(&thread.id, Default::default(), None)
+        .await
+        .unwrap();
+    c.retrieve_thread(&thread.id, None).await.unwrap();
+
+    let args = MessageArguments::new("user", "How are you?");
+    let msg = c.create_message(&thread.id, args, None).await.unwrap();
+    c.modify_message(&thread.id, &msg.id, Default::default(), None)
+        .await
+        .unwrap();
+    c.retrieve_message(&thread.id, &msg.id, None).await.unwrap();
+    let messages = c
+        .list_messages(&thread.id, Default::default(), None)
+        .await
+        .unwrap();
+    assert_eq!(messages.data.len(), 2);
+
+    assert!(c.delete_thread(&thread.id, None).await.unwrap().deleted);
+}
+
+#[tokio::test]
+pub async fn runs() {
+    use openai_rust::assistants::{messages::MessageArguments, runs::*, threads::*};
+    use std::time::Duration;
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::assistants::AssistantArguments::new("gpt-4o-mini");
+    let assistant = c.create_assistant(args, None).await.unwrap();
+    let args = ThreadArguments {
+        messages: vec![MessageArguments::new("user", "Hello GPT!")],
+        ..Default::default()
+    };
+    let thread = c.create_thread(args, None).await.unwrap();
+
+    let run = c
+        .create_run(&thread.id, RunArguments::new(&assistant.id), None)
+        .await
+        .unwrap();
+    let run = c
+        .wait_for_run(
+            &thread.id,
+            &run.id,
+            Duration::from_secs(1),
+            Duration::from_secs(60),
+        )
+        .await
+        .unwrap();
+    assert_eq!(run.status, RunStatus::Completed);
+    c.list_runs(&thread.id, Default::default(), None)
+        .await
+        .unwrap();
+    let steps = c
+        .list_run_steps(&thread.id, &run.id, Default::default(), None)
+        .await
+        .unwrap();
+    c.retrieve_run_step(&thread.id, &run.id, &steps.data[0].id, None)
+        .await
+        .unwrap();
+
+    let events = c
+        .create_run_stream(&thread.id, RunArguments::new(&assistant.id), None)
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+    assert!(events.iter().all(|e| e.is_ok()));
+
+    c.delete_thread(&thread.id, None).await.unwrap();
+    c.delete_assistant(&assistant.id, None).await.unwrap();
+}
+
+#[tokio::test]
+pub async fn list_batches() {
+    let c = openai_rust::Client::new(&KEY);
+    let batches = c.list_batches(Default::default(), None).await.unwrap();
+    if let Some(batch) = batches.data.first() {
+        c.retrieve_batch(&batch.id, None).await.unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn create_response() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::responses::ResponseArguments::new("gpt-4.1-mini", "Hello GPT!");
+    let res = c.create_response(args, None).await.unwrap();
+    assert!(!res.output_text().is_empty());
+
+    let mut args =
+        openai_rust::responses::ResponseArguments::new("gpt-4.1-mini", "What did I just say?");
+    args.previous_response_id = Some(res.id.clone());
+    let followup = c.create_response(args, None).await.unwrap();
+    assert_eq!(
+        followup.previous_response_id.as_deref(),
+        Some(res.id.as_str())
+    );
+
+    let retrieved = c.retrieve_response(&followup.id, None).await.unwrap();
+    assert_eq!(retrieved.id, followup.id);
+    assert!(c.delete_response(&followup.id, None).await.unwrap().deleted);
+    assert!(c.delete_response(&res.id, None).await.unwrap().deleted);
+}
+
+#[tokio::test]
+pub async fn create_response_stream() {
+    let c = openai_rust::Client::new(&KEY);
+    let args = openai_rust::responses::ResponseArguments::new("gpt-4.1-mini", "Hello GPT!");
+    let mut stream = c.create_response_stream(args, None).await.unwrap();
+    let mut completed = false;
+    while let Some(event) = stream.next().await {
+        if let openai_rust::responses::stream::ResponseStreamEvent::Completed(_) = event.unwrap() {
+            completed = true;
+        }
+    }
+    assert!(completed);
+}
+
+#[cfg(feature = "realtime")]
+#[tokio::test]
+pub async fn realtime() {
+    let c = openai_rust::Client::new(&KEY);
+    let mut session = c
+        .connect_realtime("gpt-4o-mini-realtime-preview", None)
+        .await
+        .unwrap();
+    let event = session.next().await.unwrap().unwrap();
+    assert!(matches!(
+        event,
+        openai_rust::realtime::ServerEvent::SessionCreated(_)
+    ));
+    session.close().await.unwrap();
+}